@@ -32,7 +32,7 @@ You are connected to a running instance of Rive Animation Viewer (RAV), a deskto
 - \`autoBind: true\` automatically binds the default ViewModel instance. An explicit instance selection deliberately loads with \`autoBind: false\` and binds that selected instance before controls and snapshots are restored.
 - \`stateMachines: "Name"\` must be set to activate a state machine.
 - If the user asks for a working instantiation snippet, prefer **generate_web_instantiation_code** first instead of hand-writing one from scratch.
-- If you do need to edit the live config, call **rav_get_editor_code** first and modify the returned object surgically. Do not invent placeholder globals like `FILE`, `FILE_PATH`, or custom file tokens.
+- If you do need to edit the live config, call **rav_get_editor_code** first and modify the returned object surgically. Do not invent placeholder globals like \`FILE\`, \`FILE_PATH\`, or custom file tokens.
 - Use **rav_set_editor_code** then **rav_apply_code** to change configuration and reload.
 - **rav_status** returns the live instantiation source and whether the editor has unapplied draft changes.
 - **generate_web_instantiation_code** returns the canonical copy-paste snippet for the live mode currently running in RAV.
@@ -56,6 +56,16 @@ You are connected to a running instance of Rive Animation Viewer (RAV), a deskto
 - **rav_console_read** returns the JS console transcript, including REPL input/result rows and captured \`console.*\` output.
 - **rav_console_exec** evaluates code in the REPL with output shown in the console panel. Use \`rav_console_read\` to verify what actually happened instead of assuming execution succeeded.
 - **rav_export_demo** creates a self-contained HTML file with the current animation, runtime, and settings baked in.
+- To put several artboards in one demo, pass **rav_export_demo** \`artboards\` (name, state machines, layout, control snapshot per entry). The current artboard stays first and the page gets an artboard dropdown; the .riv is embedded only once.
+- **rav_export_spec_sheet** writes a Markdown or HTML handoff spec of every state-machine input and ViewModel property path with types, defaults and enum values, plus the instantiation snippet.
+- **rav_export_web_component** writes an ES module that defines a custom element (default \`<rive-<file name>>\`). ViewModel properties become kebab-case attributes and camelCase element properties; pass \`riv_url\` to reference the .riv instead of embedding it.
+- **rav_export_framework_starter** writes a Vite project folder for react, vue or svelte with the component, the .riv in public/, a \`package.json\` pinned to the runtime version, and a README. \`package_source\` decides whether the runtime comes from npm or unpkg.
+- **rav_export_typescript_bindings** generates a .ts module with ViewModel interfaces, enum unions, trigger paths and a typed \`createTypedViewModel(instance)\` accessor, so app code can bind by path without string typos.
+- Reuse export settings with presets: save one with **rav_export_presets** (\`action: "save"\`) and pass its name to **rav_export_demo** as \`preset\`. Preset fields override the live canvas color, sizing, package source and control selection. A relative or missing \`output_path\` lands in the preset's \`output_dir\`.
+- To iterate on a design, export once with **rav_export_demo** to an \`output_path\`, then call **rav_export_watch** with \`start\`. Every save of the \`.riv\` re-runs that export with the same controls and output path; \`status\` returns the latest result and its size diff.
+- To script a demo, call **rav_record_timeline** with \`start\`, change controls in the properties panel (or with the control tools), then \`stop\`. Pass the returned \`timeline\` to **rav_export_demo**; the page plays it on load with a play/pause button and scrubber. Set \`loop: true\` to repeat it. Steps target a ViewModel \`path\` or a \`state_machine\` plus \`input\`.
+- **rav_export_pwa** writes a folder (not a single file) with \`index.html\`, \`manifest.webmanifest\`, icons and \`sw.js\`. It must be served over HTTPS or localhost for the service worker to install; after the first visit the demo runs offline.
+- **generate_native_instantiation_code** returns a native snippet for \`apple\` (SwiftUI), \`android\` (Kotlin), \`flutter\` or \`react-native\`, using the same artboard, state machine, layout, ViewModel instance and selected control values as the web snippet.
 - **rav_export_demo_visual** orchestrates the Snippet & Export Controls dialog visibly (open → selection → package/mode → Export → save) for screen recordings or non-default selections.
 - **rav_configure_workspace** sets left/right sidebar visibility, live editor/internal mode, and VM Explorer snippet state in one idempotent call.
 - **generate_web_instantiation_code** is the preferred way to get a web snippet. It bakes in the current runtime package, artboard/playback selection, layout fit/alignment, background mode, the active instantiation source, and the currently selected bound control values.
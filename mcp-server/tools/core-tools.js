@@ -49,8 +49,17 @@ export const CORE_TOOLS = [
   {
     name: 'rav_get_state_machines',
     description:
-      'List all state machine names on the current artboard.',
-    inputSchema: { type: 'object', properties: {}, additionalProperties: false },
+      'List all state machine names on the current artboard, or on another artboard of the loaded file.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        artboard: {
+          type: 'string',
+          description: 'Artboard to inspect. Defaults to the current artboard.',
+        },
+      },
+      additionalProperties: false,
+    },
   },
   {
     name: 'rav_switch_artboard',
@@ -154,6 +163,7 @@ export const CORE_TOOLS = [
       properties: {
         limit: {
           type: 'number',
+          minimum: 1,
           description: 'Maximum number of entries to return (default 50)',
         },
         source: {
@@ -227,10 +237,12 @@ export const CORE_TOOLS = [
         },
         width: {
           type: 'number',
+          minimum: 1,
           description: 'Explicit canvas width in pixels when mode is "fixed".',
         },
         height: {
           type: 'number',
+          minimum: 1,
           description: 'Explicit canvas height in pixels when mode is "fixed".',
         },
         lockAspectRatio: {
@@ -0,0 +1,81 @@
+export const DEMO_SETUP_TOOLS = [
+  {
+    name: 'rav_export_presets',
+    description:
+      "List, save or delete named demo export presets stored in RAV's config folder. A preset holds canvas color/transparency, " +
+      'canvas sizing, package source, control selection keys, an output folder and export options; pass its name to ' +
+      'rav_export_demo as preset. Returns { presets } after the change.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        action: { type: 'string', enum: ['list', 'save', 'delete'], description: 'Defaults to list.' },
+        name: { type: 'string', description: 'Preset to delete.' },
+        preset: {
+          type: 'object',
+          description: "Preset to save; replaces a preset with the same name. Omitted fields keep the live session's values at export time.",
+          properties: {
+            name: { type: 'string' },
+            canvas_color: { type: 'string', description: 'Hex background color, e.g. #0d1117.' },
+            canvas_transparent: { type: 'boolean' },
+            canvas_sizing: { type: 'object', description: '{ mode: auto|fixed, width, height, lockAspectRatio, aspectRatio }.' },
+            package_source: { type: 'string', enum: ['cdn', 'local'] },
+            control_selection_keys: {
+              type: 'array',
+              items: { type: 'string' },
+              description: 'Control keys (e.g. vm:title:string) to serialize and show.',
+            },
+            output_dir: { type: 'string', description: 'Folder for exports that give no absolute output_path.' },
+            options: {
+              type: 'object',
+              description: 'Export options: embed, embed_base_url, content_hash, interface_manifest, max_size_bytes, csp.',
+            },
+          },
+          required: ['name'],
+          additionalProperties: false,
+        },
+      },
+      additionalProperties: false,
+    },
+  },
+  {
+    name: 'rav_export_watch',
+    description:
+      'Watch mode for demo exports. "start" re-runs the last demo export to a path, with the same payload, control snapshot ' +
+      'and output path, whenever the source .riv changes on disk. Each re-export is logged with its verification report and ' +
+      'a size diff against the previous export. "stop" ends watching and "status" reports ' +
+      '{ watching, sourcePath, outputPath, exportCount, lastEvent }.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        action: {
+          type: 'string',
+          enum: ['start', 'stop', 'status'],
+          description: 'Watch action. Defaults to status.',
+        },
+        source_path: {
+          type: 'string',
+          description: 'Absolute path of the .riv to watch. Defaults to the file open in RAV.',
+        },
+      },
+      additionalProperties: false,
+    },
+  },
+  {
+    name: 'rav_record_timeline',
+    description:
+      "Record the control changes made in RAV's properties panel as a timeline for rav_export_demo. " +
+      '"start" begins a new recording, "stop" ends it and returns { timeline } with one step per change, ' +
+      'and "status" reports { recording, elapsedMs, stepCount }.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        action: {
+          type: 'string',
+          enum: ['start', 'stop', 'status'],
+          description: 'Recording action. Defaults to status.',
+        },
+      },
+      additionalProperties: false,
+    },
+  },
+];
@@ -29,106 +29,6 @@ export const EDITOR_TOOLS = [
       'configuration. Equivalent to clicking the "Apply & Reload" button.',
     inputSchema: { type: 'object', properties: {}, additionalProperties: false },
   },
-  {
-    name: 'rav_export_demo',
-    description:
-      'Export the current animation as a self-contained standalone HTML demo file. ' +
-      'Provide output_path to save directly (recommended for MCP). ' +
-      'Without output_path, opens a native save dialog (will timeout in MCP).',
-    inputSchema: {
-      type: 'object',
-      properties: {
-        output_path: {
-          type: 'string',
-          description:
-            'Absolute path where the HTML demo will be saved. ' +
-            'Parent directories are created automatically. ' +
-            'If omitted, a native save dialog opens (not usable from MCP).',
-        },
-      },
-      additionalProperties: false,
-    },
-  },
-  {
-    name: 'rav_export_demo_visual',
-    description:
-      'Orchestrate the Snippet & Export Controls dialog visually: open the dialog, apply the control selection, set package source and snippet mode, click Export, and write the demo to output_path. ' +
-      'Use this when the export needs to be visible (screen recordings) or when a non-default control selection is required. For pure programmatic export, use rav_export_demo.',
-    inputSchema: {
-      type: 'object',
-      properties: {
-        output_path: {
-          type: 'string',
-          description: 'Absolute path where the exported HTML demo will be saved.',
-        },
-        selection: {
-          oneOf: [
-            { type: 'string', enum: ['all', 'changed', 'none'] },
-            { type: 'array', items: { type: 'string' }, description: 'Explicit list of control snapshot keys to enable.' },
-          ],
-          description:
-            "How to populate the dialog's control selection. 'all' clicks SELECT ALL, 'changed' clicks CHANGED ONLY, 'none' clicks CLEAR, or pass an explicit array of control keys.",
-        },
-        package_source: {
-          type: 'string',
-          enum: ['cdn', 'local'],
-          description: 'Optional. Sets the package source select. Default: leave as-is.',
-        },
-        snippet_mode: {
-          type: 'string',
-          enum: ['compact', 'scaffold'],
-          description: 'Optional. Sets the snippet mode select. Default: leave as-is.',
-        },
-        step_delay_ms: {
-          type: 'number',
-          description: 'Milliseconds between visible steps so a recording captures each. Default: 250.',
-        },
-      },
-      required: ['output_path'],
-      additionalProperties: false,
-    },
-  },
-  {
-    name: 'generate_web_instantiation_code',
-    description:
-      'Generate a copy-paste-ready web instantiation snippet for the animation currently loaded in RAV. ' +
-      'The snippet mirrors the live source mode that is actually running in RAV: either internal wiring ' +
-      'or the last applied editor code. Supports either CDN or local npm package usage, restores the current ' +
-      'ViewModel/state-machine values on load, and exposes helper controls on window.ravRive. This is the preferred way to provide a working runtime-control snippet.',
-    inputSchema: {
-      type: 'object',
-      properties: {
-        package_source: {
-          type: 'string',
-          enum: ['cdn', 'local'],
-          description: 'Use a CDN/global runtime snippet or a local npm package import snippet.',
-        },
-        snippet_mode: {
-          type: 'string',
-          enum: ['compact', 'scaffold'],
-          description: 'Use a compact snippet with only selected live controls, or a scaffold snippet that lists all controls with unselected ones commented out.',
-        },
-      },
-      additionalProperties: false,
-    },
-  },
-  {
-    name: 'rav_toggle_instantiation_controls_dialog',
-    description:
-      'Open, close, or toggle the Snippet & Export Controls dialog inside RAV. ' +
-      'Use this when a human user should choose exactly which bound controls are serialized into snippets and demos.',
-    inputSchema: {
-      type: 'object',
-      properties: {
-        action: {
-          type: 'string',
-          enum: ['open', 'close', 'toggle'],
-          description: 'Whether to open, close, or toggle the dialog. Defaults to toggle.',
-        },
-      },
-      additionalProperties: false,
-    },
-  },
   {
     name: 'rav_configure_workspace',
     description:
@@ -262,6 +162,7 @@ export const EDITOR_TOOLS = [
       properties: {
         limit: {
           type: 'number',
+          minimum: 1,
           description: 'Maximum entries to return (default 50)',
         },
       },
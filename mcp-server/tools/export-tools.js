@@ -0,0 +1,368 @@
+export const EXPORT_TOOLS = [
+  {
+    name: 'rav_export_demo',
+    description:
+      'Export the current animation as a self-contained standalone HTML demo file. ' +
+      'Provide output_path to save directly (recommended for MCP). ' +
+      'Without output_path, opens a native save dialog (will timeout in MCP). ' +
+      'The written file is verified (no unresolved placeholders, embedded JSON parses, runtime script intact, ' +
+      'within the size budget) and the result includes the verification report. ' +
+      'A demo that fails verification is not written, any previous file at output_path is kept, and the result has ok: false with the failed checks.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        output_path: {
+          type: 'string',
+          description:
+            'Absolute path where the HTML demo will be saved. ' +
+            'Parent directories are created automatically. ' +
+            'If omitted, a native save dialog opens (not usable from MCP) unless preset names a preset with an output folder.',
+        },
+        embed: {
+          type: 'boolean',
+          description:
+            'Also write a chrome-free <name>.embed.html, an oEmbed <name>.oembed.json manifest, ' +
+            'and a ready-to-paste <name>.iframe.html snippet sized from the canvas sizing.',
+        },
+        embed_base_url: {
+          type: 'string',
+          description:
+            'Optional public base URL where the embed files will be hosted. ' +
+            'Used as the iframe src prefix; defaults to a relative src.',
+        },
+        content_hash: {
+          type: 'boolean',
+          description:
+            'Append a short SHA-256 of the exported HTML to the file name (e.g. demo.3f2a9c1b0d4e.html). ' +
+            'Identical inputs always produce identical bytes and names. The returned path reflects the final name.',
+        },
+        interface_manifest: {
+          type: 'boolean',
+          description:
+            'Also write <name>.rav.json: a versioned (schema "rav.interface", schemaVersion 1) machine-readable list of ' +
+            'artboards, state machines, inputs and ViewModel properties with types and default values.',
+        },
+        max_size_bytes: {
+          type: 'number',
+          minimum: 1,
+          description:
+            'Optional size budget for the exported HTML in bytes. Default: 67108864 (64 MiB). ' +
+            'The export fails verification when the file is larger.',
+        },
+        csp: {
+          type: 'boolean',
+          description:
+            'Inject a strict <meta http-equiv="Content-Security-Policy"> whose script-src/style-src list the SHA-256 hash ' +
+            "of every inline script and style. The result reports the policy and warns if the runtime script needs 'unsafe-eval'.",
+        },
+        artboards: {
+          type: 'array',
+          description:
+            'Additional artboards for an in-page artboard switcher. The current artboard is always the first entry; ' +
+            'the .riv is embedded once and shared by every entry.',
+          items: {
+            type: 'object',
+            properties: {
+              artboard_name: { type: 'string', description: 'Artboard name in the loaded file.' },
+              state_machines: { type: 'array', items: { type: 'string' }, description: 'State machines to run. Default: the first one found.' },
+              animations: { type: 'array', items: { type: 'string' }, description: 'Timeline animations, used when no state machine is given.' },
+              layout_fit: { type: 'string', description: 'Layout fit. Default: the current fit.' },
+              layout_alignment: { type: 'string', description: 'Layout alignment. Default: the current alignment.' },
+              view_model_instance_name: { type: 'string', description: 'ViewModel instance to bind. Default: auto-bind.' },
+              control_snapshot: {
+                type: 'array',
+                items: { type: 'object' },
+                description:
+                  'Control values to restore, as { descriptor: { path, kind } or { source: "state-machine", stateMachineName, name, kind }, kind, value } entries. ' +
+                  'When omitted with control_selection_keys, every control is shown.',
+              },
+              control_selection_keys: {
+                type: 'array',
+                items: { type: 'string' },
+                description: 'Control keys (e.g. vm:title:string) to show in the properties panel.',
+              },
+            },
+            required: ['artboard_name'],
+            additionalProperties: false,
+          },
+        },
+        preset: {
+          type: 'string',
+          description:
+            'Name of a saved export preset (see rav_export_presets). Its canvas color and sizing, package source, control selection ' +
+            "and export options are applied; a relative or omitted output_path is resolved against its output folder. " +
+            "Export options passed in this call replace the preset's.",
+        },
+        timeline: {
+          type: 'object',
+          description:
+            'Scripted interaction timeline the demo plays on load, with a play/pause button and scrubber. ' +
+            'rav_record_timeline with action "stop" returns one in this shape.',
+          properties: {
+            loop: { type: 'boolean', description: 'Restart from the beginning when the timeline ends. Default: false.' },
+            autoplay: { type: 'boolean', description: 'Start playing as soon as the animation loads. Default: true.' },
+            duration_ms: { type: 'number', minimum: 0, description: 'Total length in milliseconds. Default: one second after the last step.' },
+            steps: {
+              type: 'array',
+              items: {
+                type: 'object',
+                properties: {
+                  at_ms: { type: 'number', minimum: 0, description: 'Time from the start of the timeline in milliseconds.' },
+                  kind: {
+                    type: 'string',
+                    enum: ['number', 'boolean', 'string', 'enum', 'color', 'trigger'],
+                    description: 'Control kind. State-machine inputs accept number, boolean and trigger.',
+                  },
+                  path: { type: 'string', description: 'ViewModel property path, e.g. header/title.' },
+                  state_machine: { type: 'string', description: 'State machine name, for a state-machine input step (instead of path).' },
+                  input: { type: 'string', description: 'State-machine input name, used with state_machine.' },
+                  value: { description: 'New value matching kind; colors are ARGB integers. Ignored for triggers.' },
+                },
+                required: ['at_ms', 'kind'],
+                additionalProperties: false,
+              },
+            },
+          },
+          required: ['steps'],
+          additionalProperties: false,
+        },
+      },
+      additionalProperties: false,
+    },
+  },
+  {
+    name: 'rav_export_spec_sheet',
+    description:
+      'Write a developer handoff spec sheet for the loaded file: artboard, animations, state-machine inputs (including trigger names), ' +
+      'and every ViewModel property path with its type, default value and enum choices, followed by the web instantiation snippet RAV generates. ' +
+      'Rendered as Markdown or a standalone HTML page.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        output_path: {
+          type: 'string',
+          description: 'Absolute path where the spec sheet will be saved. Parent directories are created automatically.',
+        },
+        format: {
+          type: 'string',
+          enum: ['markdown', 'html'],
+          description: 'Optional. Defaults to html for .html/.htm paths and markdown otherwise.',
+        },
+        package_source: {
+          type: 'string',
+          enum: ['cdn', 'local'],
+          description: 'Optional. Package source used for the embedded instantiation snippet. Default: cdn.',
+        },
+      },
+      required: ['output_path'],
+      additionalProperties: false,
+    },
+  },
+  {
+    name: 'rav_export_web_component',
+    description:
+      'Export the loaded animation as a self-contained ES module that defines a custom element. ' +
+      'The element applies the current layout, artboard, state machine and control snapshot, exposes every ViewModel property ' +
+      'as a kebab-case attribute and a camelCase property, and fires triggers through `el.fire(path)`. ' +
+      'The .riv is embedded unless `riv_url` is given.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        output_path: {
+          type: 'string',
+          description: 'Absolute path where the .js module will be saved. Parent directories are created automatically.',
+        },
+        tag_name: {
+          type: 'string',
+          description: 'Optional. Custom element name (lowercase, must contain a hyphen). Default: rive-<file name>.',
+        },
+        riv_url: {
+          type: 'string',
+          description: 'Optional. Load the .riv from this URL instead of embedding it as base64.',
+        },
+      },
+      required: ['output_path'],
+      additionalProperties: false,
+    },
+  },
+  {
+    name: 'rav_export_framework_starter',
+    description:
+      'Write a small Vite project for React, Vue or Svelte that wraps the loaded animation: a component carrying the current artboard, ' +
+      'state machines, layout and control values, the .riv in public/, a package.json pinned to the payload\'s runtime version, and a README. ' +
+      'With package_source "local" the component imports the runtime from npm; with "cdn" it is loaded from unpkg at the same version. ' +
+      'Fails if the runtime version is not known yet.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        output_path: {
+          type: 'string',
+          description: 'Absolute path of the project folder to write. It is created if missing; existing files with the same names are overwritten.',
+        },
+        framework: {
+          type: 'string',
+          enum: ['react', 'vue', 'svelte'],
+          description: 'Framework for the starter project.',
+        },
+        package_source: {
+          type: 'string',
+          enum: ['cdn', 'local'],
+          description: 'Optional. Where the project gets the Rive runtime from. Default: cdn.',
+        },
+      },
+      required: ['output_path', 'framework'],
+      additionalProperties: false,
+    },
+  },
+  {
+    name: 'rav_export_typescript_bindings',
+    description:
+      "Generate a TypeScript module from the loaded file's ViewModel hierarchy: one interface per ViewModel, enum value unions, " +
+      'a path-to-type map of every property, the trigger paths, state-machine inputs, and a `createTypedViewModel(instance)` helper ' +
+      'with typed get/set/fire by path.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        output_path: {
+          type: 'string',
+          description: 'Absolute path where the .ts file will be saved. Parent directories are created automatically.',
+        },
+      },
+      required: ['output_path'],
+      additionalProperties: false,
+    },
+  },
+  {
+    name: 'rav_export_pwa',
+    description:
+      'Write an installable offline PWA folder for the loaded animation: `index.html` (the full demo page with a web manifest link ' +
+      'and service worker registration), `manifest.webmanifest` using the RAV app icon, the icon files, and `sw.js`. The service worker ' +
+      "pre-caches the page, which embeds the runtime script and animation, plus the runtime WebAssembly binary and the page's CDN scripts " +
+      'and fonts, so the demo works offline after the first load. Returns the verification report like `rav_export_demo`.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        output_path: {
+          type: 'string',
+          description: 'Absolute path of the folder to write. It is created if missing; serve it over HTTPS or localhost so the service worker can register.',
+        },
+        max_size_bytes: {
+          type: 'number',
+          minimum: 1,
+          description:
+            'Optional size budget for index.html in bytes. Default: 67108864 (64 MiB). ' +
+            'The export fails verification when the page is larger.',
+        },
+        csp: {
+          type: 'boolean',
+          description:
+            'Inject the same strict Content-Security-Policy as `rav_export_demo`, plus manifest-src and worker-src \'self\' ' +
+            'so the manifest and service worker still load.',
+        },
+      },
+      required: ['output_path'],
+      additionalProperties: false,
+    },
+  },
+  {
+    name: 'rav_export_demo_visual',
+    description:
+      'Orchestrate the Snippet & Export Controls dialog visually: open the dialog, apply the control selection, set package source and snippet mode, click Export, and write the demo to output_path. ' +
+      'Use this when the export needs to be visible (screen recordings) or when a non-default control selection is required. For pure programmatic export, use rav_export_demo.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        output_path: {
+          type: 'string',
+          description: 'Absolute path where the exported HTML demo will be saved.',
+        },
+        selection: {
+          oneOf: [
+            { type: 'string', enum: ['all', 'changed', 'none'] },
+            { type: 'array', items: { type: 'string' }, description: 'Explicit list of control snapshot keys to enable.' },
+          ],
+          description:
+            "How to populate the dialog's control selection. 'all' clicks SELECT ALL, 'changed' clicks CHANGED ONLY, 'none' clicks CLEAR, or pass an explicit array of control keys.",
+        },
+        package_source: {
+          type: 'string',
+          enum: ['cdn', 'local'],
+          description: 'Optional. Sets the package source select. Default: leave as-is.',
+        },
+        snippet_mode: {
+          type: 'string',
+          enum: ['compact', 'scaffold'],
+          description: 'Optional. Sets the snippet mode select. Default: leave as-is.',
+        },
+        step_delay_ms: {
+          type: 'number',
+          minimum: 0,
+          description: 'Milliseconds between visible steps so a recording captures each. Default: 250.',
+        },
+      },
+      required: ['output_path'],
+      additionalProperties: false,
+    },
+  },
+  {
+    name: 'generate_web_instantiation_code',
+    description:
+      'Generate a copy-paste-ready web instantiation snippet for the animation currently loaded in RAV. ' +
+      'The snippet mirrors the live source mode that is actually running in RAV: either internal wiring ' +
+      'or the last applied editor code. Supports either CDN or local npm package usage, restores the current ' +
+      'ViewModel/state-machine values on load, and exposes helper controls on window.ravRive. This is the preferred way to provide a working runtime-control snippet.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        package_source: {
+          type: 'string',
+          enum: ['cdn', 'local'],
+          description: 'Use a CDN/global runtime snippet or a local npm package import snippet.',
+        },
+        snippet_mode: {
+          type: 'string',
+          enum: ['compact', 'scaffold'],
+          description: 'Use a compact snippet with only selected live controls, or a scaffold snippet that lists all controls with unselected ones commented out.',
+        },
+      },
+      additionalProperties: false,
+    },
+  },
+  {
+    name: 'generate_native_instantiation_code',
+    description:
+      'Generate a native instantiation snippet for the animation currently loaded in RAV: SwiftUI (apple), ' +
+      'Kotlin RiveAnimationView (android), Flutter (flutter) or React Native (react-native). The snippet uses the ' +
+      'current artboard, state machine, layout fit/alignment, ViewModel instance binding and the selected control values. ' +
+      'Returns { platform, language, code }.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        platform: {
+          type: 'string',
+          enum: ['apple', 'android', 'flutter', 'react-native'],
+          description: 'Target platform for the snippet.',
+        },
+      },
+      required: ['platform'],
+      additionalProperties: false,
+    },
+  },
+  {
+    name: 'rav_toggle_instantiation_controls_dialog',
+    description:
+      'Open, close, or toggle the Snippet & Export Controls dialog inside RAV. ' +
+      'Use this when a human user should choose exactly which bound controls are serialized into snippets and demos.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        action: {
+          type: 'string',
+          enum: ['open', 'close', 'toggle'],
+          description: 'Whether to open, close, or toggle the dialog. Defaults to toggle.',
+        },
+      },
+      additionalProperties: false,
+    },
+  },
+];
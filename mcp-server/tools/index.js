@@ -1,7 +1,11 @@
 import { CORE_TOOLS } from './core-tools.js';
+import { DEMO_SETUP_TOOLS } from './demo-setup-tools.js';
 import { EDITOR_TOOLS } from './editor-tools.js';
+import { EXPORT_TOOLS } from './export-tools.js';
 
 export const TOOLS = [
   ...CORE_TOOLS,
   ...EDITOR_TOOLS,
+  ...EXPORT_TOOLS,
+  ...DEMO_SETUP_TOOLS,
 ];
@@ -4,3 +4,4 @@ pub const ONLINE_DOCS_MENU_ID: &str = "rav-online-docs";
 pub const ABOUT_MENU_ID: &str = "rav-about";
 pub const RAV_DOCS_URL: &str = "https://forge.mograph.life/apps/rav/docs";
 pub const MCP_CLIENT_LAUNCHER_NAME: &str = "rav-mcp-rav";
+pub const RAV_HOMEPAGE_URL: &str = "https://forge.mograph.life/apps/rav";
@@ -0,0 +1,165 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use sha2::{Digest, Sha256};
+
+const SCRIPT_SOURCES: &str = "https://unpkg.com 'wasm-unsafe-eval'";
+const STYLE_SOURCES: &str = "https://fonts.googleapis.com";
+const FIXED_DIRECTIVES: [&str; 6] = [
+    "default-src 'none'",
+    "font-src https://fonts.gstatic.com",
+    "img-src data: blob:",
+    "connect-src blob: data: https://unpkg.com https://cdn.jsdelivr.net",
+    "base-uri 'none'",
+    "object-src 'none'",
+];
+const EVAL_CALLS: [(&str, &str); 5] = [
+    ("eval(", "eval()"),
+    ("Function(", "new Function()"),
+    ("setTimeout(\"", "string setTimeout()"),
+    ("setTimeout('", "string setTimeout()"),
+    ("setInterval(\"", "string setInterval()"),
+];
+
+pub struct ContentSecurityPolicy {
+    pub policy: String,
+    pub warnings: Vec<String>,
+}
+
+pub fn build_content_security_policy(html: &str, runtime_script: &str) -> ContentSecurityPolicy {
+    let script_hashes = inline_block_hashes(html, "script");
+    let style_hashes = inline_block_hashes(html, "style");
+    let mut directives = vec![
+        format!("script-src {} {SCRIPT_SOURCES}", script_hashes.join(" ")),
+        format!("style-src {} {STYLE_SOURCES}", style_hashes.join(" ")),
+    ];
+    directives.extend(
+        FIXED_DIRECTIVES
+            .iter()
+            .map(|directive| directive.to_string()),
+    );
+    directives.sort();
+
+    let mut eval_calls: Vec<&str> = EVAL_CALLS
+        .iter()
+        .filter(|(call, _)| contains_global_call(runtime_script, call))
+        .map(|(_, label)| *label)
+        .collect();
+    eval_calls.dedup();
+    let warnings = if eval_calls.is_empty() {
+        Vec::new()
+    } else {
+        vec![format!(
+            "The runtime script uses {} and needs 'unsafe-eval'; it may fail under this policy unless script-src is relaxed.",
+            eval_calls.join(", ")
+        )]
+    };
+
+    ContentSecurityPolicy {
+        policy: directives.join("; "),
+        warnings,
+    }
+}
+
+pub fn inject_content_security_policy(html: &str, policy: &str) -> String {
+    let meta = format!(
+        "\n    <meta http-equiv=\"Content-Security-Policy\" content=\"{}\">",
+        policy.replace('"', "&quot;")
+    );
+    match html.find("<meta charset=\"UTF-8\">") {
+        Some(start) => {
+            let end = start + "<meta charset=\"UTF-8\">".len();
+            format!("{}{meta}{}", &html[..end], &html[end..])
+        }
+        None => html.replacen("<head>", &format!("<head>{meta}"), 1),
+    }
+}
+
+fn inline_block_hashes(html: &str, tag: &str) -> Vec<String> {
+    let open = format!("<{tag}");
+    let close = format!("</{tag}>");
+    let mut hashes = Vec::new();
+    let mut rest = html;
+    while let Some(start) = rest.find(&open) {
+        let after_name = &rest[start + open.len()..];
+        let Some(tag_end) = after_name.find('>') else {
+            break;
+        };
+        let attributes = &after_name[..tag_end];
+        let body = &after_name[tag_end + 1..];
+        let Some(body_end) = body.find(&close) else {
+            break;
+        };
+        let is_tag = attributes.is_empty() || attributes.starts_with(char::is_whitespace);
+        if is_tag && !attributes.contains("src=") {
+            let digest = Sha256::digest(&body.as_bytes()[..body_end]);
+            let hash = format!("'sha256-{}'", STANDARD.encode(digest));
+            if !hashes.contains(&hash) {
+                hashes.push(hash);
+            }
+        }
+        rest = &body[body_end + close.len()..];
+    }
+    hashes
+}
+
+fn contains_global_call(script: &str, call: &str) -> bool {
+    script.match_indices(call).any(|(index, _)| {
+        script[..index]
+            .chars()
+            .next_back()
+            .is_none_or(|ch| !(ch.is_alphanumeric() || ch == '_' || ch == '$' || ch == '.'))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_content_security_policy, inject_content_security_policy};
+    use crate::app::export::demo_bundle::build_demo_html;
+    use crate::app::export::fixtures::demo_payload;
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use sha2::{Digest, Sha256};
+
+    #[test]
+    fn policy_hashes_every_inline_script_and_style_but_not_external_scripts() {
+        let html = "<head><meta charset=\"UTF-8\"><style>a{}</style><script src=\"https://unpkg.com/x.js\"></script></head><script>go()</script><script type=\"application/json\">{}</script>";
+        let csp = build_content_security_policy(html, "go()");
+        let hash = |body: &str| format!("'sha256-{}'", STANDARD.encode(Sha256::digest(body)));
+
+        assert!(csp
+            .policy
+            .contains(&format!("script-src {} {}", hash("go()"), hash("{}"))));
+        assert!(csp.policy.contains(&format!(
+            "style-src {} https://fonts.googleapis.com",
+            hash("a{}")
+        )));
+        assert!(!csp.policy.contains(&hash("")));
+        assert!(csp.policy.starts_with("base-uri 'none'; connect-src"));
+        assert!(csp.warnings.is_empty());
+    }
+
+    #[test]
+    fn demo_html_gets_a_matching_policy_meta_before_any_script() {
+        let payload = demo_payload();
+        let html = build_demo_html(&payload).expect("demo html");
+        let csp = build_content_security_policy(&html, &payload.runtime_script);
+        let secured = inject_content_security_policy(&html, &csp.policy);
+        let meta = secured
+            .find("http-equiv=\"Content-Security-Policy\"")
+            .expect("csp meta");
+
+        assert!(meta < secured.find("<script").expect("script"));
+        assert_eq!(
+            build_content_security_policy(&secured, "").policy,
+            csp.policy
+        );
+    }
+
+    #[test]
+    fn warns_when_the_runtime_needs_unsafe_eval() {
+        let eval = build_content_security_policy("", "var f = new Function('return 1');");
+        let method = build_content_security_policy("", "retrieval(x); obj.eval(y); isFunction(z);");
+
+        assert_eq!(eval.warnings.len(), 1);
+        assert!(eval.warnings[0].contains("new Function()"));
+        assert!(method.warnings.is_empty());
+    }
+}
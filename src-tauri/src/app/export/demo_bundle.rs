@@ -1,55 +1,83 @@
 use base64::{engine::general_purpose::STANDARD, Engine};
 use std::fs;
+use std::path::{Path, PathBuf};
 
-use crate::app::state::DemoBundlePayload;
+use crate::app::export::csp::{build_content_security_policy, inject_content_security_policy};
+use crate::app::export::demo_config::artboards::build_artboard_switcher_config;
+use crate::app::export::demo_config::presets::{
+    apply_export_preset_to_payload, export_presets_path, find_export_preset,
+    resolve_preset_output_path, suggested_demo_file_name,
+};
+use crate::app::export::demo_config::timeline::{
+    build_timeline_config, describe_timeline_problems,
+};
+use crate::app::export::embed::write_embed_variants;
+use crate::app::export::manifest::write_interface_manifest;
+use crate::app::export::provenance::{
+    build_provenance_json, canonical_json_string, canonicalize_json, content_addressed_path,
+};
+use crate::app::export::verify::{write_verified_demo_html, DEFAULT_DEMO_SIZE_BUDGET_BYTES};
+use crate::app::export::watch::export_and_remember;
+use crate::app::state::{
+    DemoBundlePayload, DemoExportOptions, DemoExportReport, ExportWatchManager,
+};
 
-const DEMO_TEMPLATE_SHELL: &str = include_str!("../demo-template/shell.html");
-const DEMO_TEMPLATE_MARKUP: &str = include_str!("../demo-template/markup.html");
+const DEMO_TEMPLATE_SHELL: &str = include_str!("../../demo-template/shell.html");
+const DEMO_TEMPLATE_MARKUP: &str = include_str!("../../demo-template/markup.html");
 const DEMO_TEMPLATE_STYLES: &str = concat!(
-    include_str!("../demo-template/css/base.css"),
+    include_str!("../../demo-template/css/base.css"),
     "\n",
-    include_str!("../demo-template/css/controls.css"),
+    include_str!("../../demo-template/css/controls.css"),
     "\n",
-    include_str!("../demo-template/css/runtime-layout.css"),
+    include_str!("../../demo-template/css/runtime-layout.css"),
     "\n",
-    include_str!("../demo-template/css/event-log.css"),
+    include_str!("../../demo-template/css/event-log.css"),
     "\n",
-    include_str!("../demo-template/css/properties.css"),
+    include_str!("../../demo-template/css/properties.css"),
     "\n",
-    include_str!("../demo-template/css/overlays.css"),
+    include_str!("../../demo-template/css/overlays.css"),
 );
 const DEMO_TEMPLATE_APP_JS: &str = concat!(
-    include_str!("../demo-template/js/core/preamble.js"),
+    include_str!("../../demo-template/js/core/preamble.js"),
     "\n",
-    include_str!("../demo-template/js/core/layout.js"),
+    include_str!("../../demo-template/js/core/layout.js"),
     "\n",
-    include_str!("../demo-template/js/core/bootstrap.js"),
+    include_str!("../../demo-template/js/core/bootstrap.js"),
     "\n",
-    include_str!("../demo-template/js/core/playback-layout.js"),
+    include_str!("../../demo-template/js/core/playback-layout.js"),
     "\n",
-    include_str!("../demo-template/js/core/settings.js"),
+    include_str!("../../demo-template/js/core/settings.js"),
     "\n",
-    include_str!("../demo-template/js/core/event-log.js"),
+    include_str!("../../demo-template/js/core/artboard-switcher.js"),
     "\n",
-    include_str!("../demo-template/js/vm/accessors.js"),
+    include_str!("../../demo-template/js/core/event-log.js"),
     "\n",
-    include_str!("../demo-template/js/vm/hierarchy.js"),
+    include_str!("../../demo-template/js/vm/accessors.js"),
     "\n",
-    include_str!("../demo-template/js/vm/controls-render.js"),
+    include_str!("../../demo-template/js/vm/hierarchy.js"),
     "\n",
-    include_str!("../demo-template/js/vm/sync.js"),
+    include_str!("../../demo-template/js/vm/controls-render.js"),
     "\n",
-    include_str!("../demo-template/js/core/rive-loader.js"),
+    include_str!("../../demo-template/js/vm/sync.js"),
+    "\n",
+    include_str!("../../demo-template/js/vm/timeline.js"),
+    "\n",
+    include_str!("../../demo-template/js/core/rive-loader.js"),
 );
+pub const DEMO_TEMPLATES: [&str; 4] = [
+    DEMO_TEMPLATE_SHELL,
+    DEMO_TEMPLATE_MARKUP,
+    DEMO_TEMPLATE_STYLES,
+    DEMO_TEMPLATE_APP_JS,
+];
 
 #[tauri::command]
-pub async fn make_demo_bundle(payload: DemoBundlePayload) -> Result<String, String> {
-    let suggested = format!(
-        "{}-demo.html",
-        payload
-            .file_name
-            .replace(|c: char| !c.is_ascii_alphanumeric(), "-")
-    );
+pub async fn make_demo_bundle(
+    watch: tauri::State<'_, ExportWatchManager>,
+    payload: DemoBundlePayload,
+    options: Option<DemoExportOptions>,
+) -> Result<DemoExportReport, String> {
+    let suggested = suggested_demo_file_name(&payload.file_name);
 
     let save_path = rfd::FileDialog::new()
         .set_title("Save Rive Demo Viewer")
@@ -59,32 +87,88 @@ pub async fn make_demo_bundle(payload: DemoBundlePayload) -> Result<String, Stri
 
     let path = save_path.ok_or_else(|| "Save canceled".to_string())?;
 
-    let html = build_demo_html(&payload).map_err(|error| error.to_string())?;
-    fs::write(&path, html).map_err(|error| error.to_string())?;
-
-    Ok(path.to_string_lossy().to_string())
+    let output_path = path.to_string_lossy().to_string();
+    export_and_remember(&watch, payload, output_path, options.unwrap_or_default())
 }
 
+/// A demo that fails verification is still `Ok`, because the UI and MCP show the per-check
+/// report. Callers must check `verification.passed` rather than rely on `Err`.
 #[tauri::command]
 pub async fn make_demo_bundle_to_path(
-    payload: DemoBundlePayload,
+    app: tauri::AppHandle,
+    watch: tauri::State<'_, ExportWatchManager>,
+    mut payload: DemoBundlePayload,
     output_path: String,
-) -> Result<String, String> {
+    options: Option<DemoExportOptions>,
+    preset: Option<String>,
+) -> Result<DemoExportReport, String> {
+    let mut options = options;
+    let mut output_path = output_path;
+    if let Some(name) = preset.as_deref().filter(|name| !name.trim().is_empty()) {
+        let preset = find_export_preset(&export_presets_path(&app)?, name)?;
+        apply_export_preset_to_payload(&preset, &mut payload);
+        output_path = resolve_preset_output_path(&preset, &output_path, &payload.file_name);
+        options = options.or(preset.options);
+    }
+    export_and_remember(&watch, payload, output_path, options.unwrap_or_default())
+}
+
+pub fn prepare_output_path(output_path: &str) -> Result<PathBuf, String> {
     if output_path.trim().is_empty() {
         return Err("output_path is empty".into());
     }
-    let path = std::path::PathBuf::from(&output_path);
+    let path = PathBuf::from(output_path);
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent).map_err(|error| {
             format!("Failed to create directory {}: {}", parent.display(), error)
         })?;
     }
-    let html = build_demo_html(&payload).map_err(|error| error.to_string())?;
-    fs::write(&path, html).map_err(|error| error.to_string())?;
-    Ok(path.to_string_lossy().to_string())
+    Ok(path)
 }
 
-pub fn build_demo_html(payload: &DemoBundlePayload) -> Result<String, serde_json::Error> {
+pub fn write_demo_bundle(
+    payload: &DemoBundlePayload,
+    path: &Path,
+    options: &DemoExportOptions,
+) -> Result<DemoExportReport, String> {
+    if let Some(problems) = describe_timeline_problems(payload.timeline.as_ref()) {
+        return Err(problems);
+    }
+    let mut html = build_demo_html(payload).map_err(|error| error.to_string())?;
+    let mut warnings = Vec::new();
+    let mut content_security_policy = None;
+    if options.csp {
+        let csp = build_content_security_policy(&html, &payload.runtime_script);
+        html = inject_content_security_policy(&html, &csp.policy);
+        warnings.extend(csp.warnings);
+        content_security_policy = Some(csp.policy);
+    }
+    let path = if options.content_hash {
+        content_addressed_path(path, html.as_bytes())
+    } else {
+        path.to_path_buf()
+    };
+    let size_budget_bytes = options
+        .max_size_bytes
+        .unwrap_or(DEFAULT_DEMO_SIZE_BUDGET_BYTES);
+    let verification = write_verified_demo_html(&path, &html, payload, size_budget_bytes)?;
+    if verification.passed {
+        if options.embed {
+            write_embed_variants(payload, &path, options.embed_base_url.as_deref())?;
+        }
+        if options.interface_manifest {
+            write_interface_manifest(payload, &path)?;
+        }
+    }
+    Ok(DemoExportReport {
+        path: path.to_string_lossy().to_string(),
+        verification,
+        content_security_policy,
+        warnings,
+    })
+}
+
+pub fn build_demo_config(payload: &DemoBundlePayload) -> serde_json::Value {
     use serde_json::json;
 
     let layout_state = payload
@@ -93,7 +177,7 @@ pub fn build_demo_html(payload: &DemoBundlePayload) -> Result<String, serde_json
         .and_then(|raw| serde_json::from_str::<serde_json::Value>(raw).ok())
         .unwrap_or_else(|| json!({}));
 
-    let config = json!({
+    canonicalize_json(json!({
       "runtimeName": payload.runtime_name,
       "runtimeVersion": payload.runtime_version,
       "animationBase64": payload.animation_base64,
@@ -125,6 +209,7 @@ pub fn build_demo_html(payload: &DemoBundlePayload) -> Result<String, serde_json
       "stateMachines": payload.state_machines,
       "animations": payload.animations,
       "artboardName": payload.artboard_name,
+      "artboards": build_artboard_switcher_config(payload),
       "canvasColor": payload
         .canvas_color
         .clone()
@@ -142,8 +227,13 @@ pub fn build_demo_html(payload: &DemoBundlePayload) -> Result<String, serde_json
         })),
       "canvasTransparent": payload.canvas_transparent,
       "layoutState": layout_state,
+      "timeline": build_timeline_config(payload.timeline.as_ref()),
       "viewModelInstanceName": payload.view_model_instance_name
-    });
+    }))
+}
+
+pub fn build_demo_html(payload: &DemoBundlePayload) -> Result<String, serde_json::Error> {
+    let config = build_demo_config(payload);
     let config_json = serde_json::to_string(&config)?;
     let escaped_config = escape_embedded_script_json(&config_json);
     let escaped_runtime = payload.runtime_script.replace("</script", "<\\/script");
@@ -154,15 +244,9 @@ pub fn build_demo_html(payload: &DemoBundlePayload) -> Result<String, serde_json
         "WebGL"
     };
     let runtime_version = payload.runtime_version.as_deref().unwrap_or("unknown");
-    let app_icon_data_url = format!(
-        "data:image/png;base64,{}",
-        STANDARD.encode(include_bytes!("../../icons/128x128.png"))
-    );
-    let vm_hierarchy_json = payload
-        .vm_hierarchy
-        .as_deref()
-        .unwrap_or("null")
-        .to_string();
+    let app_icon_data_url = app_icon_data_url();
+    let vm_hierarchy_json =
+        canonical_json_string(payload.vm_hierarchy.as_deref().unwrap_or("null"));
     let escaped_vm_hierarchy = escape_embedded_script_json(&vm_hierarchy_json);
     let title = format!("{} – Rive Demo", payload.file_name);
 
@@ -175,6 +259,7 @@ pub fn build_demo_html(payload: &DemoBundlePayload) -> Result<String, serde_json
         .replace("__CONFIG_JSON__", &escaped_config)
         .replace("__RUNTIME_SCRIPT__", &escaped_runtime)
         .replace("__VM_HIERARCHY_JSON__", &escaped_vm_hierarchy)
+        .replace("__PROVENANCE_JSON__", &build_provenance_json(payload))
         .replace("__FILE_NAME__", &payload.file_name)
         .replace("__RUNTIME_DISPLAY__", runtime_display)
         .replace("__APP_ICON_DATA_URL__", &app_icon_data_url)
@@ -183,6 +268,13 @@ pub fn build_demo_html(payload: &DemoBundlePayload) -> Result<String, serde_json
     Ok(html)
 }
 
+pub fn app_icon_data_url() -> String {
+    format!(
+        "data:image/png;base64,{}",
+        STANDARD.encode(include_bytes!("../../../icons/128x128.png"))
+    )
+}
+
 pub fn escape_embedded_script_json(raw: &str) -> String {
     raw.replace('\\', "\\\\")
         .replace('\'', "\\'")
@@ -209,6 +301,8 @@ mod tests {
             animation_base64: "AQID".into(),
             animations: vec![],
             artboard_name: Some("Main".into()),
+            artboards: vec![],
+            timeline: None,
             autoplay: true,
             canvas_color: Some("#0d1117".into()),
             canvas_sizing: None,
@@ -227,6 +321,7 @@ mod tests {
             runtime_script: "console.log('runtime');".into(),
             runtime_version: Some("2.36.0".into()),
             state_machines: vec!["main-sm".into()],
+            state_machine_hierarchy: None,
             view_model_instance_name: Some("Preview".into()),
             vm_hierarchy: Some(r#"{"label":"root","text":"</script>"}"#.into()),
         };
@@ -253,6 +348,8 @@ mod tests {
             animation_base64: "AQID".into(),
             animations: vec!["idle".into()],
             artboard_name: Some("Main".into()),
+            artboards: vec![],
+            timeline: None,
             autoplay: true,
             canvas_color: Some("#0d1117".into()),
             canvas_sizing: None,
@@ -273,6 +370,7 @@ mod tests {
             runtime_script: "console.log('runtime');".into(),
             runtime_version: Some("2.37.0".into()),
             state_machines: vec!["main-sm".into()],
+            state_machine_hierarchy: None,
             view_model_instance_name: None,
             vm_hierarchy: None,
         };
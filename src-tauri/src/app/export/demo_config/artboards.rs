@@ -0,0 +1,146 @@
+use serde_json::{json, Value};
+
+use crate::app::state::{DemoArtboardConfig, DemoBundlePayload};
+
+/// Switcher entries for a multi-artboard demo. The payload's own artboard always comes first,
+/// so an empty `artboards` list keeps the single-artboard demo. Without an `artboard_name` the
+/// app loaded the default artboard, which is the first one it lists.
+pub fn build_artboard_switcher_config(payload: &DemoBundlePayload) -> Vec<Value> {
+    if payload.artboards.is_empty() {
+        return Vec::new();
+    }
+
+    let Some(primary_name) = payload
+        .artboard_name
+        .as_deref()
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .or_else(|| {
+            payload
+                .artboards
+                .iter()
+                .map(|artboard| artboard.artboard_name.trim())
+                .find(|name| !name.is_empty())
+        })
+        .map(str::to_string)
+    else {
+        return Vec::new();
+    };
+    let mut entries = vec![json!({
+        "artboardName": &primary_name,
+        "stateMachines": payload.state_machines,
+        "animations": payload.animations,
+        "layoutFit": payload.layout_fit,
+        "layoutAlignment": payload.layout_alignment,
+        "controlSnapshot": parse_json_array(payload.control_snapshot.as_deref()),
+        "controlSelectionKeys": parse_json_array(payload.control_selection_keys.as_deref()),
+        "viewModelInstanceName": payload.view_model_instance_name,
+    })];
+    let mut seen = vec![primary_name];
+    for artboard in &payload.artboards {
+        let name = artboard.artboard_name.trim();
+        if name.is_empty() || seen.iter().any(|seen| seen == name) {
+            continue;
+        }
+        seen.push(name.to_string());
+        entries.push(build_artboard_entry(payload, artboard));
+    }
+    entries
+}
+
+fn build_artboard_entry(payload: &DemoBundlePayload, artboard: &DemoArtboardConfig) -> Value {
+    let layout = |value: &Option<String>, fallback: &str| {
+        value
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .unwrap_or(fallback)
+            .to_string()
+    };
+    json!({
+        "artboardName": artboard.artboard_name.trim(),
+        "stateMachines": artboard.state_machines,
+        "animations": artboard.animations,
+        "layoutFit": layout(&artboard.layout_fit, &payload.layout_fit),
+        "layoutAlignment": layout(&artboard.layout_alignment, &payload.layout_alignment),
+        "controlSnapshot": parse_json_array(artboard.control_snapshot.as_deref()),
+        "controlSelectionKeys": parse_json_array(artboard.control_selection_keys.as_deref()),
+        "viewModelInstanceName": artboard.view_model_instance_name,
+    })
+}
+
+fn parse_json_array(raw: Option<&str>) -> Value {
+    raw.and_then(|raw| serde_json::from_str::<Value>(raw).ok())
+        .filter(Value::is_array)
+        .unwrap_or(Value::Null)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::build_artboard_switcher_config;
+    use crate::app::export::demo_bundle::build_demo_html;
+    use crate::app::export::fixtures::{demo_payload, embedded_json};
+    use crate::app::state::DemoArtboardConfig;
+    use serde_json::Value;
+
+    #[test]
+    fn single_artboard_payloads_have_no_switcher_entries() {
+        assert!(build_artboard_switcher_config(&demo_payload()).is_empty());
+    }
+
+    #[test]
+    fn switcher_lists_the_primary_artboard_first_and_inherits_layout() {
+        let mut payload = demo_payload();
+        payload.artboards = vec![
+            DemoArtboardConfig {
+                artboard_name: "Main".into(),
+                ..DemoArtboardConfig::default()
+            },
+            DemoArtboardConfig {
+                artboard_name: " Card ".into(),
+                state_machines: vec!["card-sm".into()],
+                layout_fit: Some("cover".into()),
+                control_snapshot: Some(
+                    r#"[{"descriptor":{"path":"title","kind":"string"},"kind":"string","value":"Hi"}]"#
+                        .into(),
+                ),
+                ..DemoArtboardConfig::default()
+            },
+            DemoArtboardConfig::default(),
+        ];
+        let entries = build_artboard_switcher_config(&payload);
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0]["artboardName"], "Main");
+        assert_eq!(entries[0]["stateMachines"][0], "main-sm");
+        assert_eq!(entries[1]["artboardName"], "Card");
+        assert_eq!(entries[1]["layoutFit"], "cover");
+        assert_eq!(entries[1]["layoutAlignment"], "center");
+        assert_eq!(entries[1]["controlSnapshot"][0]["value"], "Hi");
+        assert!(entries[1]["controlSelectionKeys"].is_null());
+
+        let html = build_demo_html(&payload).expect("demo html");
+        let config = embedded_json(&html, "const CONFIG = JSON.parse('");
+        assert_eq!(config["artboards"], Value::Array(entries));
+        assert!(html.contains("id=\"artboard-select\""));
+        assert_eq!(html.matches("\"animationBase64\"").count(), 1);
+    }
+
+    #[test]
+    fn unnamed_primary_artboard_resolves_to_the_first_listed_artboard() {
+        let mut payload = demo_payload();
+        payload.artboard_name = None;
+        payload.artboards = ["", "Main", "Card"]
+            .into_iter()
+            .map(|name| DemoArtboardConfig {
+                artboard_name: name.into(),
+                ..DemoArtboardConfig::default()
+            })
+            .collect();
+        let entries = build_artboard_switcher_config(&payload);
+
+        let names: Vec<_> = entries.iter().map(|entry| &entry["artboardName"]).collect();
+        assert_eq!(names, ["Main", "Card"]);
+        assert_eq!(entries[0]["stateMachines"][0], "main-sm");
+    }
+}
@@ -0,0 +1,3 @@
+pub mod artboards;
+pub mod presets;
+pub mod timeline;
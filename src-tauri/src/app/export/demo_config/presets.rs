@@ -0,0 +1,271 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::Manager;
+
+use crate::app::state::{DemoBundlePayload, ExportPreset};
+use crate::app::support::ensure_parent_directory;
+
+const PRESETS_FILE: &str = "export-presets.json";
+const PRESETS_VERSION: u32 = 1;
+
+#[derive(Default, Deserialize, Serialize)]
+#[serde(default)]
+struct ExportPresetStore {
+    version: u32,
+    presets: Vec<ExportPreset>,
+}
+
+#[tauri::command]
+pub fn list_export_presets(app: tauri::AppHandle) -> Result<Vec<ExportPreset>, String> {
+    load_export_presets(&export_presets_path(&app)?)
+}
+
+#[tauri::command]
+pub fn save_export_preset(
+    app: tauri::AppHandle,
+    preset: ExportPreset,
+) -> Result<Vec<ExportPreset>, String> {
+    let path = export_presets_path(&app)?;
+    let presets = upsert_export_preset(load_export_presets(&path)?, preset)?;
+    store_export_presets(&path, &presets)?;
+    Ok(presets)
+}
+
+#[tauri::command]
+pub fn delete_export_preset(
+    app: tauri::AppHandle,
+    name: String,
+) -> Result<Vec<ExportPreset>, String> {
+    let path = export_presets_path(&app)?;
+    let mut presets = load_export_presets(&path)?;
+    let before = presets.len();
+    presets.retain(|preset| preset.name != name.trim());
+    if presets.len() == before {
+        return Err(unknown_preset_error(&name));
+    }
+    store_export_presets(&path, &presets)?;
+    Ok(presets)
+}
+
+/// Returns the named preset so the front end can apply its settings to the live session.
+#[tauri::command]
+pub fn apply_export_preset(app: tauri::AppHandle, name: String) -> Result<ExportPreset, String> {
+    find_export_preset(&export_presets_path(&app)?, &name)
+}
+
+pub fn export_presets_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_config_dir()
+        .map(|path| path.join(PRESETS_FILE))
+        .map_err(|error| format!("Failed to resolve export presets path: {}", error))
+}
+
+pub fn load_export_presets(path: &Path) -> Result<Vec<ExportPreset>, String> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let raw = fs::read_to_string(path)
+        .map_err(|error| format!("Failed to read {}: {}", path.display(), error))?;
+    serde_json::from_str::<ExportPresetStore>(&raw)
+        .map(|store| store.presets)
+        .map_err(|error| format!("Failed to parse {}: {}", path.display(), error))
+}
+
+pub fn find_export_preset(path: &Path, name: &str) -> Result<ExportPreset, String> {
+    load_export_presets(path)?
+        .into_iter()
+        .find(|preset| preset.name == name.trim())
+        .ok_or_else(|| unknown_preset_error(name))
+}
+
+fn store_export_presets(path: &Path, presets: &[ExportPreset]) -> Result<(), String> {
+    ensure_parent_directory(path)?;
+    let store = ExportPresetStore {
+        version: PRESETS_VERSION,
+        presets: presets.to_vec(),
+    };
+    let json = serde_json::to_string_pretty(&store).map_err(|error| error.to_string())?;
+    fs::write(path, format!("{json}\n"))
+        .map_err(|error| format!("Failed to write {}: {}", path.display(), error))
+}
+
+fn upsert_export_preset(
+    mut presets: Vec<ExportPreset>,
+    mut preset: ExportPreset,
+) -> Result<Vec<ExportPreset>, String> {
+    preset.name = preset.name.trim().to_string();
+    if preset.name.is_empty() {
+        return Err("Preset name is empty".into());
+    }
+    if let Some(source) = preset.package_source.as_deref() {
+        if source != "cdn" && source != "local" {
+            return Err(format!(
+                "Unknown package_source \"{source}\" (expected cdn or local)"
+            ));
+        }
+    }
+    presets.retain(|existing| existing.name != preset.name);
+    presets.push(preset);
+    presets.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(presets)
+}
+
+fn unknown_preset_error(name: &str) -> String {
+    format!("Unknown export preset \"{}\"", name.trim())
+}
+
+/// Overrides the payload fields the preset sets; the rest of the live payload is kept.
+pub fn apply_export_preset_to_payload(preset: &ExportPreset, payload: &mut DemoBundlePayload) {
+    if let Some(color) = preset.canvas_color.as_deref() {
+        payload.canvas_color = Some(color.to_string());
+        payload.canvas_transparent = false;
+    }
+    if let Some(transparent) = preset.canvas_transparent {
+        payload.canvas_transparent = transparent;
+    }
+    if let Some(sizing) = preset.canvas_sizing.as_ref() {
+        payload.canvas_sizing = Some(sizing.to_string());
+    }
+    if let Some(keys) = preset.control_selection_keys.as_ref() {
+        payload.control_selection_keys = serde_json::to_string(keys).ok();
+    }
+    if let Some(source) = preset.package_source.as_deref() {
+        payload.default_instantiation_package_source = source.to_string();
+        let snippet = payload
+            .instantiation_snippets
+            .as_deref()
+            .and_then(|raw| serde_json::from_str::<serde_json::Value>(raw).ok())
+            .and_then(|snippets| snippets[source].as_str().map(str::to_string));
+        if let Some(snippet) = snippet {
+            payload.instantiation_code = snippet;
+        }
+    }
+}
+
+/// Resolves empty or relative output paths against the preset's output folder.
+pub fn resolve_preset_output_path(
+    preset: &ExportPreset,
+    output_path: &str,
+    file_name: &str,
+) -> String {
+    let Some(dir) = preset
+        .output_dir
+        .as_deref()
+        .filter(|dir| !dir.trim().is_empty())
+    else {
+        return output_path.to_string();
+    };
+    let output_path = output_path.trim();
+    if output_path.is_empty() {
+        return Path::new(dir)
+            .join(suggested_demo_file_name(file_name))
+            .to_string_lossy()
+            .to_string();
+    }
+    if Path::new(output_path).is_absolute() {
+        return output_path.to_string();
+    }
+    Path::new(dir)
+        .join(output_path)
+        .to_string_lossy()
+        .to_string()
+}
+
+pub fn suggested_demo_file_name(file_name: &str) -> String {
+    format!(
+        "{}-demo.html",
+        file_name.replace(|c: char| !c.is_ascii_alphanumeric(), "-")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        apply_export_preset_to_payload, find_export_preset, load_export_presets,
+        resolve_preset_output_path, store_export_presets, upsert_export_preset,
+    };
+    use crate::app::export::fixtures::demo_payload;
+    use crate::app::state::ExportPreset;
+    use serde_json::json;
+    use std::path::Path;
+
+    fn preset(name: &str) -> ExportPreset {
+        ExportPreset {
+            name: name.into(),
+            ..ExportPreset::default()
+        }
+    }
+
+    #[test]
+    fn presets_round_trip_through_the_store_file_sorted_by_name() {
+        let dir = std::env::temp_dir().join(format!("rav-presets-{}", std::process::id()));
+        let path = dir.join("export-presets.json");
+        let presets = upsert_export_preset(Vec::new(), preset("zeta")).expect("zeta");
+        let presets = upsert_export_preset(
+            presets,
+            ExportPreset {
+                output_dir: Some("/tmp/out".into()),
+                ..preset(" alpha ")
+            },
+        )
+        .expect("alpha");
+        let presets = upsert_export_preset(presets, preset("alpha")).expect("replace alpha");
+        store_export_presets(&path, &presets).expect("store");
+
+        let loaded = load_export_presets(&path).expect("load");
+        let names: Vec<&str> = loaded.iter().map(|preset| preset.name.as_str()).collect();
+        assert_eq!(names, ["alpha", "zeta"]);
+        assert!(loaded[0].output_dir.is_none());
+        assert!(find_export_preset(&path, "zeta").is_ok());
+        assert_eq!(
+            find_export_preset(&path, "missing").err().as_deref(),
+            Some("Unknown export preset \"missing\"")
+        );
+        assert!(upsert_export_preset(Vec::new(), preset("  ")).is_err());
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn applying_a_preset_overrides_only_the_fields_it_sets() {
+        let mut payload = demo_payload();
+        payload.canvas_transparent = true;
+        payload.instantiation_snippets =
+            Some(json!({ "cdn": "cdn()", "local": "local()" }).to_string());
+        let preset = ExportPreset {
+            canvas_color: Some("#ffffff".into()),
+            canvas_sizing: Some(json!({ "mode": "fixed", "width": 640, "height": 480 })),
+            package_source: Some("local".into()),
+            control_selection_keys: Some(vec!["vm:title:string".into()]),
+            output_dir: Some("/srv/demos".into()),
+            ..preset("handoff")
+        };
+        apply_export_preset_to_payload(&preset, &mut payload);
+
+        assert_eq!(payload.canvas_color.as_deref(), Some("#ffffff"));
+        assert!(!payload.canvas_transparent);
+        assert_eq!(payload.default_instantiation_package_source, "local");
+        assert_eq!(payload.instantiation_code, "local()");
+        assert_eq!(
+            payload.control_selection_keys.as_deref(),
+            Some("[\"vm:title:string\"]")
+        );
+        assert_eq!(payload.layout_fit, "contain");
+        assert_eq!(
+            resolve_preset_output_path(&preset, "", "my card.riv"),
+            Path::new("/srv/demos")
+                .join("my-card-riv-demo.html")
+                .to_string_lossy()
+        );
+        assert_eq!(
+            resolve_preset_output_path(&preset, "/tmp/a.html", "x.riv"),
+            "/tmp/a.html"
+        );
+        assert_eq!(
+            resolve_preset_output_path(&preset, "nested/a.html", "x.riv"),
+            Path::new("/srv/demos")
+                .join("nested/a.html")
+                .to_string_lossy()
+        );
+    }
+}
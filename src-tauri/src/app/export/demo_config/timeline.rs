@@ -0,0 +1,186 @@
+use serde_json::{json, Value};
+
+use crate::app::state::{DemoTimeline, DemoTimelineStep};
+
+const VIEW_MODEL_KINDS: [&str; 6] = ["number", "boolean", "string", "enum", "color", "trigger"];
+const STATE_MACHINE_KINDS: [&str; 3] = ["number", "boolean", "trigger"];
+/// How long the last step stays on screen before the timeline ends or loops.
+const DEFAULT_TAIL_MS: u64 = 1000;
+
+/// The demo's `timeline` config, or null when the payload has no steps.
+pub fn build_timeline_config(timeline: Option<&DemoTimeline>) -> Value {
+    let Some(timeline) = timeline.filter(|timeline| !timeline.steps.is_empty()) else {
+        return Value::Null;
+    };
+
+    let mut steps: Vec<&DemoTimelineStep> = timeline
+        .steps
+        .iter()
+        .filter(|step| step_problem(step).is_none())
+        .collect();
+    steps.sort_by_key(|step| step.at_ms);
+    let last_step_ms = steps.last().map_or(0, |step| step.at_ms);
+
+    json!({
+        "loop": timeline.loop_playback,
+        "autoplay": timeline.autoplay.unwrap_or(true),
+        "durationMs": timeline
+            .duration_ms
+            .unwrap_or(last_step_ms + DEFAULT_TAIL_MS)
+            .max(last_step_ms),
+        "steps": steps
+            .iter()
+            .map(|step| {
+                let mut entry = json!({ "atMs": step.at_ms, "kind": step.kind });
+                match state_machine_target(step) {
+                    Some((state_machine, input)) => {
+                        entry["stateMachine"] = json!(state_machine);
+                        entry["input"] = json!(input);
+                    }
+                    None => entry["path"] = json!(step.path.as_deref().unwrap_or_default().trim()),
+                }
+                if step.kind != "trigger" {
+                    entry["value"] = step.value.clone();
+                }
+                entry
+            })
+            .collect::<Vec<_>>(),
+    })
+}
+
+/// One message for the whole timeline, or None when every step is playable.
+pub fn describe_timeline_problems(timeline: Option<&DemoTimeline>) -> Option<String> {
+    let problems: Vec<String> = timeline
+        .into_iter()
+        .flat_map(|timeline| timeline.steps.iter().enumerate())
+        .filter_map(|(index, step)| {
+            step_problem(step).map(|problem| format!("step {}: {problem}", index + 1))
+        })
+        .collect();
+    (!problems.is_empty()).then(|| format!("Invalid timeline: {}", problems.join("; ")))
+}
+
+fn state_machine_target(step: &DemoTimelineStep) -> Option<(&str, &str)> {
+    let state_machine = step.state_machine.as_deref().map(str::trim)?;
+    let input = step.input.as_deref().map(str::trim).unwrap_or_default();
+    Some((state_machine, input))
+}
+
+fn step_problem(step: &DemoTimelineStep) -> Option<String> {
+    let kind = step.kind.as_str();
+    match state_machine_target(step) {
+        Some((state_machine, input)) => {
+            if state_machine.is_empty() || input.is_empty() {
+                return Some("state-machine steps need both state_machine and input".into());
+            }
+            if !STATE_MACHINE_KINDS.contains(&kind) {
+                return Some(format!(
+                    "state-machine inputs cannot have kind \"{kind}\" (expected number, boolean or trigger)"
+                ));
+            }
+        }
+        None => {
+            if step
+                .path
+                .as_deref()
+                .is_none_or(|path| path.trim().is_empty())
+            {
+                return Some("needs a ViewModel path or a state_machine and input".into());
+            }
+            if !VIEW_MODEL_KINDS.contains(&kind) {
+                return Some(format!("unknown kind \"{kind}\""));
+            }
+        }
+    }
+
+    let value = &step.value;
+    let value_matches = match kind {
+        "number" => value.is_number(),
+        "boolean" => value.is_boolean(),
+        "string" | "enum" => value.is_string(),
+        "color" => value
+            .as_u64()
+            .is_some_and(|color| color <= u64::from(u32::MAX)),
+        _ => true,
+    };
+    (!value_matches).then(|| format!("value {value} does not match kind \"{kind}\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_timeline_config, describe_timeline_problems};
+    use crate::app::state::{DemoTimeline, DemoTimelineStep};
+    use serde_json::json;
+
+    fn step(at_ms: u64, kind: &str, path: &str, value: serde_json::Value) -> DemoTimelineStep {
+        DemoTimelineStep {
+            at_ms,
+            kind: kind.into(),
+            path: Some(path.into()),
+            value,
+            ..DemoTimelineStep::default()
+        }
+    }
+
+    #[test]
+    fn steps_are_sorted_and_state_machine_targets_keep_their_input() {
+        let timeline = DemoTimeline {
+            loop_playback: true,
+            steps: vec![
+                step(1500, "enum", "mode", json!("dark")),
+                step(0, "number", "score", json!(42)),
+                DemoTimelineStep {
+                    at_ms: 800,
+                    kind: "trigger".into(),
+                    state_machine: Some("main-sm".into()),
+                    input: Some("pulse".into()),
+                    value: json!(true),
+                    ..DemoTimelineStep::default()
+                },
+            ],
+            ..DemoTimeline::default()
+        };
+        let config = build_timeline_config(Some(&timeline));
+
+        assert_eq!(config["loop"], true);
+        assert_eq!(config["autoplay"], true);
+        assert_eq!(config["durationMs"], 2500);
+        assert_eq!(
+            config["steps"][0],
+            json!({"atMs": 0, "kind": "number", "path": "score", "value": 42})
+        );
+        assert_eq!(
+            config["steps"][1],
+            json!({"atMs": 800, "kind": "trigger", "stateMachine": "main-sm", "input": "pulse"})
+        );
+        assert_eq!(config["steps"][2]["value"], "dark");
+        assert!(describe_timeline_problems(Some(&timeline)).is_none());
+        assert!(build_timeline_config(None).is_null());
+    }
+
+    #[test]
+    fn problems_name_every_unplayable_step() {
+        let timeline = DemoTimeline {
+            steps: vec![
+                step(0, "number", "score", json!("high")),
+                step(100, "enum", " ", json!("dark")),
+                DemoTimelineStep {
+                    kind: "string".into(),
+                    state_machine: Some("main-sm".into()),
+                    input: Some("label".into()),
+                    ..DemoTimelineStep::default()
+                },
+                step(200, "trigger", "pulse", json!(null)),
+            ],
+            ..DemoTimeline::default()
+        };
+        let message = describe_timeline_problems(Some(&timeline)).expect("problems");
+
+        assert!(message.starts_with(
+            "Invalid timeline: step 1: value \"high\" does not match kind \"number\""
+        ));
+        assert!(message.contains("step 2: needs a ViewModel path"));
+        assert!(message.contains("step 3: state-machine inputs cannot have kind \"string\""));
+        assert!(!message.contains("step 4"));
+    }
+}
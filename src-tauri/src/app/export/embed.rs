@@ -0,0 +1,292 @@
+use serde_json::{json, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::app::constants::RAV_HOMEPAGE_URL;
+use crate::app::export::demo_bundle::{
+    app_icon_data_url, build_demo_config, escape_embedded_script_json,
+};
+use crate::app::export::provenance::build_provenance_json;
+use crate::app::state::DemoBundlePayload;
+
+const EMBED_TEMPLATE_SHELL: &str = include_str!("../../demo-template/embed.html");
+const EMBED_TEMPLATE_APP_JS: &str = concat!(
+    include_str!("../../demo-template/js/embed/preamble.js"),
+    "\n",
+    include_str!("../../demo-template/js/core/layout.js"),
+    "\n",
+    include_str!("../../demo-template/js/vm/accessors.js"),
+    "\n",
+    include_str!("../../demo-template/js/embed/player.js"),
+);
+const DEFAULT_BACKGROUND: &str = "#0d1117";
+const DEFAULT_FRAME_WIDTH: u32 = 1280;
+const DEFAULT_FRAME_HEIGHT: u32 = 720;
+const MAX_FRAME_DIMENSION: u32 = 8192;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct EmbedFrameSize {
+    pub width: u32,
+    pub height: u32,
+}
+
+pub struct EmbedVariantPaths {
+    pub html: PathBuf,
+    pub oembed: PathBuf,
+    pub iframe: PathBuf,
+}
+
+pub fn resolve_embed_frame_size(payload: &DemoBundlePayload) -> EmbedFrameSize {
+    let sizing = payload
+        .canvas_sizing
+        .as_deref()
+        .and_then(|raw| serde_json::from_str::<Value>(raw).ok())
+        .unwrap_or(Value::Null);
+    let dimension = |key: &str, fallback: u32| {
+        sizing
+            .get(key)
+            .and_then(Value::as_f64)
+            .filter(|value| value.is_finite() && *value >= 1.0)
+            .map(|value| (value.round() as u32).min(MAX_FRAME_DIMENSION))
+            .unwrap_or(fallback)
+    };
+
+    EmbedFrameSize {
+        width: dimension("width", DEFAULT_FRAME_WIDTH),
+        height: dimension("height", DEFAULT_FRAME_HEIGHT),
+    }
+}
+
+pub fn embed_variant_paths(html_path: &Path) -> EmbedVariantPaths {
+    let stem = html_path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| "demo".into());
+    let sibling = |suffix: &str| html_path.with_file_name(format!("{stem}.{suffix}"));
+
+    EmbedVariantPaths {
+        html: sibling("embed.html"),
+        oembed: sibling("oembed.json"),
+        iframe: sibling("iframe.html"),
+    }
+}
+
+pub fn build_embed_html(payload: &DemoBundlePayload) -> Result<String, serde_json::Error> {
+    let config_json = serde_json::to_string(&build_demo_config(payload))?;
+    let escaped_config = escape_embedded_script_json(&config_json);
+    let escaped_runtime = payload.runtime_script.replace("</script", "<\\/script");
+    let background = css_background(payload);
+    let title = format!("{} – Rive Embed", payload.file_name);
+
+    Ok(EMBED_TEMPLATE_SHELL
+        .replace("__EMBED_APP_JS__", EMBED_TEMPLATE_APP_JS)
+        .replace("__TITLE__", &escape_html_attribute(&title))
+        .replace("__APP_ICON_DATA_URL__", &app_icon_data_url())
+        .replace("__EMBED_BACKGROUND__", background)
+        .replace("__CONFIG_JSON__", &escaped_config)
+        .replace("__PROVENANCE_JSON__", &build_provenance_json(payload))
+        .replace("__RUNTIME_SCRIPT__", &escaped_runtime))
+}
+
+/// The canvas background for a CSS rule. Anything but a hex or `rgb()`/`rgba()` color falls
+/// back to the default, so a stray value cannot close the rule and inject its own.
+pub fn css_background(payload: &DemoBundlePayload) -> &str {
+    if payload.canvas_transparent {
+        return "transparent";
+    }
+    payload
+        .canvas_color
+        .as_deref()
+        .map(str::trim)
+        .filter(|color| is_plain_css_color(color))
+        .unwrap_or(DEFAULT_BACKGROUND)
+}
+
+fn is_plain_css_color(color: &str) -> bool {
+    if let Some(hex) = color.strip_prefix('#') {
+        return matches!(hex.len(), 3 | 4 | 6 | 8) && hex.chars().all(|ch| ch.is_ascii_hexdigit());
+    }
+    let Some(arguments) = color
+        .strip_prefix("rgba(")
+        .or_else(|| color.strip_prefix("rgb("))
+        .and_then(|rest| rest.strip_suffix(')'))
+    else {
+        return false;
+    };
+    arguments.chars().any(|ch| ch.is_ascii_digit())
+        && arguments
+            .chars()
+            .all(|ch| ch.is_ascii_digit() || matches!(ch, '.' | ',' | '%' | '/' | ' '))
+}
+
+pub fn build_iframe_snippet(payload: &DemoBundlePayload, src: &str) -> String {
+    let size = resolve_embed_frame_size(payload);
+    format!(
+        "<iframe src=\"{}\" title=\"{}\" width=\"{}\" height=\"{}\" style=\"border:0;max-width:100%;\" loading=\"lazy\" allow=\"fullscreen\"></iframe>",
+        escape_html_attribute(src),
+        escape_html_attribute(&payload.file_name),
+        size.width,
+        size.height
+    )
+}
+
+pub fn build_oembed_manifest(payload: &DemoBundlePayload, src: &str) -> Value {
+    let size = resolve_embed_frame_size(payload);
+    json!({
+        "version": "1.0",
+        "type": "rich",
+        "title": payload.file_name,
+        "provider_name": "Rive Animation Viewer",
+        "provider_url": RAV_HOMEPAGE_URL,
+        "width": size.width,
+        "height": size.height,
+        "html": build_iframe_snippet(payload, src),
+    })
+}
+
+pub fn resolve_embed_src(embed_path: &Path, base_url: Option<&str>) -> String {
+    let file_name = embed_path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| "embed.html".into());
+    match base_url.map(str::trim).filter(|url| !url.is_empty()) {
+        Some(base_url) => format!("{}/{}", base_url.trim_end_matches('/'), file_name),
+        None => file_name,
+    }
+}
+
+pub fn write_embed_variants(
+    payload: &DemoBundlePayload,
+    html_path: &Path,
+    base_url: Option<&str>,
+) -> Result<EmbedVariantPaths, String> {
+    let paths = embed_variant_paths(html_path);
+    let src = resolve_embed_src(&paths.html, base_url);
+    let embed_html = build_embed_html(payload).map_err(|error| error.to_string())?;
+    let oembed = serde_json::to_string_pretty(&build_oembed_manifest(payload, &src))
+        .map_err(|error| error.to_string())?;
+
+    fs::write(&paths.html, embed_html)
+        .map_err(|error| format!("Failed to write {}: {}", paths.html.display(), error))?;
+    fs::write(&paths.oembed, format!("{oembed}\n"))
+        .map_err(|error| format!("Failed to write {}: {}", paths.oembed.display(), error))?;
+    fs::write(
+        &paths.iframe,
+        format!("{}\n", build_iframe_snippet(payload, &src)),
+    )
+    .map_err(|error| format!("Failed to write {}: {}", paths.iframe.display(), error))?;
+    Ok(paths)
+}
+
+fn escape_html_attribute(raw: &str) -> String {
+    raw.replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        build_embed_html, build_iframe_snippet, build_oembed_manifest, css_background,
+        embed_variant_paths, resolve_embed_frame_size, resolve_embed_src, EmbedFrameSize,
+    };
+    use crate::app::export::demo_bundle::build_demo_config;
+    use crate::app::export::fixtures::{demo_payload, embedded_json, inline_json};
+    use crate::app::export::provenance::build_provenance_json;
+    use crate::app::export::verify::find_placeholders;
+    use serde_json::Value;
+    use std::path::Path;
+
+    #[test]
+    fn sizes_the_iframe_from_canvas_sizing_with_a_default_fallback() {
+        let mut payload = demo_payload();
+        assert_eq!(
+            resolve_embed_frame_size(&payload),
+            EmbedFrameSize {
+                width: 1280,
+                height: 720
+            }
+        );
+
+        payload.canvas_sizing = Some(r#"{"mode":"fixed","width":640.4,"height":360}"#.into());
+        let snippet = build_iframe_snippet(&payload, "demo.embed.html");
+
+        assert!(snippet.contains("width=\"640\""));
+        assert!(snippet.contains("height=\"360\""));
+        assert!(snippet.contains("src=\"demo.embed.html\""));
+    }
+
+    #[test]
+    fn oembed_manifest_embeds_the_same_iframe_snippet() {
+        let payload = demo_payload();
+        let src = resolve_embed_src(
+            Path::new("/tmp/out/demo.embed.html"),
+            Some("https://cdn.example.com/demos/"),
+        );
+        let manifest = build_oembed_manifest(&payload, &src);
+
+        assert_eq!(src, "https://cdn.example.com/demos/demo.embed.html");
+        assert_eq!(manifest["type"], "rich");
+        assert_eq!(manifest["version"], "1.0");
+        assert_eq!(manifest["width"], 1280);
+        assert_eq!(manifest["html"], build_iframe_snippet(&payload, &src));
+    }
+
+    #[test]
+    fn embed_html_has_no_chrome_or_control_panels() {
+        let payload = demo_payload();
+        let html = build_embed_html(&payload).expect("embed html");
+        let paths = embed_variant_paths(Path::new("/tmp/out/demo.html"));
+
+        let provenance: Value =
+            serde_json::from_str(&build_provenance_json(&payload)).expect("provenance json");
+
+        assert_eq!(
+            embedded_json(&html, "const CONFIG = JSON.parse('"),
+            build_demo_config(&payload)
+        );
+        assert_eq!(
+            inline_json(
+                &html,
+                "<script type=\"application/json\" id=\"rav-provenance\">"
+            ),
+            provenance
+        );
+        assert!(
+            html.contains("bindViewModelInstanceByKey(riveInstance, CONFIG.viewModelInstanceName)")
+        );
+        assert!(!html.contains("top-bar"));
+        assert!(!html.contains("properties-panel"));
+        assert!(!html.contains("event-log-panel"));
+        assert_eq!(find_placeholders(&html), Vec::<&str>::new());
+        assert_eq!(paths.html, Path::new("/tmp/out/demo.embed.html"));
+        assert_eq!(paths.oembed, Path::new("/tmp/out/demo.oembed.json"));
+        assert_eq!(paths.iframe, Path::new("/tmp/out/demo.iframe.html"));
+    }
+
+    #[test]
+    fn embed_background_accepts_only_plain_colors() {
+        let mut payload = demo_payload();
+        let mut background = |color: &str| {
+            payload.canvas_color = Some(color.into());
+            css_background(&payload).to_string()
+        };
+
+        assert_eq!(background("#1a2B3c"), "#1a2B3c");
+        assert_eq!(
+            background(" rgba(13, 17, 23, 0.5) "),
+            "rgba(13, 17, 23, 0.5)"
+        );
+        assert_eq!(background("#12345"), "#0d1117");
+        assert_eq!(background("red;}body{display:none"), "#0d1117");
+        assert_eq!(background("rgb(0 0 0) url(x)"), "#0d1117");
+
+        payload.canvas_color = Some("#fff}</style><script>alert(1)</script>".into());
+        let html = build_embed_html(&payload).expect("embed html");
+        let style =
+            &html[html.find("<style>").expect("style")..html.find("</style>").expect("end")];
+        assert!(style.contains("background: #0d1117;"));
+        assert!(!style.contains("#fff"));
+    }
+}
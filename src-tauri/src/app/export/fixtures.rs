@@ -0,0 +1,53 @@
+use serde_json::Value;
+
+use crate::app::export::verify::read_script_literal;
+use crate::app::state::DemoBundlePayload;
+
+pub fn demo_payload() -> DemoBundlePayload {
+    DemoBundlePayload {
+        animation_base64: "AQID".into(),
+        animations: vec![],
+        artboard_name: Some("Main".into()),
+        autoplay: true,
+        canvas_color: Some("#0d1117".into()),
+        default_instantiation_package_source: "cdn".into(),
+        file_name: "demo.riv".into(),
+        instantiation_code: "console.log('snippet');".into(),
+        instantiation_source_mode: "internal".into(),
+        layout_alignment: "center".into(),
+        layout_fit: "contain".into(),
+        layout_state: Some("{}".into()),
+        runtime_name: "webgl2".into(),
+        runtime_script: "console.log('runtime');".into(),
+        runtime_version: Some("2.37.0".into()),
+        state_machines: vec!["main-sm".into()],
+        ..DemoBundlePayload::default()
+    }
+}
+
+/// `state_machine_hierarchy` as the app's `serializeStateMachineHierarchy()` sends it.
+pub const STATE_MACHINE_HIERARCHY: &str = r#"{"children":[{"children":[],"inputs":[
+    {"descriptor":{"kind":"boolean","name":"armed","path":"stateMachine/main-sm/armed","source":"state-machine","stateMachineName":"main-sm"},"kind":"boolean","name":"armed","path":"stateMachine/main-sm/armed","source":"state-machine","stateMachineName":"main-sm","value":true},
+    {"descriptor":{"kind":"trigger","name":"fire","path":"stateMachine/main-sm/fire","source":"state-machine","stateMachineName":"main-sm"},"kind":"trigger","name":"fire","path":"stateMachine/main-sm/fire","source":"state-machine","stateMachineName":"main-sm","value":null}
+],"kind":"state-machine","label":"main-sm","path":"stateMachine/main-sm"}],"inputs":[],"kind":"state-machines","label":"State Machines","path":"__state_machines__"}"#;
+
+/// The JSON a generated file passes to `JSON.parse('…')` right after `marker`, unescaped.
+pub fn embedded_json(source: &str, marker: &str) -> Value {
+    let start = source
+        .find(marker)
+        .unwrap_or_else(|| panic!("missing {marker}"));
+    let literal = read_script_literal(&source[start + marker.len()..]).expect("string literal");
+    serde_json::from_str(&literal).expect("embedded json")
+}
+
+/// The JSON value written inline right after `marker`, such as `const APP_SHELL = […];`.
+pub fn inline_json(source: &str, marker: &str) -> Value {
+    let start = source
+        .find(marker)
+        .unwrap_or_else(|| panic!("missing {marker}"));
+    serde_json::Deserializer::from_str(&source[start + marker.len()..])
+        .into_iter::<Value>()
+        .next()
+        .expect("inline json")
+        .expect("valid json")
+}
@@ -0,0 +1,277 @@
+use serde_json::{json, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::app::export::demo_config::artboards::build_artboard_switcher_config;
+use crate::app::export::provenance::{build_provenance, canonicalize_json};
+use crate::app::state::DemoBundlePayload;
+
+pub const INTERFACE_MANIFEST_SCHEMA: &str = "rav.interface";
+pub const INTERFACE_MANIFEST_SCHEMA_VERSION: u32 = 1;
+const STATE_MACHINE_INPUT_SOURCE: &str = "state-machine";
+
+pub fn interface_manifest_path(html_path: &Path) -> PathBuf {
+    let stem = html_path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| "demo".into());
+    html_path.with_file_name(format!("{stem}.rav.json"))
+}
+
+/// Parses one of the hierarchies the app serializes into the payload.
+pub fn parse_hierarchy(raw: Option<&str>) -> Option<Value> {
+    raw.and_then(|raw| serde_json::from_str::<Value>(raw).ok())
+        .filter(Value::is_object)
+}
+
+/// Inputs are only known for the artboard loaded at export time; other switcher artboards list
+/// their state machines without inputs.
+pub fn build_interface_manifest(payload: &DemoBundlePayload) -> Value {
+    let hierarchy = parse_hierarchy(payload.vm_hierarchy.as_deref());
+    let state_machine_hierarchy = parse_hierarchy(payload.state_machine_hierarchy.as_deref());
+    let mut properties = Vec::new();
+    let mut state_machine_inputs = Vec::new();
+    for root in [hierarchy.as_ref(), state_machine_hierarchy.as_ref()]
+        .into_iter()
+        .flatten()
+    {
+        collect_interface_entries(root, &mut properties, &mut state_machine_inputs);
+    }
+
+    let switcher = build_artboard_switcher_config(payload);
+    let artboards: Vec<Value> = if switcher.is_empty() {
+        vec![artboard_entry(
+            json!(payload.artboard_name),
+            json!(payload.animations),
+            &json!(payload.state_machines),
+            &state_machine_inputs,
+        )]
+    } else {
+        switcher
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| {
+                let inputs = if index == 0 {
+                    &state_machine_inputs[..]
+                } else {
+                    &[]
+                };
+                artboard_entry(
+                    entry["artboardName"].clone(),
+                    entry["animations"].clone(),
+                    &entry["stateMachines"],
+                    inputs,
+                )
+            })
+            .collect()
+    };
+    let view_model = hierarchy.as_ref().map(|root| {
+        json!({
+            "name": root.get("label").cloned().unwrap_or(Value::Null),
+            "instanceName": payload.view_model_instance_name,
+            "properties": properties,
+        })
+    });
+
+    canonicalize_json(json!({
+        "schema": INTERFACE_MANIFEST_SCHEMA,
+        "schemaVersion": INTERFACE_MANIFEST_SCHEMA_VERSION,
+        "provenance": build_provenance(payload),
+        "artboards": artboards,
+        "viewModel": view_model,
+    }))
+}
+
+fn artboard_entry(
+    name: Value,
+    animations: Value,
+    state_machines: &Value,
+    state_machine_inputs: &[(String, Value)],
+) -> Value {
+    let state_machines: Vec<Value> = state_machines
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .map(|name| {
+            let inputs: Vec<Value> = state_machine_inputs
+                .iter()
+                .filter(|(owner, _)| owner == name)
+                .map(|(_, input)| input.clone())
+                .collect();
+            json!({ "name": name, "inputs": inputs })
+        })
+        .collect();
+    json!({
+        "name": name,
+        "animations": animations,
+        "stateMachines": state_machines,
+    })
+}
+
+pub fn write_interface_manifest(
+    payload: &DemoBundlePayload,
+    html_path: &Path,
+) -> Result<PathBuf, String> {
+    let path = interface_manifest_path(html_path);
+    let manifest = serde_json::to_string_pretty(&build_interface_manifest(payload))
+        .map_err(|error| error.to_string())?;
+    fs::write(&path, format!("{manifest}\n"))
+        .map_err(|error| format!("Failed to write {}: {}", path.display(), error))?;
+    Ok(path)
+}
+
+fn collect_interface_entries(
+    node: &Value,
+    properties: &mut Vec<Value>,
+    state_machine_inputs: &mut Vec<(String, Value)>,
+) {
+    for input in node
+        .get("inputs")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        let field = |key: &str| {
+            input
+                .get(key)
+                .or_else(|| {
+                    input
+                        .get("descriptor")
+                        .and_then(|descriptor| descriptor.get(key))
+                })
+                .cloned()
+                .unwrap_or(Value::Null)
+        };
+        let mut entry = json!({
+            "name": field("name"),
+            "path": field("path"),
+            "type": field("kind"),
+            "default": input.get("value").cloned().unwrap_or(Value::Null),
+        });
+        if let Some(enum_values) = input.get("enumValues") {
+            entry["enumValues"] = enum_values.clone();
+        }
+
+        match field("stateMachineName").as_str() {
+            Some(owner) if field("source") == STATE_MACHINE_INPUT_SOURCE => {
+                state_machine_inputs.push((owner.to_string(), entry));
+            }
+            _ => properties.push(entry),
+        }
+    }
+
+    for child in node
+        .get("children")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        if child.get("kind").and_then(Value::as_str) == Some("list") {
+            let path = child
+                .get("path")
+                .and_then(Value::as_str)
+                .unwrap_or_default();
+            properties.push(json!({
+                "name": path.rsplit('/').next().unwrap_or(path),
+                "path": path,
+                "type": "list",
+                "default": Value::Null,
+                "length": child.get("children").and_then(Value::as_array).map_or(0, Vec::len),
+            }));
+        }
+        collect_interface_entries(child, properties, state_machine_inputs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_interface_manifest, interface_manifest_path};
+    use crate::app::export::fixtures::{demo_payload, STATE_MACHINE_HIERARCHY};
+    use crate::app::state::DemoArtboardConfig;
+    use serde_json::json;
+    use std::path::Path;
+
+    #[test]
+    fn manifest_lists_typed_vm_properties_with_defaults() {
+        let mut payload = demo_payload();
+        payload.view_model_instance_name = Some("Preview".into());
+        payload.vm_hierarchy = Some(
+            r#"{"kind":"vm","label":"Card","path":"<root>","inputs":[
+                {"kind":"number","name":"score","path":"score","value":42},
+                {"kind":"enum","name":"mode","path":"mode","value":"dark","enumValues":["light","dark"]}
+            ],"children":[
+                {"kind":"list","label":"rows [1]","path":"rows","inputs":[],"children":[
+                    {"kind":"instance","label":"Row 1","path":"rows/0","children":[],"inputs":[
+                        {"kind":"string","name":"title","path":"rows/0/title","value":"Hello"}
+                    ]}
+                ]}
+            ]}"#
+            .into(),
+        );
+
+        let manifest = build_interface_manifest(&payload);
+        let properties = manifest["viewModel"]["properties"]
+            .as_array()
+            .expect("properties");
+
+        assert_eq!(manifest["schema"], "rav.interface");
+        assert_eq!(manifest["schemaVersion"], 1);
+        assert_eq!(manifest["artboards"][0]["name"], "Main");
+        assert_eq!(manifest["viewModel"]["name"], "Card");
+        assert_eq!(manifest["viewModel"]["instanceName"], "Preview");
+        assert_eq!(properties.len(), 4);
+        assert_eq!(properties[0]["default"], 42);
+        assert_eq!(properties[1]["enumValues"][1], "dark");
+        assert_eq!(properties[2]["type"], "list");
+        assert_eq!(properties[2]["length"], 1);
+        assert_eq!(properties[3]["path"], "rows/0/title");
+        assert_eq!(
+            interface_manifest_path(Path::new("/tmp/out/demo.html")),
+            Path::new("/tmp/out/demo.rav.json")
+        );
+    }
+
+    #[test]
+    fn manifest_groups_state_machine_inputs_under_their_machine() {
+        let mut payload = demo_payload();
+        payload.state_machine_hierarchy = Some(STATE_MACHINE_HIERARCHY.into());
+
+        let manifest = build_interface_manifest(&payload);
+        let state_machine = &manifest["artboards"][0]["stateMachines"][0];
+
+        assert_eq!(state_machine["name"], "main-sm");
+        assert_eq!(state_machine["inputs"][0]["name"], "armed");
+        assert_eq!(state_machine["inputs"][0]["type"], "boolean");
+        assert_eq!(state_machine["inputs"][0]["default"], true);
+        assert_eq!(state_machine["inputs"][1]["type"], "trigger");
+        assert!(manifest["viewModel"].is_null());
+    }
+
+    #[test]
+    fn manifest_describes_every_switcher_artboard() {
+        let mut payload = demo_payload();
+        payload.state_machine_hierarchy = Some(STATE_MACHINE_HIERARCHY.into());
+        payload.artboards = vec![DemoArtboardConfig {
+            artboard_name: "Card".into(),
+            state_machines: vec!["card-sm".into()],
+            animations: vec!["idle".into()],
+            ..DemoArtboardConfig::default()
+        }];
+
+        let artboards = build_interface_manifest(&payload)["artboards"].clone();
+
+        assert_eq!(artboards.as_array().map(Vec::len), Some(2));
+        assert_eq!(artboards[0]["name"], "Main");
+        assert_eq!(
+            artboards[0]["stateMachines"][0]["inputs"][1]["name"],
+            "fire"
+        );
+        assert_eq!(artboards[1]["name"], "Card");
+        assert_eq!(artboards[1]["animations"][0], "idle");
+        assert_eq!(
+            artboards[1]["stateMachines"],
+            json!([{ "name": "card-sm", "inputs": [] }])
+        );
+    }
+}
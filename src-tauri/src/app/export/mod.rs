@@ -0,0 +1,13 @@
+pub mod csp;
+pub mod demo_bundle;
+pub mod demo_config;
+pub mod embed;
+pub mod manifest;
+pub mod provenance;
+pub mod spec_sheet;
+pub mod targets;
+pub mod verify;
+pub mod watch;
+
+#[cfg(test)]
+mod fixtures;
@@ -0,0 +1,147 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde_json::{json, Map, Value};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+
+use crate::app::state::DemoBundlePayload;
+
+const CONTENT_HASH_LENGTH: usize = 12;
+
+pub fn sha256_hex(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+pub fn canonicalize_json(value: Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<(String, Value)> = map.into_iter().collect();
+            entries.sort_by(|left, right| left.0.cmp(&right.0));
+            Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, canonicalize_json(value)))
+                    .collect::<Map<String, Value>>(),
+            )
+        }
+        Value::Array(items) => Value::Array(items.into_iter().map(canonicalize_json).collect()),
+        other => other,
+    }
+}
+
+pub fn canonical_json_string(raw: &str) -> String {
+    serde_json::from_str::<Value>(raw)
+        .map(|value| canonicalize_json(value).to_string())
+        .unwrap_or_else(|_| raw.to_string())
+}
+
+/// The source hash and size are left out when `animation_base64` does not decode, rather than
+/// recording a hash of something other than the .riv.
+pub fn build_provenance(payload: &DemoBundlePayload) -> Value {
+    let mut source = json!({ "fileName": payload.file_name });
+    if let Ok(source_bytes) = STANDARD.decode(payload.animation_base64.trim()) {
+        source["sha256"] = json!(sha256_hex(&source_bytes));
+        source["size"] = json!(source_bytes.len());
+    }
+
+    canonicalize_json(json!({
+        "generator": "Rive Animation Viewer",
+        "ravVersion": env!("CARGO_PKG_VERSION"),
+        "runtime": {
+            "name": payload.runtime_name,
+            "version": payload.runtime_version,
+        },
+        "source": source,
+    }))
+}
+
+pub fn build_provenance_json(payload: &DemoBundlePayload) -> String {
+    build_provenance(payload)
+        .to_string()
+        .replace("</script", "<\\/script")
+}
+
+pub fn content_addressed_path(path: &Path, contents: &[u8]) -> PathBuf {
+    let hash = sha256_hex(contents);
+    let stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| "demo".into());
+    let file_name = match path.extension() {
+        Some(extension) => format!(
+            "{stem}.{}.{}",
+            &hash[..CONTENT_HASH_LENGTH],
+            extension.to_string_lossy()
+        ),
+        None => format!("{stem}.{}", &hash[..CONTENT_HASH_LENGTH]),
+    };
+    path.with_file_name(file_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_provenance, canonicalize_json, content_addressed_path, sha256_hex};
+    use crate::app::export::demo_bundle::build_demo_html;
+    use crate::app::export::fixtures::demo_payload;
+    use serde_json::json;
+    use std::path::Path;
+
+    #[test]
+    fn canonical_json_sorts_nested_object_keys() {
+        let value =
+            canonicalize_json(json!({ "b": { "z": 1, "a": [ { "y": 1, "x": 2 } ] }, "a": 0 }));
+
+        assert_eq!(
+            value.to_string(),
+            r#"{"a":0,"b":{"a":[{"x":2,"y":1}],"z":1}}"#
+        );
+    }
+
+    #[test]
+    fn provenance_records_versions_and_the_source_riv_hash() {
+        let payload = demo_payload();
+        let provenance = build_provenance(&payload);
+
+        assert_eq!(provenance["ravVersion"], env!("CARGO_PKG_VERSION"));
+        assert_eq!(provenance["runtime"]["version"], "2.37.0");
+        assert_eq!(provenance["source"]["sha256"], sha256_hex(&[1, 2, 3]));
+        assert_eq!(provenance["source"]["size"], 3);
+    }
+
+    #[test]
+    fn provenance_omits_the_source_hash_when_the_riv_does_not_decode() {
+        let mut payload = demo_payload();
+        payload.animation_base64 = "not base64!".into();
+        let provenance = build_provenance(&payload);
+
+        assert_eq!(
+            provenance["source"],
+            json!({ "fileName": payload.file_name })
+        );
+    }
+
+    #[test]
+    fn identical_payloads_produce_identical_bytes_and_hashed_names() {
+        let mut payload = demo_payload();
+        payload.layout_state = Some(r#"{"zoom":1,"panel":{"width":320,"open":true}}"#.into());
+        payload.vm_hierarchy = Some(r#"{"path":"root","label":"Root","children":[]}"#.into());
+        let first = build_demo_html(&payload).expect("demo html");
+        let second = build_demo_html(&payload).expect("demo html");
+
+        payload.layout_state = Some(r#"{"panel":{"open":true,"width":320},"zoom":1}"#.into());
+        payload.vm_hierarchy = Some(r#"{"children":[],"label":"Root","path":"root"}"#.into());
+        let reordered = build_demo_html(&payload).expect("demo html");
+
+        assert_eq!(first, second);
+        assert_eq!(first, reordered);
+        assert!(first.contains("id=\"rav-provenance\""));
+
+        let hashed = content_addressed_path(Path::new("/tmp/out/demo.html"), first.as_bytes());
+        let name = hashed.file_name().unwrap().to_string_lossy().to_string();
+        assert_eq!(
+            hashed,
+            content_addressed_path(Path::new("/tmp/out/demo.html"), reordered.as_bytes())
+        );
+        assert!(name.starts_with("demo.") && name.ends_with(".html"));
+        assert_eq!(name.len(), "demo..html".len() + 12);
+    }
+}
@@ -0,0 +1,340 @@
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+
+use crate::app::export::demo_bundle::prepare_output_path;
+use crate::app::export::manifest::build_interface_manifest;
+use crate::app::state::DemoBundlePayload;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SpecSheetFormat {
+    Markdown,
+    Html,
+}
+
+struct SpecTable {
+    heading: String,
+    columns: &'static [&'static str],
+    rows: Vec<Vec<String>>,
+}
+
+#[tauri::command]
+pub async fn make_spec_sheet_to_path(
+    payload: DemoBundlePayload,
+    output_path: String,
+    format: Option<String>,
+) -> Result<String, String> {
+    let path = prepare_output_path(&output_path)?;
+    let format = resolve_spec_sheet_format(format.as_deref(), &path)?;
+    fs::write(&path, build_spec_sheet(&payload, format))
+        .map_err(|error| format!("Failed to write {}: {}", path.display(), error))?;
+    Ok(path.to_string_lossy().to_string())
+}
+
+pub fn resolve_spec_sheet_format(
+    requested: Option<&str>,
+    path: &Path,
+) -> Result<SpecSheetFormat, String> {
+    let requested = requested
+        .map(|format| format.trim().to_ascii_lowercase())
+        .filter(|format| !format.is_empty());
+    let Some(requested) = requested else {
+        let extension = path
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_ascii_lowercase());
+        return Ok(match extension.as_deref() {
+            Some("html" | "htm") => SpecSheetFormat::Html,
+            _ => SpecSheetFormat::Markdown,
+        });
+    };
+
+    match requested.as_str() {
+        "markdown" | "md" => Ok(SpecSheetFormat::Markdown),
+        "html" => Ok(SpecSheetFormat::Html),
+        other => Err(format!(
+            "Unsupported spec sheet format \"{other}\" (expected markdown or html)"
+        )),
+    }
+}
+
+pub fn build_spec_sheet(payload: &DemoBundlePayload, format: SpecSheetFormat) -> String {
+    let manifest = build_interface_manifest(payload);
+    let title = format!("{} – Interface Spec", payload.file_name);
+    let summary = format!(
+        "Generated by Rive Animation Viewer {} · runtime {}@{} · source SHA-256 {}",
+        manifest["provenance"]["ravVersion"]
+            .as_str()
+            .unwrap_or("unknown"),
+        payload.runtime_name,
+        payload.runtime_version.as_deref().unwrap_or("unknown"),
+        manifest["provenance"]["source"]["sha256"]
+            .as_str()
+            .unwrap_or("unknown"),
+    );
+    let tables = collect_spec_tables(&manifest);
+    let snippet = payload.instantiation_code.trim();
+
+    match format {
+        SpecSheetFormat::Markdown => render_markdown(&title, &summary, &tables, snippet),
+        SpecSheetFormat::Html => render_html(&title, &summary, &tables, snippet),
+    }
+}
+
+fn collect_spec_tables(manifest: &Value) -> Vec<SpecTable> {
+    let mut tables = Vec::new();
+    for artboard in manifest["artboards"].as_array().into_iter().flatten() {
+        let artboard_name = artboard["name"].as_str().unwrap_or("Default artboard");
+        let animations: Vec<Vec<String>> = artboard["animations"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .map(|name| vec![name.to_string()])
+            .collect();
+        if !animations.is_empty() {
+            tables.push(SpecTable {
+                heading: format!("Artboard {artboard_name} · Animations"),
+                columns: &["Animation"],
+                rows: animations,
+            });
+        }
+        for state_machine in artboard["stateMachines"].as_array().into_iter().flatten() {
+            tables.push(SpecTable {
+                heading: format!(
+                    "Artboard {artboard_name} · State machine {}",
+                    state_machine["name"].as_str().unwrap_or_default()
+                ),
+                columns: &["Input", "Type", "Default"],
+                rows: state_machine["inputs"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(|input| {
+                        vec![
+                            display_value(&input["name"]),
+                            display_value(&input["type"]),
+                            display_value(&input["default"]),
+                        ]
+                    })
+                    .collect(),
+            });
+        }
+    }
+
+    if let Some(view_model) = manifest["viewModel"].as_object() {
+        let instance = view_model
+            .get("instanceName")
+            .and_then(Value::as_str)
+            .map(|name| format!(" (instance {name})"))
+            .unwrap_or_default();
+        tables.push(SpecTable {
+            heading: format!(
+                "ViewModel {}{instance}",
+                view_model
+                    .get("name")
+                    .and_then(Value::as_str)
+                    .unwrap_or("Root")
+            ),
+            columns: &["Path", "Type", "Default", "Enum values"],
+            rows: view_model
+                .get("properties")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .map(|property| {
+                    let enum_values = property["enumValues"]
+                        .as_array()
+                        .map(|values| {
+                            values
+                                .iter()
+                                .map(display_value)
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        })
+                        .unwrap_or_default();
+                    vec![
+                        display_value(&property["path"]),
+                        display_value(&property["type"]),
+                        display_value(&property["default"]),
+                        enum_values,
+                    ]
+                })
+                .collect(),
+        });
+    }
+    tables
+}
+
+fn display_value(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn render_markdown(title: &str, summary: &str, tables: &[SpecTable], snippet: &str) -> String {
+    let cell = |text: &str| text.replace('|', "\\|").replace('\n', " ");
+    let mut out = format!("# {title}\n\n{summary}\n");
+    for table in tables {
+        out.push_str(&format!("\n## {}\n\n", table.heading));
+        if table.rows.is_empty() {
+            out.push_str("_No inputs._\n");
+            continue;
+        }
+        out.push_str(&format!("| {} |\n", table.columns.join(" | ")));
+        out.push_str(&format!("|{}\n", "---|".repeat(table.columns.len())));
+        for row in &table.rows {
+            let cells: Vec<String> = row
+                .iter()
+                .map(|value| {
+                    if value.is_empty() {
+                        String::new()
+                    } else {
+                        format!("`{}`", cell(value))
+                    }
+                })
+                .collect();
+            out.push_str(&format!("| {} |\n", cells.join(" | ")));
+        }
+    }
+    if !snippet.is_empty() {
+        let language = if snippet.starts_with('<') {
+            "html"
+        } else {
+            "js"
+        };
+        // A fence must be longer than any backtick run inside it, or the snippet closes it.
+        let longest_run = snippet
+            .split(|character| character != '`')
+            .map(str::len)
+            .max()
+            .unwrap_or(0);
+        let fence = "`".repeat((longest_run + 1).max(3));
+        out.push_str(&format!(
+            "\n## Instantiation\n\n{fence}{language}\n{snippet}\n{fence}\n"
+        ));
+    }
+    out
+}
+
+fn render_html(title: &str, summary: &str, tables: &[SpecTable], snippet: &str) -> String {
+    let mut body = format!(
+        "<h1>{}</h1>\n<p class=\"summary\">{}</p>\n",
+        escape_html(title),
+        escape_html(summary)
+    );
+    for table in tables {
+        body.push_str(&format!("<h2>{}</h2>\n", escape_html(&table.heading)));
+        if table.rows.is_empty() {
+            body.push_str("<p class=\"empty\">No inputs.</p>\n");
+            continue;
+        }
+        let header: String = table
+            .columns
+            .iter()
+            .map(|column| format!("<th>{}</th>", escape_html(column)))
+            .collect();
+        body.push_str(&format!(
+            "<table>\n<thead><tr>{header}</tr></thead>\n<tbody>\n"
+        ));
+        for row in &table.rows {
+            let cells: String = row
+                .iter()
+                .map(|value| format!("<td><code>{}</code></td>", escape_html(value)))
+                .collect();
+            body.push_str(&format!("<tr>{cells}</tr>\n"));
+        }
+        body.push_str("</tbody>\n</table>\n");
+    }
+    if !snippet.is_empty() {
+        body.push_str(&format!(
+            "<h2>Instantiation</h2>\n<pre><code>{}</code></pre>\n",
+            escape_html(snippet)
+        ));
+    }
+
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"UTF-8\">\n<title>{}</title>\n<style>\nbody{{font:14px/1.5 system-ui,sans-serif;margin:32px auto;max-width:960px;padding:0 16px;color:#1f2328}}\ntable{{border-collapse:collapse;width:100%;margin-bottom:16px}}\nth,td{{border:1px solid #d0d7de;padding:6px 10px;text-align:left}}\nth{{background:#f6f8fa}}\npre{{background:#f6f8fa;padding:12px;overflow:auto}}\n.summary,.empty{{color:#59636e}}\n</style>\n</head>\n<body>\n{body}</body>\n</html>\n",
+        escape_html(title)
+    )
+}
+
+fn escape_html(raw: &str) -> String {
+    raw.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_spec_sheet, resolve_spec_sheet_format, SpecSheetFormat};
+    use crate::app::export::fixtures::{demo_payload, STATE_MACHINE_HIERARCHY};
+    use crate::app::state::DemoBundlePayload;
+    use std::path::Path;
+
+    fn spec_payload() -> DemoBundlePayload {
+        let mut payload = demo_payload();
+        payload.instantiation_code = "<canvas id=\"rive\"></canvas>".into();
+        payload.vm_hierarchy = Some(
+            r#"{"kind":"vm","label":"Card","path":"<root>","children":[],"inputs":[
+                {"kind":"enum","name":"mode","path":"mode","value":"dark","enumValues":["light","dark"]},
+                {"kind":"trigger","name":"pulse","path":"pulse","value":null}
+            ]}"#
+            .into(),
+        );
+        payload.state_machine_hierarchy = Some(STATE_MACHINE_HIERARCHY.into());
+        payload
+    }
+
+    #[test]
+    fn markdown_spec_lists_paths_enum_values_triggers_and_the_snippet() {
+        let markdown = build_spec_sheet(&spec_payload(), SpecSheetFormat::Markdown);
+
+        assert!(markdown.starts_with("# demo.riv – Interface Spec\n"));
+        assert!(markdown.contains("## Artboard Main · State machine main-sm"));
+        assert!(markdown.contains("| `armed` | `boolean` | `true` |"));
+        assert!(markdown.contains("| `fire` | `trigger` |  |"));
+        assert!(markdown.contains("| `pulse` | `trigger` |  |  |"));
+        assert!(markdown.contains("| `mode` | `enum` | `dark` | `light, dark` |"));
+        assert!(markdown.contains("```html\n<canvas id=\"rive\"></canvas>\n```"));
+    }
+
+    #[test]
+    fn markdown_fence_outlasts_backtick_runs_in_the_snippet() {
+        let mut payload = spec_payload();
+        payload.instantiation_code = "// Usage:\n// ```js\n// new Rive({ src })\n// ```".into();
+
+        let markdown = build_spec_sheet(&payload, SpecSheetFormat::Markdown);
+
+        assert!(markdown.ends_with(&format!("````js\n{}\n````\n", payload.instantiation_code)));
+    }
+
+    #[test]
+    fn html_spec_is_standalone_and_escapes_the_snippet() {
+        let html = build_spec_sheet(&spec_payload(), SpecSheetFormat::Html);
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<td><code>light, dark</code></td>"));
+        assert!(html.contains("&lt;canvas id=&quot;rive&quot;&gt;&lt;/canvas&gt;"));
+        assert!(!html.contains("<canvas"));
+    }
+
+    #[test]
+    fn format_falls_back_to_the_output_extension() {
+        let resolve = |format, path| resolve_spec_sheet_format(format, Path::new(path));
+
+        assert_eq!(resolve(None, "/tmp/spec.html"), Ok(SpecSheetFormat::Html));
+        assert_eq!(resolve(None, "/tmp/spec.md"), Ok(SpecSheetFormat::Markdown));
+        assert_eq!(
+            resolve(Some("HTML"), "/tmp/spec.md"),
+            Ok(SpecSheetFormat::Html)
+        );
+        assert_eq!(
+            resolve(None, "/tmp/spec.txt"),
+            Ok(SpecSheetFormat::Markdown)
+        );
+        assert!(resolve(Some("pdf"), "/tmp/spec.pdf").is_err());
+    }
+}
@@ -0,0 +1,49 @@
+pub mod native;
+pub mod pwa;
+pub mod starter;
+pub mod typescript;
+pub mod web_component;
+
+pub fn pascal_case(raw: &str, fallback: &str) -> String {
+    let name: String = split_words(raw)
+        .iter()
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect();
+    if name.is_empty() {
+        fallback.into()
+    } else if name.starts_with(|ch: char| ch.is_ascii_digit()) {
+        format!("{fallback}{name}")
+    } else {
+        name
+    }
+}
+
+pub fn split_words(raw: &str) -> Vec<String> {
+    let mut words: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut previous_lower = false;
+    for ch in raw.chars() {
+        if !ch.is_ascii_alphanumeric() {
+            previous_lower = false;
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        if ch.is_ascii_uppercase() && previous_lower && !current.is_empty() {
+            words.push(std::mem::take(&mut current));
+        }
+        previous_lower = ch.is_ascii_lowercase() || ch.is_ascii_digit();
+        current.push(ch.to_ascii_lowercase());
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
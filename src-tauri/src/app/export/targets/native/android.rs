@@ -0,0 +1,91 @@
+use super::{argb, double_quoted, number_literal, screaming_snake, NativeSnippetContext};
+
+pub fn render(context: &NativeSnippetContext) -> String {
+    let mut arguments = vec![format!("R.raw.{}", context.resource_name)];
+    if let Some(artboard) = &context.artboard {
+        arguments.push(format!("artboardName = {}", kotlin_string(artboard)));
+    }
+    if let Some(state_machine) = &context.state_machine {
+        arguments.push(format!(
+            "stateMachineName = {}",
+            kotlin_string(state_machine)
+        ));
+    }
+    if let Some(animation) = &context.animation {
+        arguments.push(format!("animationName = {}", kotlin_string(animation)));
+    }
+    arguments.push(format!("fit = Fit.{}", screaming_snake(&context.fit)));
+    arguments.push(format!(
+        "alignment = Alignment.{}",
+        screaming_snake(&context.alignment)
+    ));
+    arguments.push(format!("autoplay = {}", context.autoplay));
+
+    let mut out = format!(
+        "import app.rive.runtime.kotlin.RiveAnimationView\nimport app.rive.runtime.kotlin.core.Alignment\nimport app.rive.runtime.kotlin.core.Fit\n\n// Copy the .riv to res/raw/{}.riv.\nfun bind{}(riveView: RiveAnimationView) {{\n    riveView.setRiveResource(\n        {},\n    )\n",
+        context.resource_name,
+        context.component_name,
+        arguments.join(",\n        ")
+    );
+
+    if let Some(state_machine) = &context.state_machine {
+        let state_machine = kotlin_string(state_machine);
+        for input in &context.state_machine_values {
+            let name = kotlin_string(&input.path);
+            out.push_str(&match input.kind.as_str() {
+                "boolean" => format!(
+                    "    riveView.setBooleanState({state_machine}, {name}, {})\n",
+                    input.value.as_bool().unwrap_or_default()
+                ),
+                _ => format!(
+                    "    riveView.setNumberState({state_machine}, {name}, {}f)\n",
+                    number_literal(&input.value)
+                ),
+            });
+        }
+    }
+
+    if context.view_model_instance.is_some() || !context.view_model_values.is_empty() {
+        let create = match &context.view_model_instance {
+            Some(name) => format!("createInstanceFromName({})", kotlin_string(name)),
+            None => "createDefaultInstance()".into(),
+        };
+        out.push_str("\n    val controller = riveView.controller\n    val file = controller.file ?: return\n    val artboard = controller.activeArtboard ?: return\n");
+        out.push_str(&format!(
+            "    val instance = file.defaultViewModelForArtboard(artboard).{create}\n    controller.stateMachines.firstOrNull()?.viewModelInstance = instance\n"
+        ));
+        for property in &context.view_model_values {
+            let path = kotlin_string(&property.path);
+            let value = &property.value;
+            out.push_str(&match property.kind.as_str() {
+                "number" => format!(
+                    "    instance.getNumberProperty({path}).value = {}f\n",
+                    number_literal(value)
+                ),
+                "boolean" => format!(
+                    "    instance.getBooleanProperty({path}).value = {}\n",
+                    value.as_bool().unwrap_or_default()
+                ),
+                "string" => format!(
+                    "    instance.getStringProperty({path}).value = {}\n",
+                    kotlin_string(value.as_str().unwrap_or_default())
+                ),
+                "enum" => format!(
+                    "    instance.getEnumProperty({path}).value = {}\n",
+                    kotlin_string(value.as_str().unwrap_or_default())
+                ),
+                "color" => format!(
+                    "    instance.getColorProperty({path}).value = 0x{:08X}.toInt()\n",
+                    argb(value)
+                ),
+                other => format!("    // {path}: unsupported {other} value\n"),
+            });
+        }
+    }
+    out.push_str("}\n");
+    out
+}
+
+fn kotlin_string(raw: &str) -> String {
+    double_quoted(raw).replace('$', "\\$")
+}
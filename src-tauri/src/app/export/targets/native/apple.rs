@@ -0,0 +1,111 @@
+use super::{argb, double_quoted, number_literal, NativeSnippetContext};
+
+pub fn render(context: &NativeSnippetContext) -> String {
+    let mut arguments = vec![format!(
+        "fileName: {}",
+        double_quoted(&context.resource_name)
+    )];
+    if let Some(state_machine) = &context.state_machine {
+        arguments.push(format!(
+            "stateMachineName: {}",
+            double_quoted(state_machine)
+        ));
+    }
+    arguments.push(format!(
+        "fit: .{}",
+        if context.fit == "none" {
+            "noFit"
+        } else {
+            &context.fit
+        }
+    ));
+    arguments.push(format!("alignment: .{}", context.alignment));
+    arguments.push(format!("autoPlay: {}", context.autoplay));
+    if let Some(artboard) = &context.artboard {
+        arguments.push(format!("artboardName: {}", double_quoted(artboard)));
+    }
+    if let Some(animation) = &context.animation {
+        arguments.push(format!("animationName: {}", double_quoted(animation)));
+    }
+
+    let mut setup = Vec::new();
+    for input in &context.state_machine_values {
+        let value = match input.kind.as_str() {
+            "boolean" => input.value.as_bool().unwrap_or_default().to_string(),
+            _ => number_literal(&input.value),
+        };
+        setup.push(format!(
+            "rive.setInput({}, value: {value})",
+            double_quoted(&input.path)
+        ));
+    }
+    if context.view_model_instance.is_some() || !context.view_model_values.is_empty() {
+        let create = match &context.view_model_instance {
+            Some(name) => format!(
+                "viewModel.createInstance(fromName: {})",
+                double_quoted(name)
+            ),
+            None => "viewModel.createDefaultInstance()".into(),
+        };
+        setup.push("guard let file = rive.riveModel?.riveFile,".into());
+        setup.push("      let artboard = rive.riveModel?.artboard,".into());
+        setup.push("      let viewModel = file.defaultViewModel(for: artboard),".into());
+        setup.push(format!("      let instance = {create} else {{ return }}"));
+        setup.push("rive.riveModel?.stateMachine?.bind(viewModelInstance: instance)".into());
+        for property in &context.view_model_values {
+            let path = double_quoted(&property.path);
+            let value = &property.value;
+            setup.push(match property.kind.as_str() {
+                "number" => format!(
+                    "instance.numberProperty(fromPath: {path})?.value = {}",
+                    number_literal(value)
+                ),
+                "boolean" => format!(
+                    "instance.booleanProperty(fromPath: {path})?.value = {}",
+                    value.as_bool().unwrap_or_default()
+                ),
+                "string" => format!(
+                    "instance.stringProperty(fromPath: {path})?.value = {}",
+                    double_quoted(value.as_str().unwrap_or_default())
+                ),
+                "enum" => format!(
+                    "instance.enumProperty(fromPath: {path})?.value = {}",
+                    double_quoted(value.as_str().unwrap_or_default())
+                ),
+                "color" => format!(
+                    "instance.colorProperty(fromPath: {path})?.value = {}",
+                    ui_color(argb(value))
+                ),
+                other => format!("// {path}: unsupported {other} value"),
+            });
+        }
+    }
+
+    let mut out = format!(
+        "import SwiftUI\nimport RiveRuntime\n\n// Add {}.riv to the app bundle.\nstruct {}: View {{\n    @StateObject private var rive = RiveViewModel(\n        {}\n    )\n\n    var body: some View {{\n        rive.view()",
+        context.resource_name,
+        context.component_name,
+        arguments.join(",\n        ")
+    );
+    if setup.is_empty() {
+        out.push_str("\n    }\n}\n");
+        return out;
+    }
+    out.push_str("\n            .onAppear(perform: applyInitialState)\n    }\n\n    private func applyInitialState() {\n");
+    for line in setup {
+        out.push_str(&format!("        {line}\n"));
+    }
+    out.push_str("    }\n}\n");
+    out
+}
+
+fn ui_color(color: u32) -> String {
+    let channel = |shift: u32| f64::from((color >> shift) & 0xFF) / 255.0;
+    format!(
+        "UIColor(red: {:.3}, green: {:.3}, blue: {:.3}, alpha: {:.3})",
+        channel(16),
+        channel(8),
+        channel(0),
+        channel(24)
+    )
+}
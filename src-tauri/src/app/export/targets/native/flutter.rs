@@ -0,0 +1,90 @@
+use super::{argb, number_literal, single_quoted, NativeSnippetContext};
+
+pub fn render(context: &NativeSnippetContext) -> String {
+    let artboard = match &context.artboard {
+        Some(name) => format!("ArtboardSelector.byName({})", dart_string(name)),
+        None => "ArtboardSelector.byDefault()".into(),
+    };
+    let state_machine = match &context.state_machine {
+        Some(name) => format!("StateMachineSelector.byName({})", dart_string(name)),
+        None => "StateMachineSelector.byDefault()".into(),
+    };
+    let data_bind = match &context.view_model_instance {
+        Some(name) => format!("DataBind.byName({})", dart_string(name)),
+        None => "DataBind.auto()".into(),
+    };
+
+    let mut setup = Vec::new();
+    if !context.view_model_values.is_empty() {
+        setup.push("final viewModel = state.viewModelInstance;".to_string());
+    }
+    for property in &context.view_model_values {
+        let path = dart_string(&property.path);
+        let value = &property.value;
+        setup.push(match property.kind.as_str() {
+            "number" => format!(
+                "viewModel?.number({path})?.value = {};",
+                number_literal(value)
+            ),
+            "boolean" => format!(
+                "viewModel?.boolean({path})?.value = {};",
+                value.as_bool().unwrap_or_default()
+            ),
+            "string" => format!(
+                "viewModel?.string({path})?.value = {};",
+                dart_string(value.as_str().unwrap_or_default())
+            ),
+            "enum" => format!(
+                "viewModel?.enumerator({path})?.value = {};",
+                dart_string(value.as_str().unwrap_or_default())
+            ),
+            "color" => format!(
+                "viewModel?.color({path})?.value = const Color(0x{:08X});",
+                argb(value)
+            ),
+            other => format!("// {path}: unsupported {other} value"),
+        });
+    }
+    if !context.state_machine_values.is_empty() {
+        setup.push("final stateMachine = state.controller.stateMachine;".into());
+    }
+    for input in &context.state_machine_values {
+        let name = dart_string(&input.path);
+        setup.push(match input.kind.as_str() {
+            "boolean" => format!(
+                "stateMachine.boolean({name})?.value = {};",
+                input.value.as_bool().unwrap_or_default()
+            ),
+            _ => format!(
+                "stateMachine.number({name})?.value = {};",
+                number_literal(&input.value)
+            ),
+        });
+    }
+    let on_loaded = if setup.is_empty() {
+        String::new()
+    } else {
+        "      onLoaded: _applyInitialState,\n".into()
+    };
+
+    let mut out = format!(
+        "import 'package:flutter/material.dart';\nimport 'package:rive/rive.dart';\n\n// List assets/{resource}.riv under flutter.assets in pubspec.yaml.\nclass {name} extends StatelessWidget {{\n  const {name}({{super.key}});\n\n  static final _fileLoader = FileLoader.fromAsset('assets/{resource}.riv', riveFactory: Factory.rive);\n\n  @override\n  Widget build(BuildContext context) {{\n    return RiveWidgetBuilder(\n      fileLoader: _fileLoader,\n      artboardSelector: {artboard},\n      stateMachineSelector: {state_machine},\n      dataBind: {data_bind},\n{on_loaded}      builder: (context, state) => switch (state) {{\n        RiveLoading() => const Center(child: CircularProgressIndicator()),\n        RiveFailed() => ErrorWidget.withDetails(message: state.error.toString()),\n        RiveLoaded() => RiveWidget(\n            controller: state.controller,\n            fit: Fit.{fit},\n            alignment: Alignment.{alignment},\n          ),\n      }},\n    );\n  }}\n",
+        resource = context.resource_name,
+        name = context.component_name,
+        fit = context.fit,
+        alignment = context.alignment,
+    );
+    if !setup.is_empty() {
+        out.push_str("\n  static void _applyInitialState(RiveLoaded state) {\n");
+        for line in setup {
+            out.push_str(&format!("    {line}\n"));
+        }
+        out.push_str("  }\n");
+    }
+    out.push_str("}\n");
+    out
+}
+
+fn dart_string(raw: &str) -> String {
+    single_quoted(raw).replace('$', "\\$")
+}
@@ -0,0 +1,320 @@
+mod android;
+mod apple;
+mod flutter;
+mod react_native;
+
+use serde_json::Value;
+use std::path::Path;
+
+use crate::app::export::targets::{pascal_case, split_words};
+use crate::app::state::{DemoBundlePayload, NativeInstantiationSnippet};
+
+const STATE_MACHINE_INPUT_SOURCE: &str = "state-machine";
+const LAYOUT_FITS: [&str; 8] = [
+    "cover",
+    "contain",
+    "fill",
+    "fitWidth",
+    "fitHeight",
+    "scaleDown",
+    "none",
+    "layout",
+];
+const LAYOUT_ALIGNMENTS: [&str; 9] = [
+    "topLeft",
+    "topCenter",
+    "topRight",
+    "centerLeft",
+    "center",
+    "centerRight",
+    "bottomLeft",
+    "bottomCenter",
+    "bottomRight",
+];
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NativePlatform {
+    Apple,
+    Android,
+    Flutter,
+    ReactNative,
+}
+
+pub struct NativeSnippetContext {
+    pub component_name: String,
+    pub resource_name: String,
+    pub artboard: Option<String>,
+    pub state_machine: Option<String>,
+    pub animation: Option<String>,
+    pub fit: String,
+    pub alignment: String,
+    pub autoplay: bool,
+    pub view_model_instance: Option<String>,
+    pub view_model_values: Vec<NativeControlValue>,
+    pub state_machine_values: Vec<NativeControlValue>,
+}
+
+pub struct NativeControlValue {
+    pub path: String,
+    pub kind: String,
+    pub value: Value,
+}
+
+#[tauri::command]
+pub async fn generate_native_instantiation_code(
+    payload: DemoBundlePayload,
+    platform: String,
+) -> Result<NativeInstantiationSnippet, String> {
+    let platform = resolve_native_platform(&platform)?;
+    Ok(build_native_snippet(&payload, platform))
+}
+
+pub fn resolve_native_platform(raw: &str) -> Result<NativePlatform, String> {
+    match raw.trim().to_ascii_lowercase().as_str() {
+        "apple" | "ios" | "swift" => Ok(NativePlatform::Apple),
+        "android" | "kotlin" => Ok(NativePlatform::Android),
+        "flutter" | "dart" => Ok(NativePlatform::Flutter),
+        "react-native" | "react_native" | "reactnative" => Ok(NativePlatform::ReactNative),
+        other => Err(format!(
+            "Unsupported native platform \"{other}\" (expected apple, android, flutter or react-native)"
+        )),
+    }
+}
+
+pub fn build_native_snippet(
+    payload: &DemoBundlePayload,
+    platform: NativePlatform,
+) -> NativeInstantiationSnippet {
+    let context = build_native_context(payload);
+    let (name, language, code) = match platform {
+        NativePlatform::Apple => ("apple", "swift", apple::render(&context)),
+        NativePlatform::Android => ("android", "kotlin", android::render(&context)),
+        NativePlatform::Flutter => ("flutter", "dart", flutter::render(&context)),
+        NativePlatform::ReactNative => ("react-native", "tsx", react_native::render(&context)),
+    };
+    NativeInstantiationSnippet {
+        platform: name.into(),
+        language: language.into(),
+        code,
+    }
+}
+
+pub fn build_native_context(payload: &DemoBundlePayload) -> NativeSnippetContext {
+    let stem = Path::new(&payload.file_name)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let resource_name = split_words(&stem).join("_");
+    let state_machine = payload.state_machines.first().cloned();
+    let snapshot = payload
+        .control_snapshot
+        .as_deref()
+        .and_then(|raw| serde_json::from_str::<Value>(raw).ok())
+        .and_then(|value| value.as_array().cloned())
+        .unwrap_or_default();
+
+    let mut view_model_values = Vec::new();
+    let mut state_machine_values = Vec::new();
+    for entry in &snapshot {
+        let descriptor = &entry["descriptor"];
+        let kind = entry["kind"]
+            .as_str()
+            .or_else(|| descriptor["kind"].as_str())
+            .unwrap_or_default();
+        if kind.is_empty() || kind == "trigger" || entry["value"].is_null() {
+            continue;
+        }
+        if descriptor["source"] == STATE_MACHINE_INPUT_SOURCE {
+            if descriptor["stateMachineName"].as_str() == state_machine.as_deref() {
+                state_machine_values.push(NativeControlValue {
+                    path: descriptor["name"].as_str().unwrap_or_default().into(),
+                    kind: kind.into(),
+                    value: entry["value"].clone(),
+                });
+            }
+            continue;
+        }
+        if let Some(path) = descriptor["path"].as_str().filter(|path| !path.is_empty()) {
+            view_model_values.push(NativeControlValue {
+                path: path.into(),
+                kind: kind.into(),
+                value: entry["value"].clone(),
+            });
+        }
+    }
+
+    NativeSnippetContext {
+        component_name: format!("{}Animation", pascal_case(&stem, "Rive")),
+        resource_name: if resource_name.is_empty() {
+            "animation".into()
+        } else {
+            resource_name
+        },
+        artboard: payload.artboard_name.clone(),
+        animation: state_machine
+            .is_none()
+            .then(|| payload.animations.first().cloned())
+            .flatten(),
+        state_machine,
+        fit: normalize_layout_value(&payload.layout_fit, &LAYOUT_FITS, "contain"),
+        alignment: normalize_layout_value(&payload.layout_alignment, &LAYOUT_ALIGNMENTS, "center"),
+        autoplay: payload.autoplay,
+        view_model_instance: payload.view_model_instance_name.clone(),
+        view_model_values,
+        state_machine_values,
+    }
+}
+
+fn normalize_layout_value(raw: &str, known: &[&str], fallback: &str) -> String {
+    let value = raw.trim();
+    if known.contains(&value) {
+        value.into()
+    } else {
+        fallback.into()
+    }
+}
+
+pub fn number_literal(value: &Value) -> String {
+    let number = value.as_f64().unwrap_or_default();
+    if number.fract() == 0.0 && number.abs() < 1e15 {
+        format!("{number:.1}")
+    } else {
+        number.to_string()
+    }
+}
+
+pub fn argb(value: &Value) -> u32 {
+    value.as_u64().map_or(0xFF00_0000, |color| color as u32)
+}
+
+pub fn double_quoted(raw: &str) -> String {
+    Value::String(raw.to_string()).to_string()
+}
+
+pub fn single_quoted(raw: &str) -> String {
+    let escaped = raw
+        .replace('\\', "\\\\")
+        .replace('\'', "\\'")
+        .replace('\n', "\\n");
+    format!("'{escaped}'")
+}
+
+pub fn screaming_snake(raw: &str) -> String {
+    split_words(raw).join("_").to_ascii_uppercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_native_snippet, resolve_native_platform, NativePlatform};
+    use crate::app::export::fixtures::demo_payload;
+    use crate::app::state::DemoBundlePayload;
+
+    fn snippet_payload() -> DemoBundlePayload {
+        let mut payload = demo_payload();
+        payload.file_name = "hero banner.riv".into();
+        payload.layout_fit = "fitWidth".into();
+        payload.layout_alignment = "topLeft".into();
+        payload.view_model_instance_name = Some("Preview".into());
+        payload.control_snapshot = Some(
+            r#"[
+                {"descriptor":{"path":"score","kind":"number"},"kind":"number","value":42},
+                {"descriptor":{"path":"header/tint","kind":"color"},"kind":"color","value":4278255360},
+                {"descriptor":{"path":"mode","kind":"enum"},"kind":"enum","value":"dark"},
+                {"descriptor":{"path":"pulse","kind":"trigger"},"kind":"trigger","value":null},
+                {"descriptor":{"source":"state-machine","stateMachineName":"main-sm","name":"armed","kind":"boolean"},"kind":"boolean","value":true},
+                {"descriptor":{"source":"state-machine","stateMachineName":"other-sm","name":"speed","kind":"number"},"kind":"number","value":3}
+            ]"#
+            .into(),
+        );
+        payload
+    }
+
+    #[test]
+    fn apple_and_android_snippets_apply_layout_binding_and_snapshot() {
+        let apple = build_native_snippet(&snippet_payload(), NativePlatform::Apple);
+        let android = build_native_snippet(&snippet_payload(), NativePlatform::Android);
+
+        assert_eq!(apple.language, "swift");
+        assert!(apple.code.contains("struct HeroBannerAnimation: View"));
+        assert!(apple.code.contains("fileName: \"hero_banner\""));
+        assert!(apple
+            .code
+            .contains("fit: .fitWidth,\n        alignment: .topLeft,"));
+        assert!(apple
+            .code
+            .contains("viewModel.createInstance(fromName: \"Preview\")"));
+        assert!(apple
+            .code
+            .contains("instance.numberProperty(fromPath: \"score\")?.value = 42.0"));
+        assert!(apple.code.contains("rive.setInput(\"armed\", value: true)"));
+        assert!(!apple.code.contains("pulse"));
+        assert!(!apple.code.contains("speed"));
+
+        assert!(android.code.contains("R.raw.hero_banner,"));
+        assert!(android.code.contains("fit = Fit.FIT_WIDTH,"));
+        assert!(android.code.contains("alignment = Alignment.TOP_LEFT,"));
+        assert!(android
+            .code
+            .contains("instance.getColorProperty(\"header/tint\").value = 0xFF00FF00.toInt()"));
+        assert!(android
+            .code
+            .contains("riveView.setBooleanState(\"main-sm\", \"armed\", true)"));
+    }
+
+    #[test]
+    fn flutter_and_react_native_snippets_bind_the_named_instance() {
+        let flutter = build_native_snippet(&snippet_payload(), NativePlatform::Flutter);
+        let react_native = build_native_snippet(&snippet_payload(), NativePlatform::ReactNative);
+
+        assert!(flutter
+            .code
+            .contains("FileLoader.fromAsset('assets/hero_banner.riv'"));
+        assert!(flutter
+            .code
+            .contains("dataBind: DataBind.byName('Preview'),"));
+        assert!(flutter.code.contains("fit: Fit.fitWidth"));
+        assert!(flutter
+            .code
+            .contains("viewModel?.enumerator('mode')?.value = 'dark';"));
+        assert!(flutter
+            .code
+            .contains("viewModel?.color('header/tint')?.value = const Color(0xFF00FF00);"));
+
+        assert_eq!(react_native.platform, "react-native");
+        assert!(react_native.code.contains("resourceName=\"hero_banner\""));
+        assert!(react_native.code.contains("fit={Fit.FitWidth}"));
+        assert!(react_native
+            .code
+            .contains("dataBinding={BindByName('Preview')}"));
+        assert!(react_native
+            .code
+            .contains("riveRef.current?.setColor('header/tint', '#00FF00');"));
+        assert!(react_native
+            .code
+            .contains("riveRef.current?.setInputState('main-sm', 'armed', true);"));
+    }
+
+    #[test]
+    fn react_native_applies_the_snapshot_once_across_resumes() {
+        let code = build_native_snippet(&snippet_payload(), NativePlatform::ReactNative).code;
+        let guard = "  const applyInitialState = () => {\n    if (initialStateApplied.current) return;\n    initialStateApplied.current = true;\n";
+
+        assert!(code.contains("const initialStateApplied = useRef(false);"));
+        assert!(code.contains(guard), "{code}");
+        assert!(code.contains("onPlay={applyInitialState}"));
+    }
+
+    #[test]
+    fn platform_names_accept_language_aliases() {
+        assert_eq!(resolve_native_platform("iOS"), Ok(NativePlatform::Apple));
+        assert_eq!(
+            resolve_native_platform("kotlin"),
+            Ok(NativePlatform::Android)
+        );
+        assert_eq!(
+            resolve_native_platform("react_native"),
+            Ok(NativePlatform::ReactNative)
+        );
+        assert!(resolve_native_platform("web").is_err());
+    }
+}
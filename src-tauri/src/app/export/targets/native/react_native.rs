@@ -0,0 +1,121 @@
+use super::{argb, number_literal, single_quoted, NativeSnippetContext};
+use crate::app::export::targets::pascal_case;
+
+pub fn render(context: &NativeSnippetContext) -> String {
+    let mut props = vec![
+        "ref={riveRef}".to_string(),
+        jsx_attribute("resourceName", &context.resource_name),
+    ];
+    if let Some(artboard) = &context.artboard {
+        props.push(jsx_attribute("artboardName", artboard));
+    }
+    if let Some(state_machine) = &context.state_machine {
+        props.push(jsx_attribute("stateMachineName", state_machine));
+    }
+    if let Some(animation) = &context.animation {
+        props.push(jsx_attribute("animationName", animation));
+    }
+    props.push(format!(
+        "fit={{Fit.{}}}",
+        pascal_case(&context.fit, "Contain")
+    ));
+    props.push(format!(
+        "alignment={{Alignment.{}}}",
+        pascal_case(&context.alignment, "Center")
+    ));
+    props.push(format!("autoplay={{{}}}", context.autoplay));
+    let binding_import = match &context.view_model_instance {
+        Some(name) => {
+            props.push(format!(
+                "dataBinding={{BindByName({})}}",
+                single_quoted(name)
+            ));
+            "BindByName"
+        }
+        None => {
+            props.push("dataBinding={AutoBind(true)}".into());
+            "AutoBind"
+        }
+    };
+
+    let mut setup = Vec::new();
+    if let Some(state_machine) = &context.state_machine {
+        for input in &context.state_machine_values {
+            let value = match input.kind.as_str() {
+                "boolean" => input.value.as_bool().unwrap_or_default().to_string(),
+                _ => number_literal(&input.value),
+            };
+            setup.push(format!(
+                "riveRef.current?.setInputState({}, {}, {value});",
+                single_quoted(state_machine),
+                single_quoted(&input.path)
+            ));
+        }
+    }
+    for property in &context.view_model_values {
+        let path = single_quoted(&property.path);
+        let value = &property.value;
+        setup.push(match property.kind.as_str() {
+            "number" => format!(
+                "riveRef.current?.setNumber({path}, {});",
+                number_literal(value)
+            ),
+            "boolean" => format!(
+                "riveRef.current?.setBoolean({path}, {});",
+                value.as_bool().unwrap_or_default()
+            ),
+            "string" => format!(
+                "riveRef.current?.setString({path}, {});",
+                single_quoted(value.as_str().unwrap_or_default())
+            ),
+            "enum" => format!(
+                "riveRef.current?.setEnum({path}, {});",
+                single_quoted(value.as_str().unwrap_or_default())
+            ),
+            "color" => format!(
+                "riveRef.current?.setColor({path}, '{}');",
+                hex_color(argb(value))
+            ),
+            other => format!("// {path}: unsupported {other} value"),
+        });
+    }
+    if !setup.is_empty() {
+        props.push("onPlay={applyInitialState}".into());
+    }
+
+    let mut out = format!(
+        "import {{ useRef }} from 'react';\nimport Rive, {{ Alignment, {binding_import}, Fit, type RiveRef }} from 'rive-react-native';\n\n// Bundle {resource}.riv as a native resource (iOS bundle and Android res/raw).\nexport function {name}() {{\n  const riveRef = useRef<RiveRef>(null);\n",
+        resource = context.resource_name,
+        name = context.component_name,
+    );
+    if !setup.is_empty() {
+        // onPlay also fires on every resume, so the snapshot is applied only the first time.
+        out.push_str("  const initialStateApplied = useRef(false);\n\n  const applyInitialState = () => {\n    if (initialStateApplied.current) return;\n    initialStateApplied.current = true;\n");
+        for line in setup {
+            out.push_str(&format!("    {line}\n"));
+        }
+        out.push_str("  };\n");
+    }
+    out.push_str("\n  return (\n    <Rive\n");
+    for prop in props {
+        out.push_str(&format!("      {prop}\n"));
+    }
+    out.push_str("    />\n  );\n}\n");
+    out
+}
+
+fn jsx_attribute(name: &str, value: &str) -> String {
+    if value.contains(['"', '\\', '{', '\n']) {
+        format!("{name}={{{}}}", single_quoted(value))
+    } else {
+        format!("{name}=\"{value}\"")
+    }
+}
+
+fn hex_color(color: u32) -> String {
+    let rgb = color & 0x00FF_FFFF;
+    match color >> 24 {
+        0xFF => format!("#{rgb:06X}"),
+        alpha => format!("#{rgb:06X}{alpha:02X}"),
+    }
+}
@@ -0,0 +1,311 @@
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::Path;
+
+use crate::app::export::csp::{
+    build_content_security_policy, inject_content_security_policy, ContentSecurityPolicy,
+};
+use crate::app::export::demo_bundle::{build_demo_html, prepare_output_path};
+use crate::app::export::demo_config::timeline::describe_timeline_problems;
+use crate::app::export::targets::split_words;
+use crate::app::export::verify::{write_verified_demo_html, DEFAULT_DEMO_SIZE_BUDGET_BYTES};
+use crate::app::state::{DemoBundlePayload, DemoExportOptions, DemoExportReport};
+
+const SERVICE_WORKER_JS: &str = include_str!("../../../demo-template/pwa/service-worker.js");
+const APP_ICON_PNG: &[u8] = include_bytes!("../../../../icons/128x128.png");
+const APP_ICON_2X_PNG: &[u8] = include_bytes!("../../../../icons/128x128@2x.png");
+const INDEX_FILE: &str = "index.html";
+const MANIFEST_FILE: &str = "manifest.webmanifest";
+const SERVICE_WORKER_FILE: &str = "sw.js";
+const ICON_FILES: [(&str, &str, &[u8]); 2] = [
+    ("icon-128.png", "128x128", APP_ICON_PNG),
+    ("icon-256.png", "256x256", APP_ICON_2X_PNG),
+];
+const SERVICE_WORKER_REGISTRATION: &str = r#"    <script>
+    if ('serviceWorker' in navigator) {
+        window.addEventListener('load', () => {
+            navigator.serviceWorker.register('./sw.js').catch((error) => console.warn('[rav-demo] Service worker registration failed', error));
+        });
+    }
+    </script>
+"#;
+
+/// Honours the `max_size_bytes` and `csp` demo options. The embed, content hash and interface
+/// manifest options describe single-file demos and are ignored here.
+#[tauri::command]
+pub async fn make_pwa_demo_to_path(
+    payload: DemoBundlePayload,
+    output_path: String,
+    options: Option<DemoExportOptions>,
+) -> Result<DemoExportReport, String> {
+    let options = options.unwrap_or_default();
+    if let Some(problems) = describe_timeline_problems(payload.timeline.as_ref()) {
+        return Err(problems);
+    }
+    let root = prepare_output_path(&output_path)?;
+    fs::create_dir_all(&root)
+        .map_err(|error| format!("Failed to create directory {}: {}", root.display(), error))?;
+
+    let mut html = build_pwa_html(&payload).map_err(|error| error.to_string())?;
+    let mut warnings = Vec::new();
+    let mut content_security_policy = None;
+    if options.csp {
+        let csp = build_pwa_content_security_policy(&html, &payload.runtime_script);
+        html = inject_content_security_policy(&html, &csp.policy);
+        warnings.extend(csp.warnings);
+        content_security_policy = Some(csp.policy);
+    }
+    let size_budget_bytes = options
+        .max_size_bytes
+        .unwrap_or(DEFAULT_DEMO_SIZE_BUDGET_BYTES);
+    let verification =
+        write_verified_demo_html(&root.join(INDEX_FILE), &html, &payload, size_budget_bytes)?;
+    if !verification.passed {
+        return Ok(DemoExportReport {
+            path: root.to_string_lossy().to_string(),
+            verification,
+            content_security_policy,
+            warnings,
+        });
+    }
+
+    let mut files = vec![
+        (
+            MANIFEST_FILE.to_string(),
+            build_web_manifest(&payload).into_bytes(),
+        ),
+        (
+            SERVICE_WORKER_FILE.to_string(),
+            build_service_worker(&payload, &html)?.into_bytes(),
+        ),
+    ];
+    files.extend(
+        ICON_FILES
+            .iter()
+            .map(|(name, _, bytes)| (name.to_string(), bytes.to_vec())),
+    );
+    for (name, contents) in files {
+        let path = root.join(&name);
+        fs::write(&path, contents)
+            .map_err(|error| format!("Failed to write {}: {}", path.display(), error))?;
+    }
+
+    if payload.runtime_version.is_none() {
+        warnings.push("The runtime version is unknown, so the WebAssembly binary is cached on first load instead of at install.".into());
+    }
+    Ok(DemoExportReport {
+        path: root.to_string_lossy().to_string(),
+        verification,
+        content_security_policy,
+        warnings,
+    })
+}
+
+/// The demo page policy, plus the manifest and service worker the page loads from its folder.
+pub fn build_pwa_content_security_policy(
+    html: &str,
+    runtime_script: &str,
+) -> ContentSecurityPolicy {
+    let mut csp = build_content_security_policy(html, runtime_script);
+    csp.policy
+        .push_str("; manifest-src 'self'; worker-src 'self'");
+    csp
+}
+
+pub fn build_pwa_html(payload: &DemoBundlePayload) -> Result<String, serde_json::Error> {
+    let html = build_demo_html(payload)?;
+    let manifest_link = format!("\n    <link rel=\"manifest\" href=\"{MANIFEST_FILE}\">");
+    let html = match html.find("<meta name=\"theme-color\"") {
+        Some(start) => {
+            let end = start + html[start..].find('>').map_or(0, |offset| offset + 1);
+            format!("{}{manifest_link}{}", &html[..end], &html[end..])
+        }
+        None => html.replacen("<head>", &format!("<head>{manifest_link}"), 1),
+    };
+    Ok(match html.rfind("</body>") {
+        Some(index) => format!(
+            "{}{SERVICE_WORKER_REGISTRATION}{}",
+            &html[..index],
+            &html[index..]
+        ),
+        None => format!("{html}{SERVICE_WORKER_REGISTRATION}"),
+    })
+}
+
+pub fn build_web_manifest(payload: &DemoBundlePayload) -> String {
+    let stem = Path::new(&payload.file_name)
+        .file_stem()
+        .map(|stem| split_words(&stem.to_string_lossy()).join(" "))
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "Rive Demo".into());
+    let background = if payload.canvas_transparent {
+        "#0d1117"
+    } else {
+        payload.canvas_color.as_deref().unwrap_or("#0d1117")
+    };
+    let icons: Vec<Value> = ICON_FILES
+        .iter()
+        .map(|(name, sizes, _)| {
+            json!({ "src": name, "sizes": sizes, "type": "image/png", "purpose": "any" })
+        })
+        .collect();
+    let manifest = json!({
+        "name": format!("{} – Rive Demo", payload.file_name),
+        "short_name": stem,
+        "start_url": "./",
+        "scope": "./",
+        "display": "standalone",
+        "background_color": background,
+        "theme_color": background,
+        "icons": icons,
+    });
+    format!(
+        "{}\n",
+        serde_json::to_string_pretty(&manifest).unwrap_or_default()
+    )
+}
+
+pub fn build_service_worker(payload: &DemoBundlePayload, html: &str) -> Result<String, String> {
+    let digest = Sha256::digest(html.as_bytes());
+    let cache_name = format!(
+        "rav-demo-{}",
+        digest[..8]
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<String>()
+    );
+    let mut app_shell = vec![
+        "./".to_string(),
+        format!("./{INDEX_FILE}"),
+        format!("./{MANIFEST_FILE}"),
+    ];
+    app_shell.extend(ICON_FILES.iter().map(|(name, _, _)| format!("./{name}")));
+
+    let mut remote_assets = remote_asset_urls(html);
+    if let Some(version) = payload.runtime_version.as_deref() {
+        remote_assets.push(format!(
+            "https://unpkg.com/@rive-app/{}@{version}/rive.wasm",
+            payload.runtime_name
+        ));
+    }
+
+    let to_json = |value: &Vec<String>| serde_json::to_string(value).map_err(|e| e.to_string());
+    Ok(SERVICE_WORKER_JS
+        .replace("__CACHE_NAME__", &cache_name)
+        .replace("__APP_SHELL_JSON__", &to_json(&app_shell)?)
+        .replace("__REMOTE_ASSETS_JSON__", &to_json(&remote_assets)?))
+}
+
+/// External scripts and stylesheets the demo page loads; preconnect hints and links are skipped.
+fn remote_asset_urls(html: &str) -> Vec<String> {
+    let mut urls = Vec::new();
+    for attribute in ["src=\"https://", "href=\"https://"] {
+        for (index, _) in html.match_indices(attribute) {
+            let tag = &html[html[..index].rfind('<').unwrap_or(0)..index];
+            let asset_tag = tag.starts_with("<script") || tag.starts_with("<link");
+            if !asset_tag || tag.contains("rel=\"preconnect\"") {
+                continue;
+            }
+            let start = index + attribute.len() - "https://".len();
+            let Some(length) = html[start..].find('"') else {
+                continue;
+            };
+            let url = html[start..start + length].replace("&amp;", "&");
+            if !urls.contains(&url) {
+                urls.push(url);
+            }
+        }
+    }
+    urls
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        build_pwa_content_security_policy, build_pwa_html, build_service_worker,
+        build_web_manifest, SERVICE_WORKER_REGISTRATION,
+    };
+    use crate::app::export::fixtures::{demo_payload, inline_json};
+    use crate::app::export::verify::find_placeholders;
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use serde_json::{json, Value};
+    use sha2::{Digest, Sha256};
+
+    #[test]
+    fn pwa_page_links_the_manifest_and_registers_the_service_worker() {
+        let html = build_pwa_html(&demo_payload()).expect("pwa html");
+        let manifest: Value =
+            serde_json::from_str(&build_web_manifest(&demo_payload())).expect("manifest json");
+
+        assert!(html.contains("<meta name=\"theme-color\" content=\"#0d1117\">\n    <link rel=\"manifest\" href=\"manifest.webmanifest\">"));
+        assert!(html.contains("navigator.serviceWorker.register('./sw.js')"));
+        assert!(
+            html.find("serviceWorker").expect("sw") > html.find("const CONFIG").expect("config")
+        );
+        assert_eq!(manifest["short_name"], "demo");
+        assert_eq!(manifest["start_url"], "./");
+        assert_eq!(manifest["display"], "standalone");
+        assert_eq!(manifest["icons"][1]["src"], "icon-256.png");
+    }
+
+    #[test]
+    fn service_worker_precaches_the_shell_runtime_wasm_and_page_assets() {
+        let payload = demo_payload();
+        let html = build_pwa_html(&payload).expect("pwa html");
+        let worker = build_service_worker(&payload, &html).expect("service worker");
+
+        let remote_assets = inline_json(&worker, "const REMOTE_ASSETS = ");
+        let remote_assets: Vec<&str> = remote_assets
+            .as_array()
+            .expect("remote assets")
+            .iter()
+            .filter_map(Value::as_str)
+            .collect();
+
+        assert!(worker.contains("const CACHE_NAME = 'rav-demo-"));
+        assert_eq!(
+            inline_json(&worker, "const APP_SHELL = "),
+            json!([
+                "./",
+                "./index.html",
+                "./manifest.webmanifest",
+                "./icon-128.png",
+                "./icon-256.png"
+            ])
+        );
+        assert!(remote_assets.contains(&"https://unpkg.com/@rive-app/webgl2@2.37.0/rive.wasm"));
+        assert!(remote_assets.contains(&"https://unpkg.com/lucide@latest"));
+        assert!(remote_assets
+            .iter()
+            .any(|url| url.starts_with("https://fonts.googleapis.com/css2?family=Space+Grotesk")));
+        assert!(!remote_assets.contains(&"https://fonts.gstatic.com"));
+        assert_eq!(find_placeholders(&worker), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn pwa_policy_allows_the_service_worker_its_registration_and_the_manifest() {
+        let html = build_pwa_html(&demo_payload()).expect("pwa html");
+        let registration = SERVICE_WORKER_REGISTRATION
+            .trim_end()
+            .strip_prefix("    <script>")
+            .and_then(|script| script.strip_suffix("</script>"))
+            .expect("inline registration script");
+        let hash = STANDARD.encode(Sha256::digest(registration.as_bytes()));
+
+        let policy = build_pwa_content_security_policy(&html, "").policy;
+        let directives: Vec<&str> = policy.split("; ").collect();
+
+        assert!(directives.contains(&"manifest-src 'self'"));
+        assert!(directives.contains(&"worker-src 'self'"));
+        assert!(directives.contains(&"default-src 'none'"));
+        let script_src = directives
+            .iter()
+            .find(|directive| directive.starts_with("script-src "))
+            .expect("script-src");
+        assert!(
+            script_src.contains(&format!("'sha256-{hash}'")),
+            "{script_src}"
+        );
+    }
+}
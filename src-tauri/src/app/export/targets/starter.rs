@@ -0,0 +1,352 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde_json::{json, Map, Value};
+use std::fs;
+use std::path::Path;
+
+use crate::app::export::demo_bundle::prepare_output_path;
+use crate::app::export::targets::split_words;
+use crate::app::state::DemoBundlePayload;
+
+const STARTER_INDEX_HTML: &str = include_str!("../../../starter-template/shared/index.html");
+const STARTER_README: &str = include_str!("../../../starter-template/shared/README.md");
+const STARTER_RIVE_SETUP: &str = include_str!("../../../starter-template/shared/rive-setup.js");
+const STARTER_RUNTIME_CDN: &str = include_str!("../../../starter-template/shared/runtime-cdn.js");
+const STARTER_RUNTIME_LOCAL: &str =
+    include_str!("../../../starter-template/shared/runtime-local.js");
+const VITE_VERSION: &str = "^6.0.0";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StarterFramework {
+    React,
+    Vue,
+    Svelte,
+}
+
+struct StarterTemplates {
+    display_name: &'static str,
+    component_file: &'static str,
+    component: &'static str,
+    entry_file: &'static str,
+    entry: &'static str,
+    vite_config: &'static str,
+    dependencies: &'static [(&'static str, &'static str)],
+    dev_dependencies: &'static [(&'static str, &'static str)],
+}
+
+#[tauri::command]
+pub async fn make_framework_starter_to_path(
+    payload: DemoBundlePayload,
+    output_path: String,
+    framework: String,
+) -> Result<String, String> {
+    let framework = resolve_starter_framework(&framework)?;
+    let root = prepare_output_path(&output_path)?;
+    for (relative_path, contents) in build_starter_files(&payload, framework)? {
+        let path = root.join(&relative_path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|error| {
+                format!("Failed to create directory {}: {}", parent.display(), error)
+            })?;
+        }
+        fs::write(&path, contents)
+            .map_err(|error| format!("Failed to write {}: {}", path.display(), error))?;
+    }
+    Ok(root.to_string_lossy().to_string())
+}
+
+pub fn resolve_starter_framework(raw: &str) -> Result<StarterFramework, String> {
+    match raw.trim().to_ascii_lowercase().as_str() {
+        "react" => Ok(StarterFramework::React),
+        "vue" => Ok(StarterFramework::Vue),
+        "svelte" => Ok(StarterFramework::Svelte),
+        other => Err(format!(
+            "Unsupported starter framework \"{other}\" (expected react, vue or svelte)"
+        )),
+    }
+}
+
+pub fn build_starter_files(
+    payload: &DemoBundlePayload,
+    framework: StarterFramework,
+) -> Result<Vec<(String, Vec<u8>)>, String> {
+    let templates = starter_templates(framework);
+    let riv_bytes = STANDARD
+        .decode(payload.animation_base64.trim())
+        .map_err(|error| format!("Invalid animation payload: {error}"))?;
+    let stem = Path::new(&payload.file_name)
+        .file_stem()
+        .map(|stem| split_words(&stem.to_string_lossy()).join("-"))
+        .filter(|slug| !slug.is_empty())
+        .unwrap_or_else(|| "animation".into());
+    let riv_file_name = format!("{stem}.riv");
+    let runtime_package = format!("@rive-app/{}", payload.runtime_name);
+    let runtime_version = payload
+        .runtime_version
+        .as_deref()
+        .map(str::trim)
+        .filter(|version| !version.is_empty())
+        .ok_or_else(|| {
+            format!("The {runtime_package} version is unknown, so the starter cannot pin it. Wait for the runtime to finish loading and export again.")
+        })?;
+    let local_runtime = payload
+        .default_instantiation_package_source
+        .trim()
+        .eq_ignore_ascii_case("local");
+    let title = format!("{} – {} starter", payload.file_name, templates.display_name);
+
+    let mut dependencies = to_dependency_map(templates.dependencies);
+    dependencies.insert(runtime_package.clone(), json!(runtime_version));
+    let mut dev_dependencies = to_dependency_map(templates.dev_dependencies);
+    dev_dependencies.insert("vite".into(), json!(VITE_VERSION));
+    let package_json = json!({
+        "name": format!("{stem}-{}", templates.display_name.to_ascii_lowercase()),
+        "private": true,
+        "version": "0.0.0",
+        "type": "module",
+        "scripts": { "dev": "vite", "build": "vite build", "preview": "vite preview" },
+        "dependencies": dependencies,
+        "devDependencies": dev_dependencies,
+    });
+    let package_json =
+        serde_json::to_string_pretty(&package_json).map_err(|error| error.to_string())?;
+    let options_json = serde_json::to_string_pretty(&build_rive_options(payload, &riv_file_name))
+        .map_err(|error| error.to_string())?;
+
+    let (runtime_module, runtime_note) = if local_runtime {
+        (
+            STARTER_RUNTIME_LOCAL,
+            format!("imports `{runtime_package}`, installed from npm at the version pinned in `package.json`."),
+        )
+    } else {
+        (
+            STARTER_RUNTIME_CDN,
+            format!("loads `{runtime_package}@{runtime_version}` from unpkg at runtime. `package.json` pins the same version for tooling and for switching to the npm import."),
+        )
+    };
+    let fill = |template: &str| {
+        template
+            .replace("__TITLE__", &title)
+            .replace("__FRAMEWORK_DISPLAY__", templates.display_name)
+            .replace("__RIV_FILE_NAME__", &riv_file_name)
+            .replace("__RAV_VERSION__", env!("CARGO_PKG_VERSION"))
+            .replace("__COMPONENT_FILE__", templates.component_file)
+            .replace("__ENTRY_FILE__", templates.entry_file)
+            .replace("__RUNTIME_NOTE__", &runtime_note)
+            .replace("__RUNTIME_PACKAGE__", &runtime_package)
+            .replace("__RUNTIME_VERSION__", runtime_version)
+            .replace(
+                "__CANVAS_COLOR__",
+                if payload.canvas_transparent {
+                    "transparent"
+                } else {
+                    payload.canvas_color.as_deref().unwrap_or("#0d1117")
+                },
+            )
+            .into_bytes()
+    };
+
+    Ok(vec![
+        (
+            "package.json".into(),
+            format!("{package_json}\n").into_bytes(),
+        ),
+        ("index.html".into(), fill(STARTER_INDEX_HTML)),
+        ("vite.config.js".into(), fill(templates.vite_config)),
+        ("README.md".into(), fill(STARTER_README)),
+        (
+            format!("src/{}", templates.component_file),
+            fill(&replace_indented(
+                templates.component,
+                "__RIVE_OPTIONS__",
+                &options_json,
+            )),
+        ),
+        (
+            format!("src/{}", templates.entry_file),
+            fill(templates.entry),
+        ),
+        ("src/rive-setup.js".into(), fill(STARTER_RIVE_SETUP)),
+        ("src/rive-runtime.js".into(), fill(runtime_module)),
+        (format!("public/{riv_file_name}"), riv_bytes),
+    ])
+}
+
+fn build_rive_options(payload: &DemoBundlePayload, riv_file_name: &str) -> Value {
+    let mut options = json!({
+        "src": format!("/{riv_file_name}"),
+        "autoplay": payload.autoplay,
+        "layout": {
+            "fit": rive_enum_name(&payload.layout_fit, "Contain"),
+            "alignment": rive_enum_name(&payload.layout_alignment, "Center"),
+        },
+        "controlSnapshot": payload
+            .control_snapshot
+            .as_deref()
+            .and_then(|raw| serde_json::from_str::<Value>(raw).ok())
+            .filter(Value::is_array)
+            .unwrap_or_else(|| json!([])),
+    });
+    if let Some(artboard) = payload.artboard_name.as_deref() {
+        options["artboard"] = json!(artboard);
+    }
+    if !payload.state_machines.is_empty() {
+        options["stateMachines"] = json!(payload.state_machines);
+    } else if !payload.animations.is_empty() {
+        options["animations"] = json!(payload.animations);
+    }
+    if let Some(instance_name) = payload.view_model_instance_name.as_deref() {
+        options["viewModelInstanceName"] = json!(instance_name);
+    }
+    options
+}
+
+fn rive_enum_name(value: &str, fallback: &str) -> String {
+    let mut chars = value.trim().chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+        None => fallback.into(),
+    }
+}
+
+fn replace_indented(template: &str, placeholder: &str, value: &str) -> String {
+    let indent: String = template
+        .find(placeholder)
+        .map(|index| {
+            template[..index]
+                .rsplit('\n')
+                .next()
+                .unwrap_or_default()
+                .chars()
+                .take_while(|ch| ch.is_whitespace())
+                .collect()
+        })
+        .unwrap_or_default();
+    template.replace(placeholder, &value.replace('\n', &format!("\n{indent}")))
+}
+
+fn to_dependency_map(entries: &[(&str, &str)]) -> Map<String, Value> {
+    entries
+        .iter()
+        .map(|(name, version)| (name.to_string(), json!(version)))
+        .collect()
+}
+
+fn starter_templates(framework: StarterFramework) -> StarterTemplates {
+    match framework {
+        StarterFramework::React => StarterTemplates {
+            display_name: "React",
+            component_file: "RiveAnimation.jsx",
+            component: include_str!("../../../starter-template/react/RiveAnimation.jsx"),
+            entry_file: "main.jsx",
+            entry: include_str!("../../../starter-template/react/main.jsx"),
+            vite_config: include_str!("../../../starter-template/react/vite.config.js"),
+            dependencies: &[("react", "^18.3.1"), ("react-dom", "^18.3.1")],
+            dev_dependencies: &[("@vitejs/plugin-react", "^4.3.4")],
+        },
+        StarterFramework::Vue => StarterTemplates {
+            display_name: "Vue",
+            component_file: "RiveAnimation.vue",
+            component: include_str!("../../../starter-template/vue/RiveAnimation.vue"),
+            entry_file: "main.js",
+            entry: include_str!("../../../starter-template/vue/main.js"),
+            vite_config: include_str!("../../../starter-template/vue/vite.config.js"),
+            dependencies: &[("vue", "^3.5.13")],
+            dev_dependencies: &[("@vitejs/plugin-vue", "^5.2.1")],
+        },
+        StarterFramework::Svelte => StarterTemplates {
+            display_name: "Svelte",
+            component_file: "RiveAnimation.svelte",
+            component: include_str!("../../../starter-template/svelte/RiveAnimation.svelte"),
+            entry_file: "main.js",
+            entry: include_str!("../../../starter-template/svelte/main.js"),
+            vite_config: include_str!("../../../starter-template/svelte/vite.config.js"),
+            dependencies: &[],
+            dev_dependencies: &[
+                ("@sveltejs/vite-plugin-svelte", "^5.0.3"),
+                ("svelte", "^5.16.0"),
+            ],
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_starter_files, resolve_starter_framework, StarterFramework};
+    use crate::app::export::fixtures::{demo_payload, inline_json};
+    use crate::app::export::verify::find_placeholders;
+    use serde_json::{json, Value};
+
+    fn file<'a>(files: &'a [(String, Vec<u8>)], name: &str) -> &'a str {
+        let (_, contents) = files
+            .iter()
+            .find(|(path, _)| path == name)
+            .unwrap_or_else(|| panic!("missing {name}"));
+        std::str::from_utf8(contents).expect("utf8")
+    }
+
+    #[test]
+    fn react_starter_pins_the_local_runtime_and_embeds_current_settings() {
+        let mut payload = demo_payload();
+        payload.default_instantiation_package_source = "local".into();
+        payload.file_name = "Hero Banner.riv".into();
+        payload.layout_fit = "fitWidth".into();
+        payload.control_snapshot = Some(
+            r#"[{"descriptor":{"path":"score","kind":"number"},"kind":"number","value":7}]"#.into(),
+        );
+        let files = build_starter_files(&payload, StarterFramework::React).expect("files");
+        let package: Value =
+            serde_json::from_str(file(&files, "package.json")).expect("package json");
+        let component = file(&files, "src/RiveAnimation.jsx");
+
+        assert_eq!(package["name"], "hero-banner-react");
+        assert_eq!(package["dependencies"]["@rive-app/webgl2"], "2.37.0");
+        let options = inline_json(component, "const RIVE_OPTIONS = ");
+
+        assert_eq!(options["src"], "/hero-banner.riv");
+        assert_eq!(options["layout"]["fit"], "FitWidth");
+        assert_eq!(options["stateMachines"], json!(["main-sm"]));
+        assert_eq!(options["controlSnapshot"][0]["value"], 7);
+        assert!(file(&files, "src/rive-runtime.js").contains("from '@rive-app/webgl2'"));
+        assert!(file(&files, "index.html").contains("src=\"/src/main.jsx\""));
+        assert_eq!(
+            files
+                .iter()
+                .find(|(path, _)| path == "public/hero-banner.riv")
+                .map(|(_, bytes)| bytes.as_slice()),
+            Some(&[1u8, 2, 3][..])
+        );
+    }
+
+    #[test]
+    fn starters_refuse_to_guess_an_unknown_runtime_version() {
+        let mut payload = demo_payload();
+        payload.runtime_version = None;
+        let error = build_starter_files(&payload, StarterFramework::React).expect_err("unpinned");
+        assert!(
+            error.contains("@rive-app/webgl2 version is unknown"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn cdn_starters_load_the_runtime_from_unpkg_and_still_pin_it() {
+        for framework in ["vue", "svelte"] {
+            let framework = resolve_starter_framework(framework).expect("framework");
+            let files = build_starter_files(&demo_payload(), framework).expect("files");
+            let package: Value =
+                serde_json::from_str(file(&files, "package.json")).expect("package json");
+
+            assert_eq!(package["dependencies"]["@rive-app/webgl2"], "2.37.0");
+            assert!(file(&files, "src/rive-runtime.js")
+                .contains("https://unpkg.com/@rive-app/webgl2@2.37.0"));
+            assert!(file(&files, "README.md").contains("from unpkg at runtime"));
+            assert!(files
+                .iter()
+                .all(
+                    |(_, contents)| find_placeholders(&String::from_utf8_lossy(contents))
+                        .is_empty()
+                ));
+        }
+        assert!(resolve_starter_framework("Angular").is_err());
+    }
+}
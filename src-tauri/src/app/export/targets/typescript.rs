@@ -0,0 +1,391 @@
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+
+use crate::app::export::demo_bundle::prepare_output_path;
+use crate::app::export::manifest::parse_hierarchy;
+use crate::app::export::provenance::build_provenance;
+use crate::app::export::targets::pascal_case;
+use crate::app::state::DemoBundlePayload;
+
+const TYPED_VIEW_MODEL_TS: &str = include_str!("../../../codegen-template/typed-view-model.ts");
+const STATE_MACHINE_INPUT_SOURCE: &str = "state-machine";
+const VALUE_KINDS: [&str; 5] = ["number", "string", "boolean", "color", "enum"];
+const LIST_INDEX: &str = "*";
+
+#[derive(Default)]
+struct TypeScriptBindings {
+    used_names: BTreeSet<String>,
+    aliases: Vec<String>,
+    interfaces: Vec<String>,
+    properties: Vec<(String, String, String)>,
+    triggers: Vec<String>,
+    state_machine_inputs: BTreeMap<String, Vec<String>>,
+    /// Segment positions of the list indices enclosing the node being walked.
+    list_indices: Vec<usize>,
+}
+
+#[tauri::command]
+pub async fn make_typescript_bindings_to_path(
+    payload: DemoBundlePayload,
+    output_path: String,
+) -> Result<String, String> {
+    let path = prepare_output_path(&output_path)?;
+    let bindings = build_typescript_bindings(&payload)?;
+    fs::write(&path, bindings)
+        .map_err(|error| format!("Failed to write {}: {}", path.display(), error))?;
+    Ok(path.to_string_lossy().to_string())
+}
+
+pub fn build_typescript_bindings(payload: &DemoBundlePayload) -> Result<String, String> {
+    let root = parse_hierarchy(payload.vm_hierarchy.as_deref()).ok_or_else(|| {
+        "The loaded file has no ViewModel hierarchy to generate bindings from".to_string()
+    })?;
+
+    let mut bindings = TypeScriptBindings::default();
+    let root_name = bindings.unique_name(&pascal_case(
+        root["label"].as_str().unwrap_or("ViewModel"),
+        "ViewModel",
+    ));
+    bindings.walk(&root, &root_name, true);
+    if let Some(state_machines) = parse_hierarchy(payload.state_machine_hierarchy.as_deref()) {
+        bindings.walk_state_machines(&state_machines);
+    }
+
+    let provenance = build_provenance(payload);
+    let mut out = format!(
+        "// Generated by Rive Animation Viewer {} from {}. Do not edit by hand.\n// Source SHA-256: {}\n\n",
+        env!("CARGO_PKG_VERSION"),
+        payload.file_name,
+        provenance["source"]["sha256"].as_str().unwrap_or("unknown"),
+    );
+    out.push_str("/** Color packed as 0xAARRGGBB. */\nexport type RiveColor = number;\n\n/** Fires the trigger. */\nexport type RiveTrigger = () => void;\n\n");
+    for alias in &bindings.aliases {
+        out.push_str(alias);
+        out.push('\n');
+    }
+    if !bindings.aliases.is_empty() {
+        out.push('\n');
+    }
+    for interface in &bindings.interfaces {
+        out.push_str(interface);
+        out.push('\n');
+    }
+
+    out.push_str("export interface ViewModelPropertyTypes {\n");
+    for (path, ts_type, _) in &bindings.properties {
+        let key = if is_list_pattern(path) {
+            format!("[path: {}]", path_type(path))
+        } else {
+            quote(path)
+        };
+        out.push_str(&format!("  {key}: {ts_type};\n"));
+    }
+    out.push_str("}\n\nexport type ViewModelPropertyPath = keyof ViewModelPropertyTypes;\n\n");
+    out.push_str(&format!(
+        "export type ViewModelTriggerPath = {};\n\n",
+        union(&bindings.triggers, path_type)
+    ));
+
+    out.push_str("export interface StateMachineInputs {\n");
+    for (state_machine, inputs) in &bindings.state_machine_inputs {
+        out.push_str(&format!(
+            "  {}: {{ {} }};\n",
+            quote(state_machine),
+            inputs.join(" ")
+        ));
+    }
+    out.push_str("}\n\nexport const VIEW_MODEL_PROPERTY_KINDS = {\n");
+    for (path, _, kind) in &bindings.properties {
+        out.push_str(&format!("  {}: {},\n", quote(path), quote(kind)));
+    }
+    out.push_str("} as const;\n\n");
+    out.push_str(TYPED_VIEW_MODEL_TS);
+    Ok(out)
+}
+
+impl TypeScriptBindings {
+    fn walk(&mut self, node: &Value, type_name: &str, emit: bool) {
+        let mut members = Vec::new();
+        for input in children(node, "inputs") {
+            let field = |key: &str| {
+                input
+                    .get(key)
+                    .or_else(|| input.get("descriptor").and_then(|d| d.get(key)))
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string()
+            };
+            let (name, path, kind) = (field("name"), field("path"), field("kind"));
+            if name.is_empty() || path.is_empty() {
+                continue;
+            }
+            let ts_type = match kind.as_str() {
+                "number" => "number".to_string(),
+                "string" => "string".to_string(),
+                "boolean" => "boolean".to_string(),
+                "color" => "RiveColor".to_string(),
+                "trigger" => "RiveTrigger".to_string(),
+                "enum" => self.enum_alias(type_name, &name, input, emit),
+                _ => "unknown".to_string(),
+            };
+            let member = format!("readonly {}: {ts_type};", property_key(&name));
+
+            if field("source") == STATE_MACHINE_INPUT_SOURCE {
+                if emit {
+                    self.add_state_machine_input(field("stateMachineName"), member);
+                }
+                continue;
+            }
+            members.push(member);
+            let path = self.path_pattern(&path);
+            if kind == "trigger" {
+                if !self.triggers.contains(&path) {
+                    self.triggers.push(path);
+                }
+            } else if VALUE_KINDS.contains(&kind.as_str())
+                && !self.properties.iter().any(|(known, _, _)| *known == path)
+            {
+                self.properties.push((path, ts_type, kind));
+            }
+        }
+
+        for child in children(node, "children") {
+            let path = child["path"].as_str().unwrap_or_default();
+            let name = path.rsplit('/').next().unwrap_or(path).to_string();
+            match child["kind"].as_str() {
+                Some("list") => {
+                    let item_name = emit.then(|| {
+                        self.unique_name(&format!("{type_name}{}Item", pascal_case(&name, "")))
+                    });
+                    let mut item_type = "unknown".to_string();
+                    self.list_indices.push(path.split('/').count());
+                    for (index, item) in children(child, "children").enumerate() {
+                        let item_emit = emit && index == 0;
+                        let name = item_name.clone().unwrap_or_default();
+                        self.walk(item, &name, item_emit);
+                        if item_emit {
+                            item_type = name;
+                        }
+                    }
+                    self.list_indices.pop();
+                    members.push(format!(
+                        "readonly {}: readonly {item_type}[];",
+                        property_key(&name)
+                    ));
+                }
+                _ => {
+                    let child_name = if emit {
+                        self.unique_name(&format!("{type_name}{}", pascal_case(&name, "")))
+                    } else {
+                        String::new()
+                    };
+                    self.walk(child, &child_name, emit);
+                    members.push(format!("readonly {}: {child_name};", property_key(&name)));
+                }
+            }
+        }
+
+        if emit {
+            let body: String = members
+                .iter()
+                .map(|member| format!("  {member}\n"))
+                .collect();
+            self.interfaces
+                .push(format!("export interface {type_name} {{\n{body}}}\n"));
+        }
+    }
+
+    /// State machine inputs serialized apart from the ViewModel tree.
+    fn walk_state_machines(&mut self, node: &Value) {
+        for input in children(node, "inputs") {
+            let field = |key: &str| input[key].as_str().unwrap_or_default().to_string();
+            let ts_type = match field("kind").as_str() {
+                "number" => "number",
+                "boolean" => "boolean",
+                "trigger" => "RiveTrigger",
+                _ => "unknown",
+            };
+            let (name, state_machine) = (field("name"), field("stateMachineName"));
+            if !name.is_empty() && !state_machine.is_empty() {
+                let member = format!("readonly {}: {ts_type};", property_key(&name));
+                self.add_state_machine_input(state_machine, member);
+            }
+        }
+        for child in children(node, "children") {
+            self.walk_state_machines(child);
+        }
+    }
+
+    fn add_state_machine_input(&mut self, state_machine: String, member: String) {
+        let inputs = self.state_machine_inputs.entry(state_machine).or_default();
+        if !inputs.contains(&member) {
+            inputs.push(member);
+        }
+    }
+
+    /// Replaces the index segments of enclosing lists with `*`, so every row maps to one key.
+    fn path_pattern(&self, path: &str) -> String {
+        let mut segments: Vec<&str> = path.split('/').collect();
+        for &index in &self.list_indices {
+            if let Some(segment) = segments.get_mut(index) {
+                *segment = LIST_INDEX;
+            }
+        }
+        segments.join("/")
+    }
+
+    fn enum_alias(&mut self, type_name: &str, name: &str, input: &Value, emit: bool) -> String {
+        let values: Vec<String> = input["enumValues"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .map(str::to_string)
+            .collect();
+        if values.is_empty() {
+            return "string".into();
+        }
+        let union = union(&values, quote);
+        if !emit {
+            return union;
+        }
+        let alias = self.unique_name(&format!("{type_name}{}", pascal_case(name, "Enum")));
+        self.aliases.push(format!("export type {alias} = {union};"));
+        alias
+    }
+
+    fn unique_name(&mut self, base: &str) -> String {
+        let mut name = base.to_string();
+        let mut suffix = 2;
+        while self.used_names.contains(&name) {
+            name = format!("{base}{suffix}");
+            suffix += 1;
+        }
+        self.used_names.insert(name.clone());
+        name
+    }
+}
+
+fn children<'a>(node: &'a Value, key: &str) -> impl Iterator<Item = &'a Value> {
+    node.get(key)
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+}
+
+fn property_key(name: &str) -> String {
+    let is_identifier = name
+        .chars()
+        .next()
+        .is_some_and(|ch| ch.is_ascii_alphabetic() || ch == '_' || ch == '$')
+        && name
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '$');
+    if is_identifier {
+        name.to_string()
+    } else {
+        quote(name)
+    }
+}
+
+fn quote(raw: &str) -> String {
+    Value::String(raw.to_string()).to_string()
+}
+
+fn is_list_pattern(path: &str) -> bool {
+    path.split('/').any(|segment| segment == LIST_INDEX)
+}
+
+/// A string literal type, or a template literal type with `${number}` for each list index.
+fn path_type(path: &str) -> String {
+    if !is_list_pattern(path) {
+        return quote(path);
+    }
+    let segments: Vec<String> = path
+        .split('/')
+        .map(|segment| match segment {
+            LIST_INDEX => "${number}".to_string(),
+            _ => segment
+                .replace('\\', "\\\\")
+                .replace('`', "\\`")
+                .replace("${", "\\${"),
+        })
+        .collect();
+    format!("`{}`", segments.join("/"))
+}
+
+fn union(values: &[String], literal: fn(&str) -> String) -> String {
+    if values.is_empty() {
+        "never".into()
+    } else {
+        values
+            .iter()
+            .map(|value| literal(value))
+            .collect::<Vec<_>>()
+            .join(" | ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::build_typescript_bindings;
+    use crate::app::export::fixtures::{demo_payload, STATE_MACHINE_HIERARCHY};
+
+    #[test]
+    fn bindings_cover_paths_enum_unions_lists_and_triggers() {
+        let mut payload = demo_payload();
+        payload.vm_hierarchy = Some(
+            r#"{"kind":"vm","label":"Card","path":"<root>","inputs":[
+                {"kind":"number","name":"score","path":"score","value":42},
+                {"kind":"enum","name":"mode","path":"mode","value":"dark","enumValues":["light","dark"]},
+                {"kind":"trigger","name":"pulse","path":"pulse","value":null}
+            ],"children":[
+                {"kind":"vm","label":"header","path":"header","children":[],"inputs":[
+                    {"kind":"color","name":"tint","path":"header/tint","value":-1}
+                ]},
+                {"kind":"list","label":"rows [2]","path":"rows","inputs":[],"children":[
+                    {"kind":"instance","label":"Row 1","path":"rows/0","children":[],"inputs":[
+                        {"kind":"string","name":"title","path":"rows/0/title","value":"A"},
+                        {"kind":"trigger","name":"open","path":"rows/0/open","value":null}
+                    ]},
+                    {"kind":"instance","label":"Row 2","path":"rows/1","children":[],"inputs":[
+                        {"kind":"string","name":"title","path":"rows/1/title","value":"B"},
+                        {"kind":"trigger","name":"open","path":"rows/1/open","value":null}
+                    ]}
+                ]}
+            ]}"#
+            .into(),
+        );
+        payload.state_machine_hierarchy = Some(STATE_MACHINE_HIERARCHY.into());
+        let ts = build_typescript_bindings(&payload).expect("bindings");
+
+        assert!(ts.contains("export type CardMode = \"light\" | \"dark\";"));
+        assert!(ts.contains(
+            "export interface Card {\n  readonly score: number;\n  readonly mode: CardMode;\n  readonly pulse: RiveTrigger;\n  readonly header: CardHeader;\n  readonly rows: readonly CardRowsItem[];\n}"
+        ));
+        assert!(ts.contains("export interface CardHeader {\n  readonly tint: RiveColor;\n}"));
+        assert!(ts.contains(
+            "export interface CardRowsItem {\n  readonly title: string;\n  readonly open: RiveTrigger;\n}"
+        ));
+        assert!(ts.contains(
+            "  \"header/tint\": RiveColor;\n  [path: `rows/${number}/title`]: string;\n}"
+        ));
+        assert!(!ts.contains("rows/1"));
+        assert!(
+            ts.contains("export type ViewModelTriggerPath = \"pulse\" | `rows/${number}/open`;")
+        );
+        assert!(
+            ts.contains("  \"main-sm\": { readonly armed: boolean; readonly fire: RiveTrigger; };")
+        );
+        assert!(ts.contains("  \"mode\": \"enum\",\n"));
+        assert!(ts.contains("  \"rows/*/title\": \"string\",\n"));
+        assert!(ts.contains("export function createTypedViewModel("));
+        assert_eq!(ts.matches("export interface CardRowsItem").count(), 1);
+    }
+
+    #[test]
+    fn bindings_require_a_view_model_hierarchy() {
+        assert!(build_typescript_bindings(&demo_payload()).is_err());
+    }
+}
@@ -0,0 +1,288 @@
+use serde_json::{json, Value};
+use std::fs;
+use std::path::Path;
+
+use crate::app::export::demo_bundle::{
+    build_demo_config, escape_embedded_script_json, prepare_output_path,
+};
+use crate::app::export::embed::{css_background, resolve_embed_frame_size};
+use crate::app::export::manifest::build_interface_manifest;
+use crate::app::export::targets::split_words;
+use crate::app::state::{DemoBundlePayload, WebComponentOptions};
+
+const COMPONENT_TEMPLATE_JS: &str = concat!(
+    include_str!("../../../demo-template/js/component/preamble.js"),
+    "\n",
+    include_str!("../../../demo-template/js/core/layout.js"),
+    "\n",
+    include_str!("../../../demo-template/js/vm/accessors.js"),
+    "\n",
+    include_str!("../../../demo-template/js/component/element.js"),
+);
+const RESERVED_MEMBER_NAMES: [&str; 16] = [
+    "src", "id", "class", "style", "title", "hidden", "slot", "lang", "dir", "part", "role",
+    "canvas", "fire", "remove", "click", "focus",
+];
+const RESERVED_TAG_NAMES: [&str; 8] = [
+    "annotation-xml",
+    "color-profile",
+    "font-face",
+    "font-face-src",
+    "font-face-uri",
+    "font-face-format",
+    "font-face-name",
+    "missing-glyph",
+];
+
+#[tauri::command]
+pub async fn make_web_component_to_path(
+    payload: DemoBundlePayload,
+    output_path: String,
+    options: Option<WebComponentOptions>,
+) -> Result<String, String> {
+    let path = prepare_output_path(&output_path)?;
+    let module = build_web_component_module(&payload, &options.unwrap_or_default(), &path)?;
+    fs::write(&path, module)
+        .map_err(|error| format!("Failed to write {}: {}", path.display(), error))?;
+    Ok(path.to_string_lossy().to_string())
+}
+
+pub fn build_web_component_module(
+    payload: &DemoBundlePayload,
+    options: &WebComponentOptions,
+    path: &Path,
+) -> Result<String, String> {
+    let tag_name = resolve_web_component_tag(options.tag_name.as_deref(), &payload.file_name)?;
+    let riv_url = options
+        .riv_url
+        .as_deref()
+        .map(str::trim)
+        .filter(|url| !url.is_empty());
+    let mut config = build_demo_config(payload);
+    if riv_url.is_some() {
+        config["animationBase64"] = Value::String(String::new());
+    }
+
+    let frame = resolve_embed_frame_size(payload);
+    let manifest = build_interface_manifest(payload);
+    let mut properties = Vec::new();
+    let mut triggers = Vec::new();
+    let mut used_names = Vec::new();
+    for entry in manifest["viewModel"]["properties"]
+        .as_array()
+        .into_iter()
+        .flatten()
+    {
+        let (Some(path), Some(kind)) = (entry["path"].as_str(), entry["type"].as_str()) else {
+            continue;
+        };
+        match kind {
+            "list" => continue,
+            "trigger" => {
+                triggers.push(path.to_string());
+                continue;
+            }
+            _ => {}
+        }
+        let (attribute, property) = component_member_names(path, &used_names);
+        used_names.push(attribute.clone());
+        let mut component_property = json!({
+            "path": path,
+            "kind": kind,
+            "attribute": attribute,
+            "property": property,
+            "default": entry["default"],
+        });
+        if let Some(enum_values) = entry.get("enumValues") {
+            component_property["enumValues"] = enum_values.clone();
+        }
+        properties.push(component_property);
+    }
+
+    let component = json!({
+        "tagName": tag_name,
+        "runtimeSrc": format!(
+            "https://unpkg.com/@rive-app/{}@{}",
+            payload.runtime_name,
+            payload.runtime_version.as_deref().unwrap_or("latest")
+        ),
+        "rivUrl": riv_url,
+        "aspectRatio": format!("{} / {}", frame.width, frame.height),
+        "background": css_background(payload),
+        "properties": properties,
+        "triggers": triggers,
+    });
+    let config_json = serde_json::to_string(&config).map_err(|error| error.to_string())?;
+    let component_json = serde_json::to_string(&component).map_err(|error| error.to_string())?;
+    let module_file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| format!("{tag_name}.js"));
+
+    Ok(COMPONENT_TEMPLATE_JS
+        .replace(
+            "__CONFIG_JSON__",
+            &escape_embedded_script_json(&config_json),
+        )
+        .replace(
+            "__COMPONENT_JSON__",
+            &escape_embedded_script_json(&component_json),
+        )
+        .replace("__MODULE_FILE_NAME__", &module_file_name)
+        .replace("__TAG_NAME__", &tag_name))
+}
+
+pub fn resolve_web_component_tag(
+    requested: Option<&str>,
+    file_name: &str,
+) -> Result<String, String> {
+    let requested = requested.map(str::trim).filter(|tag| !tag.is_empty());
+    let Some(tag) = requested else {
+        let stem = Path::new(file_name)
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let slug = split_words(&stem).join("-");
+        return Ok(if slug.is_empty() {
+            "rive-animation".into()
+        } else {
+            format!("rive-{slug}")
+        });
+    };
+
+    let valid = tag.starts_with(|ch: char| ch.is_ascii_lowercase())
+        && tag.contains('-')
+        && tag
+            .chars()
+            .all(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit() || "-._".contains(ch))
+        && !RESERVED_TAG_NAMES.contains(&tag);
+    if valid {
+        Ok(tag.to_string())
+    } else {
+        Err(format!(
+            "Invalid custom element name \"{tag}\" (use lowercase letters and digits, start with a letter and include a hyphen)"
+        ))
+    }
+}
+
+fn component_member_names(path: &str, used: &[String]) -> (String, String) {
+    let mut words = split_words(path);
+    let first = words.first().map(String::as_str).unwrap_or_default();
+    let reserved = words.len() == 1 && RESERVED_MEMBER_NAMES.contains(&first);
+    if words.is_empty() || reserved || first.starts_with(|ch: char| ch.is_ascii_digit()) {
+        words.insert(0, "vm".into());
+    }
+
+    let base = words.join("-");
+    let mut attribute = base.clone();
+    let mut suffix = 2;
+    while used.contains(&attribute) {
+        attribute = format!("{base}-{suffix}");
+        suffix += 1;
+    }
+    let property = attribute
+        .split('-')
+        .enumerate()
+        .map(|(index, word)| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) if index > 0 => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                _ => word.to_string(),
+            }
+        })
+        .collect();
+    (attribute, property)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_web_component_module, resolve_web_component_tag};
+    use crate::app::export::fixtures::{demo_payload, embedded_json};
+    use crate::app::export::verify::find_placeholders;
+    use crate::app::state::WebComponentOptions;
+    use serde_json::json;
+    use std::path::Path;
+
+    const COMPONENT_MARKER: &str = "const COMPONENT = JSON.parse('";
+    const CONFIG_MARKER: &str = "const CONFIG = JSON.parse('";
+
+    #[test]
+    fn tag_defaults_to_the_file_stem_and_rejects_invalid_names() {
+        assert_eq!(
+            resolve_web_component_tag(None, "Hero Banner_v2.riv"),
+            Ok("rive-hero-banner-v2".into())
+        );
+        assert_eq!(
+            resolve_web_component_tag(None, "???.riv"),
+            Ok("rive-animation".into())
+        );
+        assert_eq!(
+            resolve_web_component_tag(Some(" my-card "), "demo.riv"),
+            Ok("my-card".into())
+        );
+        assert!(resolve_web_component_tag(Some("Card"), "demo.riv").is_err());
+        assert!(resolve_web_component_tag(Some("card"), "demo.riv").is_err());
+        assert!(resolve_web_component_tag(Some("font-face"), "demo.riv").is_err());
+    }
+
+    #[test]
+    fn module_exposes_vm_properties_as_attributes_and_camel_case_properties() {
+        let mut payload = demo_payload();
+        payload.vm_hierarchy = Some(
+            r#"{"kind":"vm","label":"Card","path":"<root>","children":[],"inputs":[
+                {"kind":"number","name":"scoreValue","path":"scoreValue","value":42},
+                {"kind":"string","name":"title","path":"title","value":"Hi"},
+                {"kind":"color","name":"tint","path":"header/tint","value":-1},
+                {"kind":"trigger","name":"pulse","path":"pulse","value":null}
+            ]}"#
+            .into(),
+        );
+        let module = build_web_component_module(
+            &payload,
+            &WebComponentOptions::default(),
+            Path::new("/tmp/demo-card.js"),
+        )
+        .expect("module");
+
+        let component = embedded_json(&module, COMPONENT_MARKER);
+        let properties = component["properties"].as_array().expect("properties");
+
+        assert_eq!(component["tagName"], "rive-demo");
+        assert_eq!(
+            properties[0],
+            json!({ "attribute": "score-value", "default": 42, "kind": "number", "path": "scoreValue", "property": "scoreValue" })
+        );
+        assert_eq!(properties[1]["attribute"], "vm-title");
+        assert_eq!(properties[2]["property"], "headerTint");
+        assert_eq!(component["triggers"], json!(["pulse"]));
+        assert_eq!(
+            component["runtimeSrc"],
+            "https://unpkg.com/@rive-app/webgl2@2.37.0"
+        );
+        assert_eq!(
+            embedded_json(&module, CONFIG_MARKER)["animationBase64"],
+            "AQID"
+        );
+        assert!(module.contains("<script type=\"module\" src=\"./demo-card.js\">"));
+        assert!(module.contains("customElements.define(COMPONENT.tagName, RiveExportElement)"));
+        assert_eq!(find_placeholders(&module), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn riv_url_replaces_the_embedded_animation() {
+        let options = WebComponentOptions {
+            tag_name: Some("x-hero".into()),
+            riv_url: Some("https://cdn.example.com/hero.riv".into()),
+        };
+        let module =
+            build_web_component_module(&demo_payload(), &options, Path::new("/tmp/hero.js"))
+                .expect("module");
+
+        assert_eq!(embedded_json(&module, CONFIG_MARKER)["animationBase64"], "");
+        assert_eq!(
+            embedded_json(&module, COMPONENT_MARKER)["rivUrl"],
+            "https://cdn.example.com/hero.riv"
+        );
+        assert!(module.contains("<x-hero></x-hero>"));
+    }
+}
@@ -0,0 +1,302 @@
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+use crate::app::export::demo_bundle::DEMO_TEMPLATES;
+use crate::app::state::{DemoBundlePayload, DemoVerificationCheck, DemoVerificationReport};
+
+pub const DEFAULT_DEMO_SIZE_BUDGET_BYTES: u64 = 64 * 1024 * 1024;
+
+const EMBEDDED_JSON_BLOCKS: [(&str, &str); 2] = [
+    ("config-json", "const CONFIG = JSON.parse('"),
+    ("vm-hierarchy-json", "const VM_HIERARCHY = JSON.parse('"),
+];
+
+pub fn verify_demo_html(
+    html: &str,
+    templates: &[&str],
+    payload: &DemoBundlePayload,
+    size_budget_bytes: u64,
+) -> DemoVerificationReport {
+    let escaped_runtime = payload.runtime_script.replace("</script", "<\\/script");
+    let runtime_tag = format!("<script>{escaped_runtime}</script>");
+    let runtime_intact = html.contains(&runtime_tag);
+    let scanned = if runtime_intact {
+        html.replacen(&runtime_tag, "", 1)
+    } else {
+        html.to_string()
+    };
+
+    let mut checks = vec![check_placeholders(&scanned, templates)];
+    checks.extend(
+        EMBEDDED_JSON_BLOCKS
+            .iter()
+            .map(|(name, marker)| check_embedded_json(&scanned, name, marker)),
+    );
+    checks.push(DemoVerificationCheck {
+        name: "runtime-script".into(),
+        passed: runtime_intact,
+        detail: (!runtime_intact).then(|| {
+            format!(
+                "the {}-byte runtime script is missing or truncated",
+                payload.runtime_script.len()
+            )
+        }),
+    });
+
+    let size_bytes = html.len() as u64;
+    checks.push(DemoVerificationCheck {
+        name: "size-budget".into(),
+        passed: size_bytes <= size_budget_bytes,
+        detail: (size_bytes > size_budget_bytes)
+            .then(|| format!("{size_bytes} bytes exceeds the {size_budget_bytes}-byte budget")),
+    });
+
+    DemoVerificationReport {
+        passed: checks.iter().all(|check| check.passed),
+        size_bytes,
+        size_budget_bytes,
+        checks,
+    }
+}
+
+/// Writes the page to a `.tmp` sibling, verifies what landed on disk and only then renames it
+/// over `path`. A failed export reports which checks failed and leaves any previous file intact.
+pub fn write_verified_demo_html(
+    path: &Path,
+    html: &str,
+    payload: &DemoBundlePayload,
+    size_budget_bytes: u64,
+) -> Result<DemoVerificationReport, String> {
+    let mut temp_name = path.file_name().unwrap_or_default().to_os_string();
+    temp_name.push(".tmp");
+    let temp_path = path.with_file_name(temp_name);
+    fs::write(&temp_path, html)
+        .map_err(|error| format!("Failed to write {}: {}", temp_path.display(), error))?;
+    let verification = fs::read_to_string(&temp_path)
+        .map_err(|error| format!("Failed to read back {}: {}", temp_path.display(), error))
+        .map(|written| verify_demo_html(&written, &DEMO_TEMPLATES, payload, size_budget_bytes));
+    let renamed = match &verification {
+        Ok(verification) if verification.passed => fs::rename(&temp_path, path)
+            .map_err(|error| format!("Failed to write {}: {}", path.display(), error)),
+        _ => Ok(()),
+    };
+    let _ = fs::remove_file(&temp_path);
+    renamed.and(verification)
+}
+
+pub fn describe_verification_failure(report: &DemoVerificationReport) -> String {
+    let failures: Vec<String> = report
+        .checks
+        .iter()
+        .filter(|check| !check.passed)
+        .map(|check| match &check.detail {
+            Some(detail) => format!("{}: {}", check.name, detail),
+            None => check.name.clone(),
+        })
+        .collect();
+    format!("Demo export failed verification ({})", failures.join("; "))
+}
+
+fn check_placeholders(html: &str, templates: &[&str]) -> DemoVerificationCheck {
+    let known: BTreeSet<&str> = templates
+        .iter()
+        .flat_map(|template| find_placeholders(template))
+        .collect();
+    let remaining: Vec<&str> = known
+        .into_iter()
+        .filter(|placeholder| html.contains(placeholder))
+        .collect();
+
+    DemoVerificationCheck {
+        name: "placeholders".into(),
+        passed: remaining.is_empty(),
+        detail: (!remaining.is_empty())
+            .then(|| format!("unresolved placeholders {}", remaining.join(", "))),
+    }
+}
+
+fn check_embedded_json(html: &str, name: &str, marker: &str) -> DemoVerificationCheck {
+    let result = html
+        .find(marker)
+        .and_then(|start| read_script_literal(&html[start + marker.len()..]))
+        .ok_or_else(|| "embedded JSON block not found".to_string())
+        .and_then(|literal| {
+            serde_json::from_str::<Value>(&literal)
+                .map_err(|error| format!("does not parse after unescaping: {error}"))
+        });
+
+    DemoVerificationCheck {
+        name: name.into(),
+        passed: result.is_ok(),
+        detail: result.err(),
+    }
+}
+
+pub(crate) fn find_placeholders(template: &str) -> Vec<&str> {
+    let bytes = template.as_bytes();
+    let mut found = Vec::new();
+    let mut index = 0;
+    while let Some(offset) = template[index..].find("__") {
+        let start = index + offset;
+        let body_len = bytes[start + 2..]
+            .iter()
+            .take_while(|byte| byte.is_ascii_uppercase() || byte.is_ascii_digit() || **byte == b'_')
+            .count();
+        let end = start + 2 + body_len;
+        if body_len > 2 && template[..end].ends_with("__") {
+            found.push(&template[start..end]);
+            index = end;
+        } else {
+            index = start + 2;
+        }
+    }
+    found
+}
+
+pub(crate) fn read_script_literal(rest: &str) -> Option<String> {
+    let mut literal = String::new();
+    let mut chars = rest.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => literal.push(chars.next()?),
+            '\'' => return Some(literal),
+            _ => literal.push(ch),
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_placeholders, verify_demo_html, DEFAULT_DEMO_SIZE_BUDGET_BYTES};
+    use crate::app::export::demo_bundle::{build_demo_html, write_demo_bundle, DEMO_TEMPLATES};
+    use crate::app::export::fixtures::demo_payload;
+    use crate::app::state::DemoExportOptions;
+
+    #[test]
+    fn finds_template_placeholders_but_not_dunder_identifiers() {
+        let template = "<b>__TITLE__</b> __proto__ __ __CONFIG_JSON__ x__y";
+
+        assert_eq!(
+            find_placeholders(template),
+            ["__TITLE__", "__CONFIG_JSON__"]
+        );
+    }
+
+    #[test]
+    fn generated_demo_passes_every_check() {
+        let mut payload = demo_payload();
+        payload.instantiation_code = "console.log('it\\'s </script> fine');".into();
+        payload.vm_hierarchy = Some(r#"{"label":"Root","text":"</script>"}"#.into());
+        let html = build_demo_html(&payload).expect("demo html");
+        let report = verify_demo_html(
+            &html,
+            &DEMO_TEMPLATES,
+            &payload,
+            DEFAULT_DEMO_SIZE_BUDGET_BYTES,
+        );
+
+        assert!(
+            report.passed,
+            "{:?}",
+            report
+                .checks
+                .iter()
+                .map(|check| &check.detail)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(report.checks.len(), 5);
+        assert_eq!(report.size_bytes, html.len() as u64);
+    }
+
+    #[test]
+    fn reports_each_broken_invariant() {
+        let payload = demo_payload();
+        let html = build_demo_html(&payload)
+            .expect("demo html")
+            .replacen("</head>", "__CANVAS_COLOR__</head>", 1)
+            .replacen(
+                "const CONFIG = JSON.parse('{",
+                "const CONFIG = JSON.parse('{,",
+                1,
+            )
+            .replacen("console.log('runtime');", "console.log('run", 1);
+        let report = verify_demo_html(&html, &DEMO_TEMPLATES, &payload, 1024);
+        let failed: Vec<&str> = report
+            .checks
+            .iter()
+            .filter(|check| !check.passed)
+            .map(|check| check.name.as_str())
+            .collect();
+
+        assert!(!report.passed);
+        assert_eq!(
+            failed,
+            [
+                "placeholders",
+                "config-json",
+                "runtime-script",
+                "size-budget"
+            ]
+        );
+        assert_eq!(
+            report.checks[0].detail.as_deref(),
+            Some("unresolved placeholders __CANVAS_COLOR__")
+        );
+    }
+
+    #[test]
+    fn failed_exports_return_the_report_and_leave_no_file() {
+        let dir = std::env::temp_dir().join(format!("rav-verify-{}", std::process::id()));
+        let path = dir.join("demo.html");
+        std::fs::create_dir_all(&dir).expect("temp dir");
+        let options = DemoExportOptions {
+            interface_manifest: true,
+            max_size_bytes: Some(1),
+            ..DemoExportOptions::default()
+        };
+
+        let report = write_demo_bundle(&demo_payload(), &path, &options).expect("report");
+        let failed: Vec<&str> = report
+            .verification
+            .checks
+            .iter()
+            .filter(|check| !check.passed)
+            .map(|check| check.name.as_str())
+            .collect();
+
+        assert!(!report.verification.passed);
+        assert_eq!(failed, ["size-budget"]);
+        assert!(!path.exists());
+        assert!(!dir.join("demo.rav.json").exists());
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn failed_re_exports_keep_the_previous_demo() {
+        let dir = std::env::temp_dir().join(format!("rav-verify-keep-{}", std::process::id()));
+        let path = dir.join("demo.html");
+        std::fs::create_dir_all(&dir).expect("temp dir");
+        let options = DemoExportOptions::default();
+        assert!(
+            write_demo_bundle(&demo_payload(), &path, &options)
+                .expect("report")
+                .verification
+                .passed
+        );
+        let previous = std::fs::read_to_string(&path).expect("previous demo");
+
+        let over_budget = DemoExportOptions {
+            max_size_bytes: Some(1),
+            ..options
+        };
+        let report = write_demo_bundle(&demo_payload(), &path, &over_budget).expect("report");
+
+        assert!(!report.verification.passed);
+        assert_eq!(std::fs::read_to_string(&path).expect("kept"), previous);
+        assert!(!dir.join("demo.html.tmp").exists());
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,310 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use std::fs;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, SystemTime};
+use tauri::{Emitter, Manager};
+
+use crate::app::export::demo_bundle::{prepare_output_path, write_demo_bundle};
+use crate::app::export::verify::describe_verification_failure;
+use crate::app::state::{
+    ActiveExportWatch, DemoBundlePayload, DemoExportOptions, DemoExportReport, ExportSizeDiff,
+    ExportWatchEvent, ExportWatchManager, ExportWatchStatus, LastDemoExport,
+};
+
+pub const EXPORT_WATCH_EVENT: &str = "export-watch";
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+#[tauri::command]
+pub fn start_export_watch(
+    app: tauri::AppHandle,
+    watch: tauri::State<'_, ExportWatchManager>,
+    source_path: String,
+) -> Result<ExportWatchStatus, String> {
+    let source_path = source_path.trim().to_string();
+    if source_path.is_empty() {
+        return Err("source_path is empty".into());
+    }
+    let mut fingerprint = file_fingerprint(Path::new(&source_path))
+        .ok_or_else(|| format!("Cannot watch {source_path}: the file does not exist"))?;
+    // The thread keeps its own copy, so a later export of another file cannot redirect it.
+    let mut job = watch
+        .last_export
+        .lock()
+        .map_err(|_| "Export watch state is unavailable".to_string())?
+        .clone()
+        .ok_or_else(|| {
+            "Export a demo to a path first; watch mode re-runs the last export".to_string()
+        })?;
+
+    stop_active_watch(&watch);
+    let stop = Arc::new(AtomicBool::new(false));
+    let export_count = Arc::new(AtomicU64::new(0));
+    let output_path = job.output_path.clone();
+    {
+        let stop = Arc::clone(&stop);
+        let export_count = Arc::clone(&export_count);
+        let source_path = source_path.clone();
+        thread::spawn(move || {
+            while !stop.load(Ordering::Relaxed) {
+                thread::sleep(POLL_INTERVAL);
+                let current = file_fingerprint(Path::new(&source_path));
+                if current.is_none() || current == Some(fingerprint) {
+                    continue;
+                }
+                // Wait for one quiet interval so a save in progress is not exported half-written.
+                thread::sleep(POLL_INTERVAL);
+                if stop.load(Ordering::Relaxed)
+                    || file_fingerprint(Path::new(&source_path)) != current
+                {
+                    continue;
+                }
+                fingerprint = current.unwrap_or(fingerprint);
+                let event = rerun_export(&mut job, &source_path);
+                if event.ok {
+                    export_count.fetch_add(1, Ordering::Relaxed);
+                    remember_rerun(&app.state::<ExportWatchManager>(), &job);
+                }
+                let _ = app.emit(EXPORT_WATCH_EVENT, event);
+            }
+        });
+    }
+
+    if let Ok(mut active) = watch.active.lock() {
+        *active = Some(ActiveExportWatch {
+            source_path,
+            output_path,
+            stop,
+            export_count,
+        });
+    }
+    Ok(export_watch_status(&watch))
+}
+
+#[tauri::command]
+pub fn stop_export_watch(watch: tauri::State<'_, ExportWatchManager>) -> ExportWatchStatus {
+    stop_active_watch(&watch);
+    export_watch_status(&watch)
+}
+
+#[tauri::command]
+pub fn get_export_watch_status(watch: tauri::State<'_, ExportWatchManager>) -> ExportWatchStatus {
+    export_watch_status(&watch)
+}
+
+/// Writes the demo and remembers the export so watch mode can replay it.
+pub fn export_and_remember(
+    watch: &ExportWatchManager,
+    payload: DemoBundlePayload,
+    output_path: String,
+    options: DemoExportOptions,
+) -> Result<DemoExportReport, String> {
+    let path = prepare_output_path(&output_path)?;
+    let report = write_demo_bundle(&payload, &path, &options)?;
+    if !report.verification.passed {
+        return Ok(report);
+    }
+    if let Ok(mut last_export) = watch.last_export.lock() {
+        *last_export = Some(LastDemoExport {
+            payload,
+            output_path,
+            options,
+            size_bytes: report.verification.size_bytes,
+        });
+    }
+    Ok(report)
+}
+
+pub fn stop_active_watch(watch: &ExportWatchManager) {
+    if let Some(active) = watch
+        .active
+        .lock()
+        .ok()
+        .and_then(|mut active| active.take())
+    {
+        active.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+fn export_watch_status(watch: &ExportWatchManager) -> ExportWatchStatus {
+    let active = watch.active.lock().ok();
+    let active = active.as_ref().and_then(|active| active.as_ref());
+    ExportWatchStatus {
+        watching: active.is_some(),
+        source_path: active.map(|active| active.source_path.clone()),
+        output_path: active.map(|active| active.output_path.clone()),
+        export_count: active.map_or(0, |active| active.export_count.load(Ordering::Relaxed)),
+    }
+}
+
+fn file_fingerprint(path: &Path) -> Option<(u64, SystemTime)> {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.len(), metadata.modified().ok()?))
+}
+
+/// Stores a re-export back as the last export so a later watch starts from it, unless another
+/// export has replaced it in the meantime.
+fn remember_rerun(watch: &ExportWatchManager, job: &LastDemoExport) {
+    if let Ok(mut last_export) = watch.last_export.lock() {
+        if last_export
+            .as_ref()
+            .is_some_and(|last| last.output_path == job.output_path)
+        {
+            *last_export = Some(job.clone());
+        }
+    }
+}
+
+/// Re-exports with the new .riv bytes; everything else in the payload, including the control snapshot, is kept.
+pub fn rerun_export(job: &mut LastDemoExport, source_path: &str) -> ExportWatchEvent {
+    let source_previous_bytes = decoded_base64_len(&job.payload.animation_base64);
+    let result = fs::read(source_path)
+        .map_err(|error| format!("Failed to read {source_path}: {error}"))
+        .and_then(|bytes| {
+            job.payload.animation_base64 = STANDARD.encode(&bytes);
+            let path = prepare_output_path(&job.output_path)?;
+            write_demo_bundle(&job.payload, &path, &job.options)
+        });
+
+    let mut event = ExportWatchEvent {
+        source_path: source_path.to_string(),
+        output_path: job.output_path.clone(),
+        ok: result.is_ok(),
+        error: None,
+        report: None,
+        size_diff: None,
+    };
+    match result {
+        Ok(report) if !report.verification.passed => {
+            event.ok = false;
+            event.output_path = report.path.clone();
+            event.error = Some(describe_verification_failure(&report.verification));
+            event.report = Some(report);
+        }
+        Ok(report) => {
+            let current_bytes = report.verification.size_bytes;
+            let source_current_bytes = decoded_base64_len(&job.payload.animation_base64);
+            event.size_diff = Some(ExportSizeDiff {
+                previous_bytes: job.size_bytes,
+                current_bytes,
+                delta_bytes: current_bytes as i64 - job.size_bytes as i64,
+                size_budget_bytes: report.verification.size_budget_bytes,
+                source_previous_bytes,
+                source_current_bytes,
+                source_delta_bytes: source_current_bytes as i64 - source_previous_bytes as i64,
+            });
+            job.size_bytes = current_bytes;
+            event.output_path = report.path.clone();
+            event.report = Some(report);
+        }
+        Err(error) => event.error = Some(error),
+    }
+    event
+}
+
+fn decoded_base64_len(encoded: &str) -> u64 {
+    let padding = encoded
+        .bytes()
+        .rev()
+        .take_while(|byte| *byte == b'=')
+        .count();
+    (encoded.len() / 4 * 3).saturating_sub(padding) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decoded_base64_len, remember_rerun, rerun_export};
+    use crate::app::export::fixtures::demo_payload;
+    use crate::app::state::{DemoExportOptions, ExportWatchManager, LastDemoExport};
+    use std::fs;
+
+    #[test]
+    fn rerun_swaps_the_riv_bytes_keeps_the_snapshot_and_diffs_the_sizes() {
+        let dir = std::env::temp_dir().join(format!("rav-watch-{}", std::process::id()));
+        fs::create_dir_all(&dir).expect("temp dir");
+        let source = dir.join("demo.riv");
+        fs::write(&source, [7u8; 300]).expect("riv");
+        let mut payload = demo_payload();
+        payload.control_snapshot =
+            Some(r#"[{"descriptor":{"path":"title","kind":"string"},"value":"kept"}]"#.into());
+        let mut job = LastDemoExport {
+            payload,
+            output_path: dir.join("demo.html").to_string_lossy().to_string(),
+            options: DemoExportOptions::default(),
+            size_bytes: 1000,
+        };
+
+        let event = rerun_export(&mut job, &source.to_string_lossy());
+        let diff = event.size_diff.as_ref().expect("size diff");
+        let html = fs::read_to_string(dir.join("demo.html")).expect("html");
+
+        assert!(event.ok, "{:?}", event.error);
+        assert_eq!(diff.source_previous_bytes, 3);
+        assert_eq!(diff.source_current_bytes, 300);
+        assert_eq!(diff.previous_bytes, 1000);
+        assert_eq!(diff.delta_bytes, diff.current_bytes as i64 - 1000);
+        assert_eq!(job.size_bytes, diff.current_bytes);
+        assert!(html.contains("kept"));
+
+        let missing = rerun_export(&mut job, &dir.join("gone.riv").to_string_lossy());
+        assert!(!missing.ok);
+        assert!(missing.error.expect("error").starts_with("Failed to read"));
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn reruns_report_the_hashed_path_and_only_replace_their_own_export() {
+        let dir = std::env::temp_dir().join(format!("rav-watch-hash-{}", std::process::id()));
+        fs::create_dir_all(&dir).expect("temp dir");
+        let source = dir.join("demo.riv");
+        fs::write(&source, [7u8; 300]).expect("riv");
+        let export = |name: &str| LastDemoExport {
+            payload: demo_payload(),
+            output_path: dir.join(name).to_string_lossy().to_string(),
+            options: DemoExportOptions {
+                content_hash: true,
+                ..DemoExportOptions::default()
+            },
+            size_bytes: 1000,
+        };
+        let watch = ExportWatchManager::default();
+        *watch.last_export.lock().expect("lock") = Some(export("demo.html"));
+        let mut job = export("demo.html");
+
+        let event = rerun_export(&mut job, &source.to_string_lossy());
+        assert!(event.ok, "{:?}", event.error);
+        assert_ne!(event.output_path, job.output_path);
+        assert!(std::path::Path::new(&event.output_path).exists());
+        remember_rerun(&watch, &job);
+        let remembered = watch
+            .last_export
+            .lock()
+            .expect("lock")
+            .clone()
+            .expect("job");
+        assert_eq!(remembered.size_bytes, job.size_bytes);
+        assert_eq!(remembered.payload.animation_base64.len(), 400);
+
+        *watch.last_export.lock().expect("lock") = Some(export("other.html"));
+        remember_rerun(&watch, &job);
+        let remembered = watch
+            .last_export
+            .lock()
+            .expect("lock")
+            .clone()
+            .expect("job");
+        assert!(remembered.output_path.ends_with("other.html"));
+        assert_eq!(remembered.size_bytes, 1000);
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn decoded_length_accounts_for_padding() {
+        assert_eq!(decoded_base64_len("AQID"), 3);
+        assert_eq!(decoded_base64_len("AQI="), 2);
+        assert_eq!(decoded_base64_len("AQ=="), 1);
+        assert_eq!(decoded_base64_len(""), 0);
+    }
+}
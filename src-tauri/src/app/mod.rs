@@ -1,5 +1,5 @@
 pub mod constants;
-pub mod demo_bundle;
+pub mod export;
 pub mod mcp;
 pub mod node_runtime;
 pub mod state;
@@ -1,10 +1,11 @@
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 use std::process::Child;
-use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicU64};
+use std::sync::{Arc, Mutex};
 use tauri_plugin_updater::Update;
 
-#[derive(Deserialize)]
+#[derive(Clone, Default, Deserialize)]
 pub struct DemoBundlePayload {
     pub file_name: String,
     pub animation_base64: String,
@@ -16,8 +17,12 @@ pub struct DemoBundlePayload {
     pub layout_fit: String,
     pub state_machines: Vec<String>,
     #[serde(default)]
+    pub state_machine_hierarchy: Option<String>,
+    #[serde(default)]
     pub animations: Vec<String>,
     pub artboard_name: Option<String>,
+    #[serde(default)]
+    pub artboards: Vec<DemoArtboardConfig>,
     pub canvas_color: Option<String>,
     #[serde(default)]
     pub canvas_sizing: Option<String>,
@@ -37,12 +42,168 @@ pub struct DemoBundlePayload {
     pub instantiation_source_mode: String,
     pub layout_state: Option<String>,
     #[serde(default)]
+    pub timeline: Option<DemoTimeline>,
+    #[serde(default)]
     pub view_model_instance_name: Option<String>,
     pub vm_hierarchy: Option<String>,
 }
 
+#[derive(Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct DemoArtboardConfig {
+    pub artboard_name: String,
+    pub state_machines: Vec<String>,
+    pub animations: Vec<String>,
+    pub layout_fit: Option<String>,
+    pub layout_alignment: Option<String>,
+    pub control_snapshot: Option<String>,
+    pub control_selection_keys: Option<String>,
+    pub view_model_instance_name: Option<String>,
+}
+
+#[derive(Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct DemoTimeline {
+    #[serde(rename = "loop")]
+    pub loop_playback: bool,
+    pub autoplay: Option<bool>,
+    pub duration_ms: Option<u64>,
+    pub steps: Vec<DemoTimelineStep>,
+}
+
+#[derive(Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct DemoTimelineStep {
+    pub at_ms: u64,
+    pub kind: String,
+    pub path: Option<String>,
+    pub state_machine: Option<String>,
+    pub input: Option<String>,
+    pub value: serde_json::Value,
+}
+
+#[derive(Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct DemoExportOptions {
+    pub embed: bool,
+    pub embed_base_url: Option<String>,
+    pub content_hash: bool,
+    pub interface_manifest: bool,
+    pub max_size_bytes: Option<u64>,
+    pub csp: bool,
+}
+
+/// Saved demo export settings; unset fields leave the live session's values alone.
+#[derive(Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ExportPreset {
+    pub name: String,
+    pub canvas_color: Option<String>,
+    pub canvas_transparent: Option<bool>,
+    pub canvas_sizing: Option<serde_json::Value>,
+    pub package_source: Option<String>,
+    pub control_selection_keys: Option<Vec<String>>,
+    pub output_dir: Option<String>,
+    pub options: Option<DemoExportOptions>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+pub struct WebComponentOptions {
+    pub tag_name: Option<String>,
+    pub riv_url: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DemoVerificationCheck {
+    pub name: String,
+    pub passed: bool,
+    pub detail: Option<String>,
+}
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DemoVerificationReport {
+    pub passed: bool,
+    pub size_bytes: u64,
+    pub size_budget_bytes: u64,
+    pub checks: Vec<DemoVerificationCheck>,
+}
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DemoExportReport {
+    pub path: String,
+    pub verification: DemoVerificationReport,
+    pub content_security_policy: Option<String>,
+    pub warnings: Vec<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NativeInstantiationSnippet {
+    pub platform: String,
+    pub language: String,
+    pub code: String,
+}
+
 pub struct OpenedFiles(pub Mutex<VecDeque<String>>);
 
+/// The most recent demo export to a path, replayed by watch mode.
+#[derive(Clone)]
+pub struct LastDemoExport {
+    pub payload: DemoBundlePayload,
+    pub output_path: String,
+    pub options: DemoExportOptions,
+    pub size_bytes: u64,
+}
+
+pub struct ActiveExportWatch {
+    pub source_path: String,
+    pub output_path: String,
+    pub stop: Arc<AtomicBool>,
+    pub export_count: Arc<AtomicU64>,
+}
+
+#[derive(Default)]
+pub struct ExportWatchManager {
+    pub last_export: Mutex<Option<LastDemoExport>>,
+    pub active: Mutex<Option<ActiveExportWatch>>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportWatchStatus {
+    pub watching: bool,
+    pub source_path: Option<String>,
+    pub output_path: Option<String>,
+    pub export_count: u64,
+}
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportSizeDiff {
+    pub previous_bytes: u64,
+    pub current_bytes: u64,
+    pub delta_bytes: i64,
+    pub size_budget_bytes: u64,
+    pub source_previous_bytes: u64,
+    pub source_current_bytes: u64,
+    pub source_delta_bytes: i64,
+}
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportWatchEvent {
+    pub source_path: String,
+    pub output_path: String,
+    pub ok: bool,
+    pub error: Option<String>,
+    pub report: Option<DemoExportReport>,
+    pub size_diff: Option<ExportSizeDiff>,
+}
+
 pub struct McpBridgeManager {
     pub child: Mutex<Option<Child>>,
     pub port: Mutex<u16>,
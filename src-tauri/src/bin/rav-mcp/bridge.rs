@@ -2,51 +2,152 @@ use anyhow::{anyhow, Result};
 use serde_json::{json, Value};
 use std::collections::HashMap;
 use std::sync::Arc;
-use std::time::Duration;
-use tokio::sync::{mpsc, oneshot, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::{broadcast, mpsc, oneshot, watch, Mutex};
 use tokio::time::timeout;
 use uuid::Uuid;
 
-use crate::support::constants::APP_CONNECTION_GRACE_MS;
-use crate::websocket::BridgePeerRole;
+use crate::recording::Recorder;
+use crate::support::constants::{APP_CONNECTION_GRACE_MS, BRIDGE_EVENT_CAPACITY};
+use crate::tool_registry::{parse_tool_catalog, tools_list};
+
+#[path = "bridge_host.rs"]
+mod host;
+
+pub type ProgressCallback = Arc<dyn Fn(&Value) + Send + Sync>;
 
 #[derive(Default)]
 struct BridgeState {
     sender: Option<mpsc::UnboundedSender<String>>,
     pending: HashMap<String, oneshot::Sender<Result<Value, String>>>,
+    progress: HashMap<String, ProgressCallback>,
     active_connection_id: u64,
     next_connection_id: u64,
     app_sender: Option<mpsc::UnboundedSender<String>>,
     app_connection_id: Option<u64>,
     client_senders: HashMap<u64, mpsc::UnboundedSender<String>>,
     pending_client_requests: HashMap<String, u64>,
+    tool_catalog: Option<Value>,
 }
 
 #[derive(Clone)]
 pub struct Bridge {
     inner: Arc<Mutex<BridgeState>>,
     command_timeout: Duration,
+    events: broadcast::Sender<Value>,
+    recorder: Option<Recorder>,
+}
+
+/// Optional per-command hooks; the default behaves like `send_command`.
+#[derive(Default)]
+pub struct CommandContext {
+    pub timeout: Option<Duration>,
+    pub cancelled: Option<watch::Receiver<bool>>,
+    pub on_progress: Option<ProgressCallback>,
+}
+
+pub const RESOURCES_UPDATED_EVENT: &str = "resources-updated";
+/// App -> server: `{ bridgeEvent, id, progress, total?, message? }` for a running command.
+pub const PROGRESS_EVENT: &str = "progress";
+/// Server -> app: `{ bridgeEvent, id }` asking the app to abandon a command.
+pub const CANCEL_EVENT: &str = "cancel";
+/// Host -> clients: `{ bridgeEvent, tools }` with the catalog the app sent in its handshake.
+/// Local subscribers get it without `tools` when the served list changes.
+pub const TOOL_CATALOG_EVENT: &str = "tool-catalog";
+
+fn bridge_event(message: &Value) -> Option<&str> {
+    message.get("bridgeEvent").and_then(Value::as_str)
+}
+
+/// Messages the app pushes without a request id, such as `resources-updated`.
+pub fn is_app_event(message: &Value) -> bool {
+    message.get("id").is_none() && bridge_event(message) == Some(RESOURCES_UPDATED_EVENT)
 }
 
 impl Bridge {
     pub fn new(command_timeout: Duration) -> Self {
+        let (events, _) = broadcast::channel(BRIDGE_EVENT_CAPACITY);
         Self {
             inner: Arc::new(Mutex::new(BridgeState::default())),
             command_timeout,
+            events,
+            recorder: None,
+        }
+    }
+
+    /// Logs every command sent through this bridge, and the requests that led to them.
+    pub fn with_recorder(mut self, recorder: Recorder) -> Self {
+        self.recorder = Some(recorder);
+        self
+    }
+
+    pub fn recorder(&self) -> Option<&Recorder> {
+        self.recorder.as_ref()
+    }
+
+    /// Receives app events seen by this process, whether it hosts the bridge or relays through it.
+    pub fn subscribe_events(&self) -> broadcast::Receiver<Value> {
+        self.events.subscribe()
+    }
+
+    /// The app-advertised tool catalog, or the built-in list until an app has sent one.
+    pub async fn tool_catalog(&self) -> Value {
+        let catalog = self.inner.lock().await.tool_catalog.clone();
+        catalog.unwrap_or_else(tools_list)
+    }
+
+    /// Serves a new catalog, or the built-in list for `None`, and tells local subscribers when
+    /// the served list differs from the previous one. Returns whether it did.
+    async fn adopt_tool_catalog(&self, tools: Option<Value>) -> bool {
+        let changed = {
+            let mut state = self.inner.lock().await;
+            let previous = std::mem::replace(&mut state.tool_catalog, tools.clone());
+            previous.unwrap_or_else(tools_list) != tools.unwrap_or_else(tools_list)
+        };
+        if changed {
+            let _ = self
+                .events
+                .send(json!({ "bridgeEvent": TOOL_CATALOG_EVENT }));
         }
+        changed
     }
 
     pub async fn send_command(&self, command: &str, params: Value) -> Result<Value> {
-        self.send_command_with_timeout(command, params, self.command_timeout)
+        self.send_command_with_context(command, params, CommandContext::default())
             .await
     }
 
-    pub async fn send_command_with_timeout(
+    pub async fn send_command_with_context(
+        &self,
+        command: &str,
+        params: Value,
+        context: CommandContext,
+    ) -> Result<Value> {
+        let Some(recorder) = &self.recorder else {
+            return self.dispatch_command(command, params, context).await;
+        };
+        let (offset_ms, started) = (recorder.offset_ms(), Instant::now());
+        let outcome = self
+            .dispatch_command(command, params.clone(), context)
+            .await;
+        recorder.record_command(command, &params, &outcome, offset_ms, started.elapsed());
+        outcome
+    }
+
+    async fn dispatch_command(
         &self,
         command: &str,
         params: Value,
-        command_timeout: Duration,
+        context: CommandContext,
     ) -> Result<Value> {
+        let command_timeout = context.timeout.unwrap_or(self.command_timeout);
+        let mut cancelled = context.cancelled;
+        if cancelled
+            .as_ref()
+            .is_some_and(|cancelled| *cancelled.borrow())
+        {
+            return Err(anyhow!("Request cancelled"));
+        }
         let request_id = Uuid::new_v4().to_string();
         let (tx, rx) = oneshot::channel();
         let payload = json!({
@@ -72,9 +173,13 @@ impl Bridge {
                             .take()
                             .expect("pending sender should exist until dispatch"),
                     );
+                    if let Some(on_progress) = context.on_progress.clone() {
+                        state.progress.insert(request_id.clone(), on_progress);
+                    }
                     let send_result = sender.send(payload.to_string());
                     if send_result.is_err() {
                         pending_tx = state.pending.remove(&request_id);
+                        state.progress.remove(&request_id);
                     }
                     send_result
                 };
@@ -90,10 +195,22 @@ impl Bridge {
                 ));
             }
 
-            tokio::time::sleep(Duration::from_millis(50)).await;
+            tokio::select! {
+                _ = tokio::time::sleep(Duration::from_millis(50)) => {}
+                _ = wait_for_cancel(cancelled.as_mut()) => return Err(anyhow!("Request cancelled")),
+            }
         }
 
-        match timeout(command_timeout, rx).await {
+        let outcome = tokio::select! {
+            outcome = timeout(command_timeout, rx) => outcome,
+            _ = wait_for_cancel(cancelled.as_mut()) => {
+                self.cancel_command(&request_id).await;
+                return Err(anyhow!("Request cancelled"));
+            }
+        };
+        self.inner.lock().await.progress.remove(&request_id);
+
+        match outcome {
             Ok(Ok(Ok(result))) => Ok(result),
             Ok(Ok(Err(message))) => Err(anyhow!(message)),
             Ok(Err(_)) => Err(anyhow!("RAV request channel closed unexpectedly")),
@@ -109,6 +226,22 @@ impl Bridge {
         }
     }
 
+    /// Rejects a pending command and tells the app to abandon it.
+    async fn cancel_command(&self, request_id: &str) {
+        let sender = {
+            let mut state = self.inner.lock().await;
+            state.progress.remove(request_id);
+            if let Some(pending) = state.pending.remove(request_id) {
+                let _ = pending.send(Err("Request cancelled".into()));
+            }
+            state.sender.clone()
+        };
+        if let Some(sender) = sender {
+            let _ =
+                sender.send(json!({ "bridgeEvent": CANCEL_EVENT, "id": request_id }).to_string());
+        }
+    }
+
     pub async fn register_connection(&self, sender: mpsc::UnboundedSender<String>) -> u64 {
         let mut state = self.inner.lock().await;
         reject_all_pending(&mut state, "RAV reconnected".into());
@@ -119,9 +252,26 @@ impl Bridge {
     }
 
     pub async fn handle_incoming_message(&self, message: Value) {
+        if bridge_event(&message) == Some(TOOL_CATALOG_EVENT) {
+            // The host sends `null` when its app falls back to the built-in list.
+            let tools = message.get("tools").and_then(parse_tool_catalog);
+            self.adopt_tool_catalog(tools).await;
+            return;
+        }
+        if is_app_event(&message) {
+            let _ = self.events.send(message);
+            return;
+        }
         let Some(request_id) = message.get("id").and_then(Value::as_str).map(str::to_owned) else {
             return;
         };
+        if bridge_event(&message) == Some(PROGRESS_EVENT) {
+            let on_progress = self.inner.lock().await.progress.get(&request_id).cloned();
+            if let Some(on_progress) = on_progress {
+                on_progress(&message);
+            }
+            return;
+        }
 
         let pending = {
             let mut state = self.inner.lock().await;
@@ -154,207 +304,25 @@ impl Bridge {
         state.sender = None;
         reject_all_pending(&mut state, message);
     }
-
-    pub async fn register_bridge_peer(
-        &self,
-        role: BridgePeerRole,
-        sender: mpsc::UnboundedSender<String>,
-    ) -> u64 {
-        let mut outgoing = Vec::new();
-        let connection_id = {
-            let mut state = self.inner.lock().await;
-            state.next_connection_id += 1;
-            let connection_id = state.next_connection_id;
-            match role {
-                BridgePeerRole::App => {
-                    state.app_connection_id = Some(connection_id);
-                    state.app_sender = Some(sender);
-                    if let Some(app_sender) = state.app_sender.clone() {
-                        outgoing.push((app_sender, build_client_presence_payload(&state)));
-                    }
-                    let drained_pending: Vec<(String, u64)> =
-                        state.pending_client_requests.drain().collect();
-                    for (request_id, client_id) in drained_pending {
-                        if let Some(client_sender) = state.client_senders.get(&client_id).cloned() {
-                            outgoing.push((
-                                client_sender,
-                                json!({ "id": request_id, "error": "RAV reconnected" }).to_string(),
-                            ));
-                        }
-                    }
-                }
-                BridgePeerRole::Client => {
-                    state.client_senders.insert(connection_id, sender);
-                    if let Some(app_sender) = state.app_sender.clone() {
-                        outgoing.push((app_sender, build_client_presence_payload(&state)));
-                    }
-                }
-            }
-            connection_id
-        };
-
-        for (sender, payload) in outgoing {
-            let _ = sender.send(payload);
-        }
-
-        connection_id
-    }
-
-    pub async fn relay_client_request(&self, client_id: u64, message: Value) -> Result<()> {
-        let request_id = message
-            .get("id")
-            .and_then(Value::as_str)
-            .map(str::to_owned)
-            .ok_or_else(|| anyhow!("Client request is missing an id"))?;
-        if message.get("command").and_then(Value::as_str).is_none() {
-            return Err(anyhow!("Client request is missing a command"));
-        }
-
-        let deadline = tokio::time::Instant::now() + Duration::from_millis(APP_CONNECTION_GRACE_MS);
-        let sender = loop {
-            let maybe_sender = {
-                let mut state = self.inner.lock().await;
-                let sender = state.app_sender.clone();
-                if sender.is_some() {
-                    state
-                        .pending_client_requests
-                        .insert(request_id.clone(), client_id);
-                }
-                sender
-            };
-            if let Some(sender) = maybe_sender {
-                break sender;
-            }
-            if tokio::time::Instant::now() >= deadline {
-                return Err(anyhow!(
-                    "RAV is not connected. Make sure the app is running and MCP is enabled."
-                ));
-            }
-            tokio::time::sleep(Duration::from_millis(50)).await;
-        };
-
-        sender
-            .send(message.to_string())
-            .map_err(|_| anyhow!("RAV bridge is unavailable"))?;
-        Ok(())
-    }
-
-    pub async fn relay_app_response(&self, message: Value) {
-        let Some(request_id) = message.get("id").and_then(Value::as_str).map(str::to_owned) else {
-            return;
-        };
-
-        let client_sender = {
-            let mut state = self.inner.lock().await;
-            let Some(client_id) = state.pending_client_requests.remove(&request_id) else {
-                return;
-            };
-            state.client_senders.get(&client_id).cloned()
-        };
-
-        if let Some(sender) = client_sender {
-            let _ = sender.send(message.to_string());
-        }
-    }
-
-    pub async fn handle_bridge_disconnect(
-        &self,
-        connection_id: u64,
-        role: BridgePeerRole,
-        message: String,
-    ) {
-        let mut outgoing = Vec::new();
-        {
-            let mut state = self.inner.lock().await;
-            match role {
-                BridgePeerRole::App => {
-                    if state.app_connection_id != Some(connection_id) {
-                        return;
-                    }
-                    state.app_connection_id = None;
-                    state.app_sender = None;
-                    let drained_pending: Vec<(String, u64)> =
-                        state.pending_client_requests.drain().collect();
-                    for (request_id, client_id) in drained_pending {
-                        if let Some(client_sender) = state.client_senders.get(&client_id).cloned() {
-                            outgoing.push((
-                                client_sender,
-                                json!({ "id": request_id, "error": message.clone() }).to_string(),
-                            ));
-                        }
-                    }
-                }
-                BridgePeerRole::Client => {
-                    state.client_senders.remove(&connection_id);
-                    state
-                        .pending_client_requests
-                        .retain(|_, client_id| *client_id != connection_id);
-                    if let Some(app_sender) = state.app_sender.clone() {
-                        outgoing.push((app_sender, build_client_presence_payload(&state)));
-                    }
-                }
-            }
-        }
-
-        for (sender, payload) in outgoing {
-            let _ = sender.send(payload);
-        }
-    }
 }
 
 fn reject_all_pending(state: &mut BridgeState, message: String) {
+    state.progress.clear();
     for (_, pending) in state.pending.drain() {
         let _ = pending.send(Err(message.clone()));
     }
 }
 
-fn build_client_presence_payload(state: &BridgeState) -> String {
-    json!({
-        "bridgeEvent": "mcp-client-state",
-        "clientCount": state.client_senders.len(),
-        "connected": !state.client_senders.is_empty(),
-    })
-    .to_string()
+/// Resolves when the request is cancelled; never resolves for untracked requests.
+async fn wait_for_cancel(cancelled: Option<&mut watch::Receiver<bool>>) {
+    if let Some(cancelled) = cancelled {
+        if cancelled.wait_for(|cancelled| *cancelled).await.is_ok() {
+            return;
+        }
+    }
+    std::future::pending().await
 }
 
 #[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[tokio::test(flavor = "current_thread")]
-    async fn send_command_waits_for_bridge_connection_before_failing() {
-        let bridge = Bridge::new(Duration::from_millis(500));
-        let bridge_for_command = bridge.clone();
-        let command_task = tokio::spawn(async move {
-            bridge_for_command
-                .send_command("rav_status", Value::Null)
-                .await
-        });
-
-        tokio::time::sleep(Duration::from_millis(80)).await;
-
-        let (tx, mut rx) = mpsc::unbounded_channel::<String>();
-        bridge.register_connection(tx).await;
-
-        let outbound = rx.recv().await.expect("bridge request");
-        let outbound_value: Value = serde_json::from_str(&outbound).expect("valid command json");
-        let request_id = outbound_value
-            .get("id")
-            .and_then(Value::as_str)
-            .expect("request id")
-            .to_string();
-
-        bridge
-            .handle_incoming_message(json!({
-                "id": request_id,
-                "result": { "ok": true }
-            }))
-            .await;
-
-        let result = command_task
-            .await
-            .expect("task result")
-            .expect("command result");
-        assert_eq!(result.get("ok"), Some(&Value::Bool(true)));
-    }
-}
+#[path = "bridge_tests.rs"]
+mod tests;
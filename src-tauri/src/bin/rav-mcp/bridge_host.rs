@@ -0,0 +1,243 @@
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+use super::{
+    bridge_event, is_app_event, Bridge, BridgeState, CANCEL_EVENT, PROGRESS_EVENT,
+    TOOL_CATALOG_EVENT,
+};
+use crate::support::constants::APP_CONNECTION_GRACE_MS;
+use crate::tool_registry::parse_tool_catalog;
+use crate::transport::websocket::BridgePeerRole;
+
+/// Routing for the process that hosts the WebSocket bridge: app replies and events go back to
+/// the bridge client that asked, client requests go to the app.
+impl Bridge {
+    pub async fn register_bridge_peer(
+        &self,
+        role: BridgePeerRole,
+        sender: mpsc::UnboundedSender<String>,
+    ) -> u64 {
+        let mut outgoing = Vec::new();
+        let connection_id = {
+            let mut state = self.inner.lock().await;
+            state.next_connection_id += 1;
+            let connection_id = state.next_connection_id;
+            match role {
+                BridgePeerRole::App => {
+                    state.app_connection_id = Some(connection_id);
+                    state.app_sender = Some(sender);
+                    if let Some(app_sender) = state.app_sender.clone() {
+                        outgoing.push((app_sender, build_client_presence_payload(&state)));
+                    }
+                    let drained_pending: Vec<(String, u64)> =
+                        state.pending_client_requests.drain().collect();
+                    for (request_id, client_id) in drained_pending {
+                        if let Some(client_sender) = state.client_senders.get(&client_id).cloned() {
+                            outgoing.push((
+                                client_sender,
+                                json!({ "id": request_id, "error": "RAV reconnected" }).to_string(),
+                            ));
+                        }
+                    }
+                }
+                BridgePeerRole::Client => {
+                    if let Some(tools) = state.tool_catalog.clone() {
+                        outgoing.push((sender.clone(), tool_catalog_payload(tools)));
+                    }
+                    state.client_senders.insert(connection_id, sender);
+                    if let Some(app_sender) = state.app_sender.clone() {
+                        outgoing.push((app_sender, build_client_presence_payload(&state)));
+                    }
+                }
+            }
+            connection_id
+        };
+
+        for (sender, payload) in outgoing {
+            let _ = sender.send(payload);
+        }
+
+        connection_id
+    }
+
+    /// Adopts the catalog from an app handshake. Apps that predate catalogs send none, and the
+    /// built-in list is served again.
+    pub async fn publish_tool_catalog(&self, hello: &Value) {
+        self.serve_tool_catalog(hello.get("tools").and_then(parse_tool_catalog))
+            .await;
+    }
+
+    /// Serves `tools`, or the built-in list for `None`, and passes a change on to every bridge
+    /// client so relaying sidecars serve the same list.
+    async fn serve_tool_catalog(&self, tools: Option<Value>) {
+        if !self.adopt_tool_catalog(tools.clone()).await {
+            return;
+        }
+        let client_senders: Vec<_> = {
+            let state = self.inner.lock().await;
+            state.client_senders.values().cloned().collect()
+        };
+        let payload = tool_catalog_payload(tools.unwrap_or(Value::Null));
+        for sender in client_senders {
+            let _ = sender.send(payload.clone());
+        }
+    }
+
+    pub async fn relay_client_request(&self, client_id: u64, message: Value) -> Result<()> {
+        let request_id = message
+            .get("id")
+            .and_then(Value::as_str)
+            .map(str::to_owned)
+            .ok_or_else(|| anyhow!("Client request is missing an id"))?;
+        if bridge_event(&message) == Some(CANCEL_EVENT) {
+            self.relay_client_cancel(client_id, &request_id, message)
+                .await;
+            return Ok(());
+        }
+        if message.get("command").and_then(Value::as_str).is_none() {
+            return Err(anyhow!("Client request is missing a command"));
+        }
+
+        let deadline = tokio::time::Instant::now() + Duration::from_millis(APP_CONNECTION_GRACE_MS);
+        let sender = loop {
+            let maybe_sender = {
+                let mut state = self.inner.lock().await;
+                let sender = state.app_sender.clone();
+                if sender.is_some() {
+                    state
+                        .pending_client_requests
+                        .insert(request_id.clone(), client_id);
+                }
+                sender
+            };
+            if let Some(sender) = maybe_sender {
+                break sender;
+            }
+            if tokio::time::Instant::now() >= deadline {
+                return Err(anyhow!(
+                    "RAV is not connected. Make sure the app is running and MCP is enabled."
+                ));
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        };
+
+        sender
+            .send(message.to_string())
+            .map_err(|_| anyhow!("RAV bridge is unavailable"))?;
+        Ok(())
+    }
+
+    /// Forwards a client's cancel to the app and stops routing the eventual reply.
+    async fn relay_client_cancel(&self, client_id: u64, request_id: &str, message: Value) {
+        let app_sender = {
+            let mut state = self.inner.lock().await;
+            if state.pending_client_requests.get(request_id) != Some(&client_id) {
+                return;
+            }
+            state.pending_client_requests.remove(request_id);
+            state.app_sender.clone()
+        };
+        if let Some(sender) = app_sender {
+            let _ = sender.send(message.to_string());
+        }
+    }
+
+    pub async fn relay_app_response(&self, message: Value) {
+        if is_app_event(&message) {
+            let client_senders: Vec<_> = {
+                let state = self.inner.lock().await;
+                state.client_senders.values().cloned().collect()
+            };
+            let payload = message.to_string();
+            for sender in client_senders {
+                let _ = sender.send(payload.clone());
+            }
+            let _ = self.events.send(message);
+            return;
+        }
+        let Some(request_id) = message.get("id").and_then(Value::as_str).map(str::to_owned) else {
+            return;
+        };
+
+        let client_sender = {
+            let mut state = self.inner.lock().await;
+            // Progress arrives before the reply, so it must not end the routing entry.
+            let client_id = if bridge_event(&message) == Some(PROGRESS_EVENT) {
+                state.pending_client_requests.get(&request_id).copied()
+            } else {
+                state.pending_client_requests.remove(&request_id)
+            };
+            let Some(client_id) = client_id else {
+                return;
+            };
+            state.client_senders.get(&client_id).cloned()
+        };
+
+        if let Some(sender) = client_sender {
+            let _ = sender.send(message.to_string());
+        }
+    }
+
+    pub async fn handle_bridge_disconnect(
+        &self,
+        connection_id: u64,
+        role: BridgePeerRole,
+        message: String,
+    ) {
+        let mut outgoing = Vec::new();
+        {
+            let mut state = self.inner.lock().await;
+            match role {
+                BridgePeerRole::App => {
+                    if state.app_connection_id != Some(connection_id) {
+                        return;
+                    }
+                    state.app_connection_id = None;
+                    state.app_sender = None;
+                    let drained_pending: Vec<(String, u64)> =
+                        state.pending_client_requests.drain().collect();
+                    for (request_id, client_id) in drained_pending {
+                        if let Some(client_sender) = state.client_senders.get(&client_id).cloned() {
+                            outgoing.push((
+                                client_sender,
+                                json!({ "id": request_id, "error": message.clone() }).to_string(),
+                            ));
+                        }
+                    }
+                }
+                BridgePeerRole::Client => {
+                    state.client_senders.remove(&connection_id);
+                    state
+                        .pending_client_requests
+                        .retain(|_, client_id| *client_id != connection_id);
+                    if let Some(app_sender) = state.app_sender.clone() {
+                        outgoing.push((app_sender, build_client_presence_payload(&state)));
+                    }
+                }
+            }
+        }
+
+        for (sender, payload) in outgoing {
+            let _ = sender.send(payload);
+        }
+        if role == BridgePeerRole::App {
+            // The catalog belonged to that app; a later one may not advertise the same tools.
+            self.serve_tool_catalog(None).await;
+        }
+    }
+}
+
+fn build_client_presence_payload(state: &BridgeState) -> String {
+    json!({
+        "bridgeEvent": "mcp-client-state",
+        "clientCount": state.client_senders.len(),
+        "connected": !state.client_senders.is_empty(),
+    })
+    .to_string()
+}
+
+fn tool_catalog_payload(tools: Value) -> String {
+    json!({ "bridgeEvent": TOOL_CATALOG_EVENT, "tools": tools }).to_string()
+}
@@ -0,0 +1,222 @@
+use super::*;
+use crate::transport::websocket::BridgePeerRole;
+
+#[tokio::test(flavor = "current_thread")]
+async fn send_command_waits_for_bridge_connection_before_failing() {
+    let bridge = Bridge::new(Duration::from_millis(500));
+    let bridge_for_command = bridge.clone();
+    let command_task = tokio::spawn(async move {
+        bridge_for_command
+            .send_command("rav_status", Value::Null)
+            .await
+    });
+
+    tokio::time::sleep(Duration::from_millis(80)).await;
+
+    let (tx, mut rx) = mpsc::unbounded_channel::<String>();
+    bridge.register_connection(tx).await;
+
+    let outbound = rx.recv().await.expect("bridge request");
+    let outbound_value: Value = serde_json::from_str(&outbound).expect("valid command json");
+    let request_id = outbound_value
+        .get("id")
+        .and_then(Value::as_str)
+        .expect("request id")
+        .to_string();
+
+    bridge
+        .handle_incoming_message(json!({
+            "id": request_id,
+            "result": { "ok": true }
+        }))
+        .await;
+
+    let result = command_task
+        .await
+        .expect("task result")
+        .expect("command result");
+    assert_eq!(result.get("ok"), Some(&Value::Bool(true)));
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn app_resource_events_reach_bridge_clients_and_local_subscribers() {
+    let bridge = Bridge::new(Duration::from_millis(500));
+    let mut local_events = bridge.subscribe_events();
+    let (client_tx, mut client_rx) = mpsc::unbounded_channel::<String>();
+    bridge
+        .register_bridge_peer(BridgePeerRole::Client, client_tx)
+        .await;
+
+    let event = json!({
+        "bridgeEvent": RESOURCES_UPDATED_EVENT,
+        "uris": ["rav://current/vm-tree"]
+    });
+    bridge.relay_app_response(event.clone()).await;
+
+    let relayed: Value =
+        serde_json::from_str(&client_rx.recv().await.expect("relayed event")).expect("json");
+    assert_eq!(relayed, event);
+    assert_eq!(local_events.recv().await.expect("local event"), event);
+    assert!(!is_app_event(&json!({ "id": "1", "result": {} })));
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn cancelled_commands_notify_the_app_and_progress_reaches_the_caller() {
+    let bridge = Bridge::new(Duration::from_millis(500));
+    let (tx, mut rx) = mpsc::unbounded_channel::<String>();
+    bridge.register_connection(tx).await;
+    let (cancel_tx, cancel_rx) = watch::channel(false);
+    let (progress_tx, mut progress_rx) = mpsc::unbounded_channel::<Value>();
+    let context = CommandContext {
+        cancelled: Some(cancel_rx),
+        on_progress: Some(Arc::new(move |event: &Value| {
+            let _ = progress_tx.send(event["progress"].clone());
+        })),
+        ..CommandContext::default()
+    };
+    let bridge_for_command = bridge.clone();
+    let command_task = tokio::spawn(async move {
+        bridge_for_command
+            .send_command_with_context("rav_export_demo_visual", json!({}), context)
+            .await
+    });
+
+    let outbound: Value = serde_json::from_str(&rx.recv().await.expect("command")).expect("json");
+    let request_id = outbound["id"].as_str().expect("request id").to_string();
+    bridge
+        .handle_incoming_message(
+            json!({ "bridgeEvent": PROGRESS_EVENT, "id": request_id, "progress": 2 }),
+        )
+        .await;
+    assert_eq!(progress_rx.recv().await, Some(json!(2)));
+
+    cancel_tx.send_replace(true);
+    let error = command_task.await.expect("task").expect_err("cancelled");
+    assert_eq!(error.to_string(), "Request cancelled");
+    let cancel: Value = serde_json::from_str(&rx.recv().await.expect("cancel")).expect("json");
+    assert_eq!(
+        cancel,
+        json!({ "bridgeEvent": CANCEL_EVENT, "id": request_id })
+    );
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn host_routes_progress_and_cancels_between_client_and_app() {
+    let bridge = Bridge::new(Duration::from_millis(500));
+    let (app_tx, mut app_rx) = mpsc::unbounded_channel::<String>();
+    let (client_tx, mut client_rx) = mpsc::unbounded_channel::<String>();
+    bridge
+        .register_bridge_peer(BridgePeerRole::App, app_tx)
+        .await;
+    let client_id = bridge
+        .register_bridge_peer(BridgePeerRole::Client, client_tx)
+        .await;
+    let _presence = app_rx.recv().await;
+    let _presence = app_rx.recv().await;
+
+    bridge
+        .relay_client_request(client_id, json!({ "id": "r1", "command": "rav_open_file" }))
+        .await
+        .expect("relayed request");
+    let _request = app_rx.recv().await;
+
+    let progress = json!({ "bridgeEvent": PROGRESS_EVENT, "id": "r1", "progress": 1 });
+    bridge.relay_app_response(progress.clone()).await;
+    bridge.relay_app_response(progress.clone()).await;
+    for _ in 0..2 {
+        let relayed: Value =
+            serde_json::from_str(&client_rx.recv().await.expect("progress")).expect("json");
+        assert_eq!(relayed, progress);
+    }
+
+    let cancel = json!({ "bridgeEvent": CANCEL_EVENT, "id": "r1" });
+    bridge
+        .relay_client_request(client_id, cancel.clone())
+        .await
+        .expect("relayed cancel");
+    let forwarded: Value =
+        serde_json::from_str(&app_rx.recv().await.expect("cancel")).expect("json");
+    assert_eq!(forwarded, cancel);
+
+    bridge
+        .relay_app_response(json!({ "id": "r1", "result": {} }))
+        .await;
+    assert!(client_rx.try_recv().is_err());
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn app_tool_catalog_reaches_clients_and_announces_changes_once() {
+    let host = Bridge::new(Duration::from_millis(500));
+    let (client_tx, mut client_rx) = mpsc::unbounded_channel::<String>();
+    host.register_bridge_peer(BridgePeerRole::Client, client_tx)
+        .await;
+    let catalog = json!([{ "name": "rav_status", "inputSchema": { "type": "object" } }]);
+
+    host.publish_tool_catalog(&json!({ "bridgeHello": "rav-app" }))
+        .await;
+    host.publish_tool_catalog(&json!({ "bridgeHello": "rav-app", "tools": [{ "name": 1 }] }))
+        .await;
+    assert!(client_rx.try_recv().is_err());
+    host.publish_tool_catalog(&json!({ "bridgeHello": "rav-app", "tools": catalog }))
+        .await;
+    assert_eq!(host.tool_catalog().await, catalog);
+
+    let sidecar = Bridge::new(Duration::from_millis(500));
+    let mut events = sidecar.subscribe_events();
+    assert_eq!(sidecar.tool_catalog().await, tools_list());
+    let relayed: Value =
+        serde_json::from_str(&client_rx.recv().await.expect("catalog")).expect("json");
+    sidecar.handle_incoming_message(relayed.clone()).await;
+    sidecar.handle_incoming_message(relayed).await;
+
+    assert_eq!(sidecar.tool_catalog().await, catalog);
+    assert_eq!(
+        events.recv().await.expect("list changed"),
+        json!({ "bridgeEvent": TOOL_CATALOG_EVENT })
+    );
+    assert!(events.try_recv().is_err());
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn tool_catalog_falls_back_to_the_built_in_list_after_the_app_reconnects() {
+    let host = Bridge::new(Duration::from_millis(500));
+    let mut host_events = host.subscribe_events();
+    let (client_tx, mut client_rx) = mpsc::unbounded_channel::<String>();
+    host.register_bridge_peer(BridgePeerRole::Client, client_tx)
+        .await;
+    let (app_tx, _app_rx) = mpsc::unbounded_channel::<String>();
+    let app_id = host
+        .register_bridge_peer(BridgePeerRole::App, app_tx.clone())
+        .await;
+    let catalog = json!([{ "name": "rav_status", "inputSchema": { "type": "object" } }]);
+    host.publish_tool_catalog(&json!({ "bridgeHello": "rav-app", "tools": catalog }))
+        .await;
+    let sidecar = Bridge::new(Duration::from_millis(500));
+    let mut sidecar_events = sidecar.subscribe_events();
+    let adopted: Value =
+        serde_json::from_str(&client_rx.recv().await.expect("catalog")).expect("json");
+    sidecar.handle_incoming_message(adopted).await;
+    assert_eq!(sidecar.tool_catalog().await, catalog);
+
+    host.handle_bridge_disconnect(app_id, BridgePeerRole::App, "RAV disconnected".into())
+        .await;
+    host.register_bridge_peer(BridgePeerRole::App, app_tx).await;
+    host.publish_tool_catalog(&json!({ "bridgeHello": "rav-app" }))
+        .await;
+
+    assert_eq!(host.tool_catalog().await, tools_list());
+    let reset: Value = serde_json::from_str(&client_rx.recv().await.expect("reset")).expect("json");
+    assert_eq!(reset["tools"], Value::Null);
+    assert!(client_rx.try_recv().is_err());
+    sidecar.handle_incoming_message(reset).await;
+    assert_eq!(sidecar.tool_catalog().await, tools_list());
+    for events in [&mut host_events, &mut sidecar_events] {
+        for _ in 0..2 {
+            assert_eq!(
+                events.recv().await.expect("list changed"),
+                json!({ "bridgeEvent": TOOL_CATALOG_EVENT })
+            );
+        }
+        assert!(events.try_recv().is_err());
+    }
+}
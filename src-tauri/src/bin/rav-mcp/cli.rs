@@ -1,6 +1,8 @@
 use anyhow::{anyhow, Context, Result};
 use std::env;
+use std::path::PathBuf;
 
+use crate::recording::replay::DEFAULT_IGNORED_KEYS;
 use crate::support::constants::{DEFAULT_COMMAND_TIMEOUT_MS, DEFAULT_WS_PORT};
 
 #[derive(Clone, Copy, Eq, PartialEq)]
@@ -8,12 +10,18 @@ pub enum RunMode {
     Combined,
     BridgeOnly,
     StdioOnly,
+    HttpOnly,
+    Replay,
 }
 
 pub struct CliOptions {
     pub mode: RunMode,
     pub ws_port: u16,
+    pub http_port: u16,
     pub command_timeout_ms: u64,
+    pub record_path: Option<PathBuf>,
+    pub replay_path: Option<PathBuf>,
+    pub replay_ignore: Vec<String>,
 }
 
 pub fn parse_cli_options() -> Result<CliOptions> {
@@ -22,16 +30,32 @@ pub fn parse_cli_options() -> Result<CliOptions> {
         .ok()
         .and_then(|value| value.parse::<u16>().ok())
         .unwrap_or(DEFAULT_WS_PORT);
+    let mut http_port = 0;
     let mut command_timeout_ms = env::var("RAV_MCP_TIMEOUT")
         .ok()
         .and_then(|value| value.parse::<u64>().ok())
         .unwrap_or(DEFAULT_COMMAND_TIMEOUT_MS);
+    let mut record_path = None;
+    let mut replay_path = None;
+    let mut replay_ignore: Vec<String> = DEFAULT_IGNORED_KEYS
+        .iter()
+        .map(|key| key.to_string())
+        .collect();
 
     let mut args = env::args().skip(1);
     while let Some(arg) = args.next() {
         match arg.as_str() {
             "--bridge-only" => mode = RunMode::BridgeOnly,
             "--stdio-only" => mode = RunMode::StdioOnly,
+            "--http" => {
+                let Some(value) = args.next() else {
+                    return Err(anyhow!("--http requires a port"));
+                };
+                http_port = value
+                    .parse::<u16>()
+                    .with_context(|| format!("invalid HTTP port value: {value}"))?;
+                mode = RunMode::HttpOnly;
+            }
             "--port" => {
                 let Some(value) = args.next() else {
                     return Err(anyhow!("--port requires a value"));
@@ -48,6 +72,32 @@ pub fn parse_cli_options() -> Result<CliOptions> {
                     .parse::<u64>()
                     .with_context(|| format!("invalid timeout value: {value}"))?;
             }
+            "--record" => {
+                let Some(value) = args.next() else {
+                    return Err(anyhow!("--record requires a file path"));
+                };
+                record_path = Some(PathBuf::from(value));
+            }
+            "--replay" => {
+                let Some(value) = args.next() else {
+                    return Err(anyhow!("--replay requires a file path"));
+                };
+                replay_path = Some(PathBuf::from(value));
+                mode = RunMode::Replay;
+            }
+            "--replay-ignore" => {
+                let Some(value) = args.next() else {
+                    return Err(anyhow!(
+                        "--replay-ignore requires a comma-separated key list"
+                    ));
+                };
+                replay_ignore = value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|key| !key.is_empty())
+                    .map(str::to_string)
+                    .collect();
+            }
             _ => {}
         }
     }
@@ -55,6 +105,10 @@ pub fn parse_cli_options() -> Result<CliOptions> {
     Ok(CliOptions {
         mode,
         ws_port,
+        http_port,
         command_timeout_ms,
+        record_path,
+        replay_path,
+        replay_ignore,
     })
 }
@@ -3,22 +3,31 @@ use std::time::Duration;
 
 mod bridge;
 mod cli;
+mod prompts;
+mod recording;
+mod resources;
 mod rpc;
-mod stdio_transport;
+mod session;
 mod support;
 mod tool_registry;
-mod websocket;
+mod transport;
 
 use bridge::Bridge;
 use cli::{parse_cli_options, RunMode};
-use stdio_transport::run_stdio_server;
-use websocket::{run_websocket_bridge, run_websocket_client_bridge};
+use recording::replay::run_replay;
+use recording::Recorder;
+use transport::http::run_http_server;
+use transport::stdio::run_stdio_server;
+use transport::websocket::{run_websocket_bridge, run_websocket_client_bridge};
 
 #[tokio::main(flavor = "current_thread")]
 async fn main() -> Result<()> {
     let options = parse_cli_options()?;
 
-    let bridge = Bridge::new(Duration::from_millis(options.command_timeout_ms));
+    let mut bridge = Bridge::new(Duration::from_millis(options.command_timeout_ms));
+    if let Some(path) = &options.record_path {
+        bridge = bridge.with_recorder(Recorder::create(path)?);
+    }
     match options.mode {
         RunMode::BridgeOnly => run_websocket_bridge(bridge, options.ws_port).await,
         RunMode::StdioOnly => {
@@ -29,6 +38,23 @@ async fn main() -> Result<()> {
                 result = stdio_server => result,
             }
         }
+        RunMode::HttpOnly => {
+            let websocket_client = run_websocket_client_bridge(bridge.clone(), options.ws_port);
+            let http_server = run_http_server(bridge, options.http_port);
+            tokio::select! {
+                result = websocket_client => result,
+                result = http_server => result,
+            }
+        }
+        RunMode::Replay => {
+            let websocket_client = run_websocket_client_bridge(bridge.clone(), options.ws_port);
+            let path = options.replay_path.unwrap_or_default();
+            let replay = run_replay(bridge, &path, &options.replay_ignore);
+            tokio::select! {
+                result = websocket_client => result,
+                result = replay => result,
+            }
+        }
         RunMode::Combined => {
             let websocket_bridge = run_websocket_bridge(bridge.clone(), options.ws_port);
             let stdio_server = run_stdio_server(bridge);
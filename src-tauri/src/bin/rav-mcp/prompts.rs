@@ -0,0 +1,187 @@
+use serde_json::{json, Value};
+
+use crate::bridge::Bridge;
+use crate::resources::{artboard_state_machines_uri, read_resource, resolve_resource};
+
+pub fn prompts_list() -> Value {
+    json!([
+        {
+            "name": "audit_vm_naming",
+            "title": "Audit ViewModel naming",
+            "description": "Review the loaded file's ViewModel tree for inconsistent, ambiguous or code-unfriendly property names.",
+            "arguments": [
+                { "name": "convention", "description": "Naming convention to check against, e.g. camelCase or snake_case. Defaults to whatever the file mostly uses.", "required": false }
+            ]
+        },
+        {
+            "name": "handoff_snippet",
+            "title": "Handoff snippet for an artboard",
+            "description": "Produce a developer handoff for one artboard: its state machines, ViewModel controls and a working web instantiation snippet.",
+            "arguments": [
+                { "name": "artboard", "description": "Artboard to hand off.", "required": true },
+                { "name": "package_source", "description": "cdn or local. Defaults to cdn.", "required": false }
+            ]
+        },
+        {
+            "name": "reproduce_bug",
+            "title": "Reproduce a reported bug",
+            "description": "Replay the steps of a bug report against the loaded file with RAV's tools and report whether it reproduces.",
+            "arguments": [
+                { "name": "steps", "description": "Reported reproduction steps.", "required": true },
+                { "name": "expected", "description": "What the reporter expected to happen.", "required": false },
+                { "name": "actual", "description": "What happened instead.", "required": false }
+            ]
+        }
+    ])
+}
+
+/// Builds the prompt messages, embedding live context read through the bridge.
+pub async fn get_prompt(bridge: &Bridge, name: &str, arguments: &Value) -> Result<Value, String> {
+    let argument = |key: &str| {
+        arguments
+            .get(key)
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+    };
+    let required = |key: &str| {
+        argument(key).ok_or_else(|| format!("Prompt \"{name}\" requires the \"{key}\" argument"))
+    };
+
+    let (description, mut messages) = match name {
+        "audit_vm_naming" => {
+            let convention =
+                argument("convention").unwrap_or("the convention most of the file already follows");
+            (
+                "Audit the ViewModel for naming issues",
+                vec![
+                    text_message(format!(
+                        "Audit the ViewModel of the .riv loaded in RAV for naming issues. Check every property path against {convention}. Flag inconsistent casing, abbreviations, names that collide once normalized, vague names (value, prop1, new), booleans that do not read as a yes/no question, and names that are awkward to type from app code. Group findings by ViewModel, quote the exact path, and suggest a replacement for each. The ViewModel tree is attached."
+                    )),
+                    resource_message(bridge, "rav://current/vm-tree").await,
+                ],
+            )
+        }
+        "handoff_snippet" => {
+            let artboard = required("artboard")?;
+            let package_source = match argument("package_source").unwrap_or("cdn") {
+                source @ ("cdn" | "local") => source,
+                other => {
+                    return Err(format!(
+                        "Unknown package_source \"{other}\" (expected cdn or local)"
+                    ))
+                }
+            };
+            let snippet = bridge
+                .send_command(
+                    "generate_web_instantiation_code",
+                    json!({ "package_source": package_source }),
+                )
+                .await
+                .map(|result| {
+                    result
+                        .get("code")
+                        .and_then(Value::as_str)
+                        .map_or_else(|| result.to_string(), str::to_string)
+                })
+                .unwrap_or_else(|error| format!("Unavailable: {error}"));
+            (
+                "Developer handoff for one artboard",
+                vec![
+                    text_message(format!(
+                        "Write a developer handoff for the \"{artboard}\" artboard of the .riv loaded in RAV. List its state machines and what each input or ViewModel property does, then give a {package_source} web snippet that instantiates it. The current file info, the artboard's state machines and RAV's generated snippet for the current artboard are attached. If the file info shows a different current artboard, call rav_switch_artboard with \"{artboard}\" and generate_web_instantiation_code again before using the snippet."
+                    )),
+                    resource_message(bridge, "rav://current/file-info").await,
+                    resource_message(bridge, &artboard_state_machines_uri(artboard)).await,
+                    text_message(format!("Generated snippet ({package_source}):\n\n{snippet}")),
+                ],
+            )
+        }
+        "reproduce_bug" => {
+            let steps = required("steps")?;
+            let mut report = format!("Steps:\n{steps}");
+            if let Some(expected) = argument("expected") {
+                report.push_str(&format!("\n\nExpected:\n{expected}"));
+            }
+            if let Some(actual) = argument("actual") {
+                report.push_str(&format!("\n\nActual:\n{actual}"));
+            }
+            (
+                "Reproduce a reported bug in RAV",
+                vec![
+                    text_message(format!(
+                        "Reproduce this bug report in RAV. Translate each step into RAV tool calls (rav_switch_artboard, rav_vm_set, rav_vm_fire, rav_set_sm_input, rav_play/rav_pause), run them in order, and read rav_get_event_log after each one. Report whether the bug reproduces, the smallest set of steps that triggers it, and the ViewModel values at that point. The current file info, ViewModel tree and recent events are attached as the starting state.\n\n{report}"
+                    )),
+                    resource_message(bridge, "rav://current/file-info").await,
+                    resource_message(bridge, "rav://current/vm-tree").await,
+                    resource_message(bridge, "rav://current/event-log").await,
+                ],
+            )
+        }
+        _ => return Err(format!("Unknown prompt: {name}")),
+    };
+
+    messages.retain(|message| !message.is_null());
+    Ok(json!({ "description": description, "messages": messages }))
+}
+
+fn text_message(text: String) -> Value {
+    json!({ "role": "user", "content": { "type": "text", "text": text } })
+}
+
+/// Embeds a resource, or a note saying why it could not be read so the prompt still works offline.
+async fn resource_message(bridge: &Bridge, uri: &str) -> Value {
+    let Some(resource) = resolve_resource(uri) else {
+        return Value::Null;
+    };
+    match read_resource(bridge, uri, resource).await {
+        Ok(result) => json!({
+            "role": "user",
+            "content": {
+                "type": "resource",
+                "resource": result["contents"][0].clone()
+            }
+        }),
+        Err(error) => text_message(format!("{uri} is unavailable: {error}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn prompts_validate_arguments_before_reading_context() {
+        let bridge = Bridge::new(Duration::from_millis(10));
+
+        assert_eq!(
+            get_prompt(&bridge, "handoff_snippet", &json!({})).await,
+            Err("Prompt \"handoff_snippet\" requires the \"artboard\" argument".into())
+        );
+        assert_eq!(
+            get_prompt(
+                &bridge,
+                "handoff_snippet",
+                &json!({ "artboard": "Main", "package_source": "npm" })
+            )
+            .await,
+            Err("Unknown package_source \"npm\" (expected cdn or local)".into())
+        );
+        assert_eq!(
+            get_prompt(&bridge, "nope", &json!({})).await,
+            Err("Unknown prompt: nope".into())
+        );
+        let prompts = prompts_list();
+        let names: Vec<&str> = prompts
+            .as_array()
+            .expect("prompts")
+            .iter()
+            .filter_map(|prompt| prompt["name"].as_str())
+            .collect();
+        assert_eq!(
+            names,
+            ["audit_vm_naming", "handoff_snippet", "reproduce_bug"]
+        );
+    }
+}
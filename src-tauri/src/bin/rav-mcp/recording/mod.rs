@@ -0,0 +1,105 @@
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::support::constants::{SERVER_NAME, SERVER_VERSION};
+
+pub mod replay;
+
+pub const SESSION_ENTRY: &str = "session";
+pub const REQUEST_ENTRY: &str = "request";
+pub const COMMAND_ENTRY: &str = "command";
+
+/// Appends one JSON line per MCP request and bridge command, with its offset from the start of
+/// the recording and its latency, so `--replay` can re-issue the commands at the same pace.
+#[derive(Clone)]
+pub struct Recorder {
+    writer: Arc<Mutex<BufWriter<File>>>,
+    started: Instant,
+}
+
+impl Recorder {
+    pub fn create(path: &Path) -> Result<Self> {
+        let file = File::create(path)
+            .with_context(|| format!("failed to create recording {}", path.display()))?;
+        let recorder = Self {
+            writer: Arc::new(Mutex::new(BufWriter::new(file))),
+            started: Instant::now(),
+        };
+        let started_at_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_millis());
+        recorder.write(json!({
+            "kind": SESSION_ENTRY,
+            "server": SERVER_NAME,
+            "version": SERVER_VERSION,
+            "started_at_ms": started_at_ms,
+        }));
+        Ok(recorder)
+    }
+
+    pub fn offset_ms(&self) -> u64 {
+        millis(self.started.elapsed())
+    }
+
+    pub fn record_request(
+        &self,
+        request: &Value,
+        response: &Value,
+        offset_ms: u64,
+        latency: Duration,
+    ) {
+        self.write(json!({
+            "kind": REQUEST_ENTRY,
+            "offset_ms": offset_ms,
+            "latency_ms": millis(latency),
+            "method": request.get("method").cloned().unwrap_or(Value::Null),
+            "request": request,
+            "response": response,
+        }));
+    }
+
+    pub fn record_command(
+        &self,
+        command: &str,
+        params: &Value,
+        outcome: &Result<Value>,
+        offset_ms: u64,
+        latency: Duration,
+    ) {
+        let mut entry = json!({
+            "kind": COMMAND_ENTRY,
+            "offset_ms": offset_ms,
+            "latency_ms": millis(latency),
+            "command": command,
+            "params": params,
+        });
+        match outcome {
+            Ok(result) => entry["result"] = result.clone(),
+            Err(error) => entry["error"] = Value::String(error.to_string()),
+        }
+        self.write(entry);
+    }
+
+    /// Recording must never break a session, so write failures are only logged.
+    fn write(&self, entry: Value) {
+        let Ok(mut writer) = self.writer.lock() else {
+            return;
+        };
+        let written = serde_json::to_writer(&mut *writer, &entry)
+            .map_err(std::io::Error::from)
+            .and_then(|()| writer.write_all(b"\n"))
+            .and_then(|()| writer.flush());
+        if let Err(error) = written {
+            eprintln!("[rav-mcp] Failed to write recording entry: {error}");
+        }
+    }
+}
+
+fn millis(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
@@ -0,0 +1,252 @@
+use anyhow::{anyhow, Context, Result};
+use serde_json::Value;
+use std::path::Path;
+use std::time::Duration;
+use tokio::time::{sleep_until, Instant};
+
+use super::COMMAND_ENTRY;
+use crate::bridge::{Bridge, CommandContext};
+use crate::tool_registry::command_timeout;
+
+pub const DEFAULT_IGNORED_KEYS: &[&str] = &["timestamp"];
+const MAX_DIFFS_PER_COMMAND: usize = 20;
+
+/// One bridge command from a recording, with the outcome the app gave at the time.
+#[derive(Debug, PartialEq)]
+pub struct RecordedCommand {
+    pub offset_ms: u64,
+    pub command: String,
+    pub params: Value,
+    pub expected: Result<Value, String>,
+}
+
+/// Command entries of a `--record` file in the order they were sent. Other entries are skipped.
+pub fn load_commands(text: &str) -> Result<Vec<RecordedCommand>> {
+    let mut commands = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let entry: Value = serde_json::from_str(line)
+            .with_context(|| format!("line {}: invalid JSON", index + 1))?;
+        if entry.get("kind").and_then(Value::as_str) != Some(COMMAND_ENTRY) {
+            continue;
+        }
+        let Some(command) = entry.get("command").and_then(Value::as_str) else {
+            return Err(anyhow!(
+                "line {}: command entry without a command",
+                index + 1
+            ));
+        };
+        let expected = match entry.get("error").and_then(Value::as_str) {
+            Some(error) => Err(error.to_string()),
+            None => Ok(entry.get("result").cloned().unwrap_or(Value::Null)),
+        };
+        commands.push(RecordedCommand {
+            offset_ms: entry.get("offset_ms").and_then(Value::as_u64).unwrap_or(0),
+            command: command.to_string(),
+            params: entry.get("params").cloned().unwrap_or(Value::Null),
+            expected,
+        });
+    }
+    commands.sort_by_key(|command| command.offset_ms);
+    Ok(commands)
+}
+
+/// Paths where `actual` differs from `expected`, skipping object keys listed in `ignored`.
+pub fn diff_values(expected: &Value, actual: &Value, ignored: &[String]) -> Vec<String> {
+    let mut differences = Vec::new();
+    diff_at("$", expected, actual, ignored, &mut differences);
+    differences
+}
+
+fn diff_at(
+    path: &str,
+    expected: &Value,
+    actual: &Value,
+    ignored: &[String],
+    out: &mut Vec<String>,
+) {
+    match (expected, actual) {
+        (Value::Object(expected), Value::Object(actual)) => {
+            let keys = expected
+                .keys()
+                .chain(actual.keys().filter(|key| !expected.contains_key(*key)));
+            for key in keys.filter(|key| !ignored.contains(key)) {
+                let child = format!("{path}.{key}");
+                match (expected.get(key), actual.get(key)) {
+                    (Some(expected), Some(actual)) => {
+                        diff_at(&child, expected, actual, ignored, out)
+                    }
+                    (Some(_), None) => out.push(format!("{child}: missing")),
+                    (None, Some(actual)) => out.push(format!("{child}: unexpected {actual}")),
+                    (None, None) => {}
+                }
+            }
+        }
+        (Value::Array(expected), Value::Array(actual)) if expected.len() != actual.len() => out
+            .push(format!(
+                "{path}: expected {} items, got {}",
+                expected.len(),
+                actual.len()
+            )),
+        (Value::Array(expected), Value::Array(actual)) => {
+            for (index, (expected, actual)) in expected.iter().zip(actual).enumerate() {
+                diff_at(&format!("{path}[{index}]"), expected, actual, ignored, out);
+            }
+        }
+        _ if expected != actual => out.push(format!("{path}: expected {expected}, got {actual}")),
+        _ => {}
+    }
+}
+
+fn diff_outcomes(
+    expected: &Result<Value, String>,
+    actual: &Result<Value>,
+    ignored: &[String],
+) -> Vec<String> {
+    match (expected, actual) {
+        (Ok(expected), Ok(actual)) => diff_values(expected, actual, ignored),
+        (Ok(_), Err(error)) => vec![format!("expected a result, got error: {error}")],
+        (Err(expected), Ok(_)) => vec![format!("expected error \"{expected}\", got a result")],
+        (Err(expected), Err(actual)) if *expected != actual.to_string() => {
+            vec![format!("expected error \"{expected}\", got \"{actual}\"")]
+        }
+        (Err(_), Err(_)) => Vec::new(),
+    }
+}
+
+/// Re-sends each command at its recorded offset and returns the differences per command index.
+pub async fn replay_commands(
+    bridge: &Bridge,
+    commands: &[RecordedCommand],
+    ignored: &[String],
+) -> Vec<(usize, Vec<String>)> {
+    let started = Instant::now();
+    let first_offset = commands.first().map_or(0, |command| command.offset_ms);
+    let mut report = Vec::with_capacity(commands.len());
+    for (index, recorded) in commands.iter().enumerate() {
+        sleep_until(started + Duration::from_millis(recorded.offset_ms - first_offset)).await;
+        let context = CommandContext {
+            timeout: command_timeout(&recorded.command),
+            ..CommandContext::default()
+        };
+        let actual = bridge
+            .send_command_with_context(&recorded.command, recorded.params.clone(), context)
+            .await;
+        report.push((index, diff_outcomes(&recorded.expected, &actual, ignored)));
+    }
+    report
+}
+
+/// Replays a recording against the connected app and fails if any response changed.
+pub async fn run_replay(bridge: Bridge, path: &Path, ignored: &[String]) -> Result<()> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read recording {}", path.display()))?;
+    let commands = load_commands(&text)?;
+    let total = commands.len();
+    let mut differing = 0;
+    for (index, differences) in replay_commands(&bridge, &commands, ignored).await {
+        let command = &commands[index].command;
+        if differences.is_empty() {
+            eprintln!("[{}/{total}] {command} ok", index + 1);
+            continue;
+        }
+        differing += 1;
+        eprintln!("[{}/{total}] {command} DIFF", index + 1);
+        for difference in differences.iter().take(MAX_DIFFS_PER_COMMAND) {
+            eprintln!("    {difference}");
+        }
+        if differences.len() > MAX_DIFFS_PER_COMMAND {
+            eprintln!("    ... {} more", differences.len() - MAX_DIFFS_PER_COMMAND);
+        }
+    }
+    eprintln!("[rav-mcp] Replayed {total} commands, {differing} differed");
+    if differing > 0 {
+        return Err(anyhow!("{differing} of {total} replayed commands differed"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::recording::Recorder;
+    use serde_json::json;
+    use tokio::sync::mpsc;
+
+    #[test]
+    fn diffs_report_paths_and_skip_ignored_keys() {
+        let ignored = vec!["timestamp".to_string()];
+        assert!(diff_values(
+            &json!({ "a": 1, "timestamp": 5 }),
+            &json!({ "a": 1, "timestamp": 9 }),
+            &ignored
+        )
+        .is_empty());
+        assert_eq!(
+            diff_values(
+                &json!({ "file": { "name": "a.riv" }, "events": [1, 2], "gone": true }),
+                &json!({ "file": { "name": "b.riv" }, "events": [1], "extra": 0 }),
+                &ignored
+            ),
+            [
+                "$.events: expected 2 items, got 1",
+                "$.file.name: expected \"a.riv\", got \"b.riv\"",
+                "$.gone: missing",
+                "$.extra: unexpected 0",
+            ]
+        );
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn recorded_commands_replay_against_a_connected_app() {
+        let path =
+            std::env::temp_dir().join(format!("rav-mcp-replay-{}.jsonl", std::process::id()));
+        let recording = Bridge::new(Duration::from_millis(20))
+            .with_recorder(Recorder::create(&path).expect("recorder"));
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        recording.register_connection(tx).await;
+        let app = recording.clone();
+        tokio::spawn(async move {
+            let mut volume = 0.5;
+            while let Some(message) = rx.recv().await {
+                let request: Value = serde_json::from_str(&message).expect("request");
+                if request["command"] == "rav_open_file" {
+                    // Slower than the default timeout; replay must use the file-open timeout.
+                    tokio::time::sleep(Duration::from_millis(60)).await;
+                    let result = json!({ "id": request["id"], "result": { "opened": true } });
+                    app.handle_incoming_message(result).await;
+                    continue;
+                }
+                let result = json!({ "path": "volume", "value": volume });
+                volume += 0.25;
+                app.handle_incoming_message(json!({ "id": request["id"], "result": result }))
+                    .await;
+            }
+        });
+        recording
+            .send_command("rav_vm_get", json!({ "path": "volume" }))
+            .await
+            .expect("recorded result");
+        let _ = recording.send_command("rav_status", json!({})).await;
+
+        let text = std::fs::read_to_string(&path).expect("recording");
+        let _ = std::fs::remove_file(&path);
+        assert!(text.starts_with("{\"kind\":\"session\""));
+        let mut commands = load_commands(&text).expect("commands");
+        assert_eq!(commands.len(), 2);
+        assert_eq!(commands[0].params, json!({ "path": "volume" }));
+        commands.push(RecordedCommand {
+            offset_ms: commands[1].offset_ms,
+            command: "rav_open_file".into(),
+            params: json!({ "path": "/tmp/a.riv" }),
+            expected: Ok(json!({ "opened": true })),
+        });
+
+        let report = replay_commands(&recording, &commands, &[]).await;
+        assert_eq!(report[0].1, ["$.value: expected 0.5, got 1.0"]);
+        assert_eq!(report[1].1, ["$.value: expected 0.75, got 1.25"]);
+        assert!(report[2].1.is_empty(), "{:?}", report[2].1);
+    }
+}
@@ -0,0 +1,296 @@
+use serde_json::{json, Value};
+
+use crate::bridge::Bridge;
+
+pub const RESOURCE_NOT_FOUND: i64 = -32002;
+
+const JSON_MIME_TYPE: &str = "application/json";
+const SCRIPT_MIME_TYPE: &str = "text/javascript";
+const ARTBOARD_PREFIX: &str = "rav://artboard/";
+const STATE_MACHINES_SUFFIX: &str = "/state-machines";
+const VM_PROPERTY_PREFIX: &str = "rav://vm/";
+const FILE_INFO_URI: &str = "rav://current/file-info";
+const VM_TREE_URI: &str = "rav://current/vm-tree";
+
+/// A resource URI resolved to the bridge command that reads it.
+#[derive(Debug, PartialEq)]
+pub struct ResourceRead {
+    pub command: &'static str,
+    pub params: Value,
+    pub view: ResourceView,
+}
+
+/// How the command result is turned into resource contents.
+#[derive(Debug, PartialEq)]
+pub enum ResourceView {
+    Json,
+    FileInfo,
+    EditorCode,
+}
+
+pub fn resources_list() -> Value {
+    json!([
+        {
+            "uri": "rav://current/file-info",
+            "name": "Current file",
+            "description": "Name, size and load state of the open .riv, with the runtime and current artboard/playback.",
+            "mimeType": JSON_MIME_TYPE
+        },
+        {
+            "uri": "rav://current/vm-tree",
+            "name": "ViewModel tree",
+            "description": "ViewModel hierarchy, every property path and state-machine inputs of the loaded file.",
+            "mimeType": JSON_MIME_TYPE
+        },
+        {
+            "uri": "rav://current/event-log",
+            "name": "Event log",
+            "description": "The 50 most recent native, Rive user and UI events.",
+            "mimeType": JSON_MIME_TYPE
+        },
+        {
+            "uri": "rav://current/editor-code",
+            "name": "Editor code",
+            "description": "JavaScript instantiation config currently in the script editor.",
+            "mimeType": SCRIPT_MIME_TYPE
+        }
+    ])
+}
+
+pub fn resource_templates_list() -> Value {
+    json!([
+        {
+            "uriTemplate": "rav://artboard/{name}/state-machines",
+            "name": "Artboard state machines",
+            "description": "State machine names on an artboard of the loaded file. Percent-encode names with spaces or slashes.",
+            "mimeType": JSON_MIME_TYPE
+        },
+        {
+            "uriTemplate": "rav://vm/{+path}",
+            "name": "ViewModel property",
+            "description": "Current kind and value of a ViewModel property, e.g. rav://vm/settings/volume.",
+            "mimeType": JSON_MIME_TYPE
+        }
+    ])
+}
+
+pub fn resolve_resource(uri: &str) -> Option<ResourceRead> {
+    let read = |command, params, view| {
+        Some(ResourceRead {
+            command,
+            params,
+            view,
+        })
+    };
+    match uri {
+        "rav://current/file-info" => return read("rav_status", json!({}), ResourceView::FileInfo),
+        "rav://current/vm-tree" => return read("rav_get_vm_tree", json!({}), ResourceView::Json),
+        "rav://current/event-log" => {
+            return read("rav_get_event_log", json!({}), ResourceView::Json)
+        }
+        "rav://current/editor-code" => {
+            return read("rav_get_editor_code", json!({}), ResourceView::EditorCode)
+        }
+        _ => {}
+    }
+    if let Some(name) = uri
+        .strip_prefix(ARTBOARD_PREFIX)
+        .and_then(|rest| rest.strip_suffix(STATE_MACHINES_SUFFIX))
+        .and_then(percent_decode)
+        .filter(|name| !name.is_empty())
+    {
+        return read(
+            "rav_get_state_machines",
+            json!({ "artboard": name }),
+            ResourceView::Json,
+        );
+    }
+    uri.strip_prefix(VM_PROPERTY_PREFIX)
+        .and_then(percent_decode)
+        .filter(|path| !path.is_empty())
+        .and_then(|path| read("rav_vm_get", json!({ "path": path }), ResourceView::Json))
+}
+
+pub async fn read_resource(
+    bridge: &Bridge,
+    uri: &str,
+    resource: ResourceRead,
+) -> Result<Value, String> {
+    let result = bridge
+        .send_command(resource.command, resource.params)
+        .await
+        .map_err(|error| error.to_string())?;
+    Ok(json!({ "contents": [resource_contents(uri, &resource.view, result)] }))
+}
+
+/// Subscribed URIs whose contents change with any of the updated URIs the app reported.
+pub fn affected_subscriptions(subscriptions: &[String], updated: &[String]) -> Vec<String> {
+    subscriptions
+        .iter()
+        .filter(|subscribed| {
+            updated
+                .iter()
+                .any(|updated| depends_on(subscribed.as_str(), updated.as_str()))
+        })
+        .cloned()
+        .collect()
+}
+
+/// `notifications/resources/updated` messages for a `resources-updated` app event.
+pub fn resource_update_notifications(subscriptions: &[String], event: &Value) -> Vec<Value> {
+    let updated: Vec<String> = event
+        .get("uris")
+        .and_then(Value::as_array)
+        .map(|uris| {
+            uris.iter()
+                .filter_map(Value::as_str)
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default();
+    affected_subscriptions(subscriptions, &updated)
+        .into_iter()
+        .map(|uri| {
+            json!({
+                "jsonrpc": "2.0",
+                "method": "notifications/resources/updated",
+                "params": { "uri": uri }
+            })
+        })
+        .collect()
+}
+
+fn depends_on(subscribed: &str, updated: &str) -> bool {
+    if subscribed == updated {
+        return true;
+    }
+    let file_changed = updated == FILE_INFO_URI;
+    if subscribed.starts_with(VM_PROPERTY_PREFIX) {
+        return file_changed || updated == VM_TREE_URI;
+    }
+    if subscribed == VM_TREE_URI {
+        return file_changed || updated.starts_with(VM_PROPERTY_PREFIX);
+    }
+    file_changed && subscribed.starts_with(ARTBOARD_PREFIX)
+}
+
+fn resource_contents(uri: &str, view: &ResourceView, result: Value) -> Value {
+    match view {
+        ResourceView::EditorCode => json!({
+            "uri": uri,
+            "mimeType": SCRIPT_MIME_TYPE,
+            "text": result.get("code").and_then(Value::as_str).unwrap_or_default(),
+        }),
+        ResourceView::FileInfo => json_contents(
+            uri,
+            &json!({
+                "file": result.get("file").cloned().unwrap_or(Value::Null),
+                "runtime": result.get("runtime").cloned().unwrap_or(Value::Null),
+                "artboard": result.get("artboard").cloned().unwrap_or(Value::Null),
+            }),
+        ),
+        ResourceView::Json => json_contents(uri, &result),
+    }
+}
+
+fn json_contents(uri: &str, value: &Value) -> Value {
+    json!({
+        "uri": uri,
+        "mimeType": JSON_MIME_TYPE,
+        "text": serde_json::to_string_pretty(value).unwrap_or_else(|_| value.to_string()),
+    })
+}
+
+pub fn artboard_state_machines_uri(artboard: &str) -> String {
+    let encoded: String = artboard
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect();
+    format!("{ARTBOARD_PREFIX}{encoded}{STATE_MACHINES_SUFFIX}")
+}
+
+fn percent_decode(raw: &str) -> Option<String> {
+    let bytes = raw.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] == b'%' {
+            let hex = raw.get(index + 1..index + 3)?;
+            decoded.push(u8::from_str_radix(hex, 16).ok()?);
+            index += 3;
+        } else {
+            decoded.push(bytes[index]);
+            index += 1;
+        }
+    }
+    String::from_utf8(decoded).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn static_and_templated_uris_resolve_to_bridge_commands() {
+        let file_info = resolve_resource("rav://current/file-info").expect("file info");
+        assert_eq!(file_info.command, "rav_status");
+        assert_eq!(file_info.view, ResourceView::FileInfo);
+
+        let state_machines =
+            resolve_resource("rav://artboard/Main%20Menu/state-machines").expect("template");
+        assert_eq!(state_machines.command, "rav_get_state_machines");
+        assert_eq!(state_machines.params, json!({ "artboard": "Main Menu" }));
+
+        let property = resolve_resource("rav://vm/settings/volume").expect("vm property");
+        assert_eq!(property.params, json!({ "path": "settings/volume" }));
+
+        assert_eq!(
+            resolve_resource(&artboard_state_machines_uri("Menu/Ö")).map(|read| read.params),
+            Some(json!({ "artboard": "Menu/Ö" }))
+        );
+        assert!(resolve_resource("rav://artboard//state-machines").is_none());
+        assert!(resolve_resource("rav://artboard/bad%zz/state-machines").is_none());
+        assert!(resolve_resource("rav://current/unknown").is_none());
+    }
+
+    #[test]
+    fn updates_reach_subscriptions_that_depend_on_them() {
+        let subscriptions = vec![
+            "rav://current/event-log".to_string(),
+            "rav://current/vm-tree".to_string(),
+            "rav://vm/settings/volume".to_string(),
+            "rav://artboard/Menu/state-machines".to_string(),
+        ];
+        let notify = |updated: &[&str]| {
+            let updated: Vec<String> = updated.iter().map(|uri| uri.to_string()).collect();
+            affected_subscriptions(&subscriptions, &updated)
+        };
+
+        assert_eq!(
+            notify(&["rav://vm/settings/volume"]),
+            ["rav://current/vm-tree", "rav://vm/settings/volume"]
+        );
+        assert_eq!(notify(&["rav://current/file-info"]).len(), 3);
+        assert_eq!(
+            notify(&["rav://current/event-log"]),
+            ["rav://current/event-log"]
+        );
+        assert_eq!(notify(&["rav://vm/other"]), ["rav://current/vm-tree"]);
+    }
+
+    #[test]
+    fn editor_code_is_served_as_script_text() {
+        let contents = resource_contents(
+            "rav://current/editor-code",
+            &ResourceView::EditorCode,
+            json!({ "code": "({ autoplay: true })" }),
+        );
+        assert_eq!(contents["mimeType"], SCRIPT_MIME_TYPE);
+        assert_eq!(contents["text"], "({ autoplay: true })");
+    }
+}
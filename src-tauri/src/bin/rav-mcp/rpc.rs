@@ -1,12 +1,16 @@
+use futures_util::future::join_all;
 use serde_json::{json, Value};
-use std::time::Duration;
+use std::time::Instant;
 
-use crate::bridge::Bridge;
-use crate::support::constants::{
-    DEFAULT_PROTOCOL_VERSION, FILE_OPEN_COMMAND_TIMEOUT_MS, SERVER_NAME, SERVER_VERSION,
+use crate::bridge::{Bridge, CommandContext};
+use crate::prompts::{get_prompt, prompts_list};
+use crate::resources::{
+    read_resource, resolve_resource, resource_templates_list, resources_list, RESOURCE_NOT_FOUND,
 };
+use crate::session::Session;
+use crate::support::constants::{DEFAULT_PROTOCOL_VERSION, SERVER_NAME, SERVER_VERSION};
 use crate::support::instructions::SERVER_INSTRUCTIONS;
-use crate::tool_registry::tools_list;
+use crate::tool_registry::{command_timeout, input_schema, validate_arguments};
 
 pub fn jsonrpc_error(id: Value, code: i64, message: impl Into<String>) -> Value {
     json!({
@@ -27,7 +31,144 @@ fn jsonrpc_result(id: Value, result: Value) -> Value {
     })
 }
 
-pub async fn handle_request(bridge: &Bridge, request: Value) -> Value {
+fn tool_error(text: String) -> Value {
+    json!({
+        "content": [
+            {
+                "type": "text",
+                "text": text
+            }
+        ],
+        "isError": true
+    })
+}
+
+/// One incoming frame, a single message or a batch, sorted into the work it carries.
+#[derive(Debug, Default, PartialEq)]
+pub struct Frame {
+    pub requests: Vec<Value>,
+    pub notifications: Vec<Value>,
+    /// `-32600` replies for members that are not valid JSON-RPC messages.
+    pub errors: Vec<Value>,
+    /// Replies must go back as one array.
+    pub batch: bool,
+}
+
+impl Frame {
+    /// Packs replies the way the frame arrived; `None` when nothing needs an answer.
+    pub fn reply(&self, mut responses: Vec<Value>) -> Option<Value> {
+        if self.batch {
+            (!responses.is_empty()).then_some(Value::Array(responses))
+        } else {
+            responses.pop()
+        }
+    }
+}
+
+pub fn split_frame(message: Value) -> Frame {
+    let mut frame = Frame::default();
+    let members = match message {
+        Value::Array(members) if members.is_empty() => {
+            frame
+                .errors
+                .push(invalid_request(Value::Null, "empty batch"));
+            return frame;
+        }
+        Value::Array(members) => {
+            frame.batch = true;
+            members
+        }
+        message => vec![message],
+    };
+    for member in members {
+        let id = member.get("id").cloned();
+        let problem = if !member.is_object() {
+            Some("expected a JSON-RPC object")
+        } else if id
+            .as_ref()
+            .is_some_and(|id| !(id.is_string() || id.is_number() || id.is_null()))
+        {
+            Some("id must be a string or number")
+        } else if member.get("jsonrpc").and_then(Value::as_str) != Some("2.0") {
+            Some("jsonrpc must be \"2.0\"")
+        } else {
+            None
+        };
+        match (problem, member.get("method").map(Value::is_string), &id) {
+            (Some(problem), _, _) => frame.errors.push(invalid_request(
+                id.filter(|id| !id.is_object()).unwrap_or(Value::Null),
+                problem,
+            )),
+            (None, Some(true), Some(_)) => frame.requests.push(member),
+            (None, Some(true), None) => frame.notifications.push(member),
+            // Replies to server-initiated requests; rav-mcp sends none, so there is nothing to match.
+            (None, None, Some(_))
+                if member.get("result").is_some() || member.get("error").is_some() => {}
+            (None, _, id) => frame.errors.push(invalid_request(
+                id.clone().unwrap_or(Value::Null),
+                "method must be a string",
+            )),
+        }
+    }
+    frame
+}
+
+fn invalid_request(id: Value, problem: &str) -> Value {
+    jsonrpc_error(id, -32600, format!("Invalid Request: {problem}"))
+}
+
+/// Answers a whole frame at once. Stdio dispatches through `Dispatcher` instead so slow calls
+/// never hold up the reader.
+pub async fn handle_frame(bridge: &Bridge, session: &Session, message: Value) -> Option<Value> {
+    let frame = split_frame(message);
+    for notification in &frame.notifications {
+        handle_notification(session, notification);
+    }
+    let requests = frame.requests.iter().map(|request| async move {
+        let id = request.get("id").cloned().unwrap_or(Value::Null);
+        session.begin_request(&id);
+        let response = handle_request(bridge, session, request.clone()).await;
+        session.finish_request(&id);
+        response
+    });
+    let mut responses = frame.errors.clone();
+    responses.extend(join_all(requests).await);
+    frame.reply(responses)
+}
+
+pub fn handle_notification(session: &Session, notification: &Value) {
+    let method = notification
+        .get("method")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    match method {
+        "notifications/cancelled" => {
+            if let Some(request_id) = notification
+                .get("params")
+                .and_then(|params| params.get("requestId"))
+            {
+                session.cancel_request(request_id);
+            }
+        }
+        // The session is usable as soon as `initialize` returns; nothing waits on this.
+        "notifications/initialized" => {}
+        // Client-side state rav-mcp does not use.
+        "notifications/roots/list_changed" | "notifications/progress" => {}
+        _ => eprintln!("[rav-mcp] Ignoring unknown notification: {method}"),
+    }
+}
+
+pub async fn handle_request(bridge: &Bridge, session: &Session, request: Value) -> Value {
+    let Some(recorder) = bridge.recorder() else {
+        return respond(bridge, session, request).await;
+    };
+    let (offset_ms, started) = (recorder.offset_ms(), Instant::now());
+    let response = respond(bridge, session, request.clone()).await;
+    recorder.record_request(&request, &response, offset_ms, started.elapsed());
+    response
+}
+
+async fn respond(bridge: &Bridge, session: &Session, request: Value) -> Value {
     let id = request.get("id").cloned().unwrap_or(Value::Null);
     let method = request
         .get("method")
@@ -52,10 +193,10 @@ pub async fn handle_request(bridge: &Bridge, request: Value) -> Value {
                         },
                         "resources": {
                             "listChanged": false,
-                            "subscribe": false
+                            "subscribe": true
                         },
                         "tools": {
-                            "listChanged": false
+                            "listChanged": true
                         }
                     },
                     "serverInfo": {
@@ -67,11 +208,54 @@ pub async fn handle_request(bridge: &Bridge, request: Value) -> Value {
             )
         }
         "ping" => jsonrpc_result(id, json!({})),
-        "prompts/list" => jsonrpc_result(id, json!({ "prompts": [] })),
-        "resources/list" => jsonrpc_result(id, json!({ "resources": [] })),
-        "resources/templates/list" => jsonrpc_result(id, json!({ "resourceTemplates": [] })),
+        "prompts/list" => jsonrpc_result(id, json!({ "prompts": prompts_list() })),
+        "prompts/get" => {
+            let Some(params) = request.get("params") else {
+                return jsonrpc_error(id, -32602, "Missing prompt params");
+            };
+            let Some(name) = params.get("name").and_then(Value::as_str) else {
+                return jsonrpc_error(id, -32602, "Missing prompt name");
+            };
+            let arguments = params
+                .get("arguments")
+                .cloned()
+                .unwrap_or_else(|| json!({}));
+            match get_prompt(bridge, name, &arguments).await {
+                Ok(result) => jsonrpc_result(id, result),
+                Err(error) => jsonrpc_error(id, -32602, error),
+            }
+        }
+        "resources/list" => jsonrpc_result(id, json!({ "resources": resources_list() })),
+        "resources/templates/list" => jsonrpc_result(
+            id,
+            json!({ "resourceTemplates": resource_templates_list() }),
+        ),
+        "resources/read" | "resources/subscribe" | "resources/unsubscribe" => {
+            let Some(uri) = request
+                .get("params")
+                .and_then(|params| params.get("uri"))
+                .and_then(Value::as_str)
+            else {
+                return jsonrpc_error(id, -32602, "Missing resource uri");
+            };
+            if method == "resources/unsubscribe" {
+                session.unsubscribe(uri);
+                return jsonrpc_result(id, json!({}));
+            }
+            let Some(resource) = resolve_resource(uri) else {
+                return jsonrpc_error(id, RESOURCE_NOT_FOUND, format!("Resource not found: {uri}"));
+            };
+            if method == "resources/subscribe" {
+                session.subscribe(uri);
+                return jsonrpc_result(id, json!({}));
+            }
+            match read_resource(bridge, uri, resource).await {
+                Ok(result) => jsonrpc_result(id, result),
+                Err(error) => jsonrpc_error(id, -32603, format!("Failed to read {uri}: {error}")),
+            }
+        }
         "logging/setLevel" => jsonrpc_result(id, json!({})),
-        "tools/list" => jsonrpc_result(id, json!({ "tools": tools_list() })),
+        "tools/list" => jsonrpc_result(id, json!({ "tools": bridge.tool_catalog().await })),
         "tools/call" => {
             let Some(params) = request.get("params") else {
                 return jsonrpc_error(id, -32602, "Missing tool call params");
@@ -83,18 +267,30 @@ pub async fn handle_request(bridge: &Bridge, request: Value) -> Value {
                 .get("arguments")
                 .cloned()
                 .unwrap_or_else(|| json!({}));
+            let catalog = bridge.tool_catalog().await;
+            if let Some(Err(violations)) =
+                input_schema(&catalog, name).map(|schema| validate_arguments(&schema, &arguments))
+            {
+                return jsonrpc_result(
+                    id,
+                    tool_error(format!(
+                        "Invalid arguments for {name}:\n- {}",
+                        violations.join("\n- ")
+                    )),
+                );
+            }
 
-            let command_result = if name == "rav_open_file" {
-                bridge
-                    .send_command_with_timeout(
-                        name,
-                        arguments,
-                        Duration::from_millis(FILE_OPEN_COMMAND_TIMEOUT_MS),
-                    )
-                    .await
-            } else {
-                bridge.send_command(name, arguments).await
+            let context = CommandContext {
+                timeout: command_timeout(name),
+                cancelled: session.cancellation(&id),
+                on_progress: params
+                    .get("_meta")
+                    .and_then(|meta| meta.get("progressToken"))
+                    .and_then(|token| session.progress_reporter(token.clone())),
             };
+            let command_result = bridge
+                .send_command_with_context(name, arguments, context)
+                .await;
 
             match command_result {
                 Ok(result) => {
@@ -117,20 +313,72 @@ pub async fn handle_request(bridge: &Bridge, request: Value) -> Value {
                     }
                     jsonrpc_result(id, payload)
                 }
-                Err(error) => jsonrpc_result(
-                    id,
-                    json!({
-                        "content": [
-                            {
-                                "type": "text",
-                                "text": format!("Error: {}", error)
-                            }
-                        ],
-                        "isError": true
-                    }),
-                ),
+                Err(error) => jsonrpc_result(id, tool_error(format!("Error: {}", error))),
             }
         }
         _ => jsonrpc_error(id, -32601, format!("Method not found: {}", method)),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn frames_sort_batches_into_requests_notifications_and_invalid_members() {
+        let frame = split_frame(json!([
+            { "jsonrpc": "2.0", "id": 1, "method": "ping" },
+            { "jsonrpc": "2.0", "method": "notifications/initialized" },
+            { "jsonrpc": "2.0", "id": 2, "result": {} },
+            { "jsonrpc": "1.0", "id": 3, "method": "ping" },
+            { "jsonrpc": "2.0", "id": 4 },
+            7
+        ]));
+        assert!(frame.batch);
+        assert_eq!(frame.requests.len(), 1);
+        assert_eq!(frame.notifications.len(), 1);
+        let codes: Vec<(Value, Value)> = frame
+            .errors
+            .iter()
+            .map(|error| (error["id"].clone(), error["error"]["code"].clone()))
+            .collect();
+        assert_eq!(
+            codes,
+            [
+                (json!(3), json!(-32600)),
+                (json!(4), json!(-32600)),
+                (Value::Null, json!(-32600))
+            ]
+        );
+
+        let empty = split_frame(json!([]));
+        assert!(!empty.batch);
+        assert_eq!(
+            empty.reply(empty.errors.clone()).expect("error")["error"]["code"],
+            -32600
+        );
+        let notifications_only = split_frame(json!([{ "jsonrpc": "2.0", "method": "x" }]));
+        assert_eq!(notifications_only.reply(Vec::new()), None);
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn batches_are_answered_with_one_array() {
+        let bridge = Bridge::new(Duration::from_millis(10));
+        let reply = handle_frame(
+            &bridge,
+            &Session::default(),
+            json!([
+                { "jsonrpc": "2.0", "id": "a", "method": "ping" },
+                { "jsonrpc": "2.0", "id": "b", "method": "nope" }
+            ]),
+        )
+        .await
+        .expect("batch reply");
+        assert_eq!(
+            reply[0],
+            json!({ "jsonrpc": "2.0", "id": "a", "result": {} })
+        );
+        assert_eq!(reply[1]["error"]["code"], -32601);
+    }
+}
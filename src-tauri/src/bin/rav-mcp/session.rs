@@ -0,0 +1,138 @@
+use serde_json::{json, Value};
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Arc, Mutex};
+use tokio::sync::{mpsc, watch};
+
+use crate::bridge::ProgressCallback;
+
+/// Per-client MCP state that outlives a single request.
+#[derive(Clone, Default)]
+pub struct Session {
+    subscriptions: Arc<Mutex<BTreeSet<String>>>,
+    in_flight: Arc<Mutex<HashMap<String, watch::Sender<bool>>>>,
+    notifications: Option<mpsc::UnboundedSender<Value>>,
+}
+
+impl Session {
+    /// A session whose transport can deliver server-initiated notifications such as progress.
+    pub fn with_notifications(notifications: mpsc::UnboundedSender<Value>) -> Self {
+        Self {
+            notifications: Some(notifications),
+            ..Self::default()
+        }
+    }
+
+    /// The same session with its notifications sent to one response stream instead.
+    pub fn routing_notifications_to(&self, notifications: mpsc::UnboundedSender<Value>) -> Self {
+        Self {
+            notifications: Some(notifications),
+            ..self.clone()
+        }
+    }
+
+    pub fn subscribe(&self, uri: &str) {
+        if let Ok(mut subscriptions) = self.subscriptions.lock() {
+            subscriptions.insert(uri.to_string());
+        }
+    }
+
+    pub fn unsubscribe(&self, uri: &str) {
+        if let Ok(mut subscriptions) = self.subscriptions.lock() {
+            subscriptions.remove(uri);
+        }
+    }
+
+    pub fn subscriptions(&self) -> Vec<String> {
+        self.subscriptions
+            .lock()
+            .map(|subscriptions| subscriptions.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Tracks a request from the moment it is read so `notifications/cancelled` can reach it.
+    pub fn begin_request(&self, id: &Value) {
+        if let Ok(mut in_flight) = self.in_flight.lock() {
+            in_flight
+                .entry(id.to_string())
+                .or_insert_with(|| watch::channel(false).0);
+        }
+    }
+
+    pub fn finish_request(&self, id: &Value) {
+        if let Ok(mut in_flight) = self.in_flight.lock() {
+            in_flight.remove(&id.to_string());
+        }
+    }
+
+    /// Flags an in-flight request as cancelled. Unknown or finished ids are ignored.
+    pub fn cancel_request(&self, id: &Value) -> bool {
+        self.in_flight
+            .lock()
+            .ok()
+            .and_then(|in_flight| {
+                in_flight
+                    .get(&id.to_string())
+                    .map(|sender| sender.send_replace(true))
+            })
+            .is_some()
+    }
+
+    /// Resolves once the request is cancelled; `None` for requests the transport does not track.
+    pub fn cancellation(&self, id: &Value) -> Option<watch::Receiver<bool>> {
+        self.in_flight
+            .lock()
+            .ok()?
+            .get(&id.to_string())
+            .map(watch::Sender::subscribe)
+    }
+
+    /// Turns app progress events into `notifications/progress` for the client's token.
+    pub fn progress_reporter(&self, progress_token: Value) -> Option<ProgressCallback> {
+        let notifications = self.notifications.clone()?;
+        Some(Arc::new(move |event: &Value| {
+            let mut params = json!({
+                "progressToken": progress_token,
+                "progress": event.get("progress").cloned().unwrap_or(json!(0)),
+            });
+            if let Some(total) = event.get("total").filter(|total| total.is_number()) {
+                params["total"] = total.clone();
+            }
+            if let Some(message) = event.get("message").filter(|message| message.is_string()) {
+                params["message"] = message.clone();
+            }
+            let _ = notifications.send(json!({
+                "jsonrpc": "2.0",
+                "method": "notifications/progress",
+                "params": params,
+            }));
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cancellation_and_progress_are_scoped_to_tracked_requests() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let session = Session::with_notifications(tx);
+
+        assert!(!session.cancel_request(&json!(7)));
+        session.begin_request(&json!(7));
+        let cancelled = session.cancellation(&json!(7)).expect("tracked");
+        assert!(session.cancellation(&json!("7")).is_none());
+        assert!(session.cancel_request(&json!(7)));
+        assert!(*cancelled.borrow());
+        session.finish_request(&json!(7));
+        assert!(session.cancellation(&json!(7)).is_none());
+
+        let report = session.progress_reporter(json!("tok")).expect("reporter");
+        report(&json!({ "progress": 1, "total": "n/a", "message": "Loading" }));
+        assert_eq!(
+            rx.try_recv().expect("notification")["params"],
+            json!({ "progressToken": "tok", "progress": 1, "message": "Loading" })
+        );
+        assert!(Session::default().progress_reporter(json!(1)).is_none());
+    }
+}
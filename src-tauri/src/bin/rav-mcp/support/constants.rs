@@ -6,3 +6,4 @@ pub const FILE_OPEN_COMMAND_TIMEOUT_MS: u64 = 60_000;
 pub const DEFAULT_PROTOCOL_VERSION: &str = "2025-06-18";
 pub const RECONNECT_DELAY_MS: u64 = 250;
 pub const APP_CONNECTION_GRACE_MS: u64 = 2_500;
+pub const BRIDGE_EVENT_CAPACITY: usize = 64;
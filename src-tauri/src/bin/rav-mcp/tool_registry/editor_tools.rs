@@ -0,0 +1,141 @@
+use serde_json::{json, Value};
+
+pub fn editor_tools() -> Vec<Value> {
+    vec![
+        json!({
+            "name": "rav_get_editor_code",
+            "description": "Get the current code in the RAV script editor (CodeMirror).",
+            "inputSchema": { "type": "object", "properties": {}, "additionalProperties": false }
+        }),
+        json!({
+            "name": "rav_set_editor_code",
+            "description": "Replace the code in the RAV script editor. This does NOT reload the animation — call rav_apply_code afterwards to apply changes.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "code": { "type": "string", "description": "JavaScript code to set in the editor" }
+                },
+                "required": ["code"],
+                "additionalProperties": false
+            }
+        }),
+        json!({
+            "name": "rav_apply_code",
+            "description": "Apply the current editor code and reload the animation with the new configuration. Equivalent to clicking the \"Apply & Reload\" button.",
+            "inputSchema": { "type": "object", "properties": {}, "additionalProperties": false }
+        }),
+        json!({
+            "name": "rav_configure_workspace",
+            "description": "Set workspace UI state inside RAV. This can open or close the left/right sidebars, switch the live instantiation source between internal and editor mode, and inject or remove the VM Explorer snippet without guessing current state.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "left_sidebar": { "type": "string", "enum": ["open", "close"], "description": "Open or close the left editor sidebar." },
+                    "right_sidebar": { "type": "string", "enum": ["open", "close"], "description": "Open or close the right properties sidebar." },
+                    "source_mode": { "type": "string", "enum": ["internal", "editor"], "description": "Set the live instantiation source. editor applies the current draft code; internal switches back to RAV wiring." },
+                    "vm_explorer": { "type": "string", "enum": ["inject", "remove"], "description": "Ensure the VM Explorer snippet is present or removed in the editor draft." }
+                },
+                "additionalProperties": false
+            }
+        }),
+        json!({
+            "name": "rav_eval",
+            "description": "Evaluate arbitrary JavaScript in the RAV browser context. Has access to window.riveInst, window.vmGet/vmSet/vmFire, and all RAV globals. Use for advanced inspection or operations not covered by other tools. Returns the stringified result.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "expression": { "type": "string", "description": "JavaScript expression or statement to evaluate" }
+                },
+                "required": ["expression"],
+                "additionalProperties": false
+            }
+        }),
+        json!({
+            "name": "rav_console_open",
+            "description": "Open the bottom console panel. Defaults to JS mode. Optional `mode`, `level`, `sources`, and `search` apply pre-configured filter state on open.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "mode": { "type": "string", "enum": ["events", "js"], "description": "Mode to activate on open. Default: js." },
+                    "level": { "type": "string", "enum": ["all", "info", "warning", "error"], "description": "JS console level filter (only applies when mode is js)." },
+                    "sources": {
+                        "type": "array",
+                        "items": { "type": "string", "enum": ["native", "riveUser", "ui", "mcp"] },
+                        "description": "Event console source toggles to enable (only applies when mode is events). Sources not listed are hidden."
+                    },
+                    "search": { "type": "string", "description": "Substring filter applied to console entries." }
+                },
+                "additionalProperties": false
+            }
+        }),
+        json!({
+            "name": "rav_console_close",
+            "description": "Close the JavaScript console panel (switches back to Event Console mode).",
+            "inputSchema": { "type": "object", "properties": {}, "additionalProperties": false }
+        }),
+        json!({
+            "name": "rav_console_set_mode",
+            "description": "Set the bottom console panel mode to Event Console (`events`), JS REPL (`js`), or close it (`closed`). Opens the panel first if needed.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "mode": { "type": "string", "enum": ["events", "js", "closed"], "description": "Console mode to activate." }
+                },
+                "required": ["mode"],
+                "additionalProperties": false
+            }
+        }),
+        json!({
+            "name": "rav_console_set_filter",
+            "description": "Apply filters to the rendered console transcript. Mirrors the existing on-screen filter toggles. JS mode supports `level` (all/info/warning/error). Events mode supports `sources` (subset of native/riveUser/ui/mcp — sources not listed are hidden). Both modes support `search`. Targets the currently active mode if `mode` is omitted.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "mode": { "type": "string", "enum": ["events", "js"], "description": "Optional — apply to a specific mode. Defaults to the currently active mode." },
+                    "level": { "type": "string", "enum": ["all", "info", "warning", "error"], "description": "JS console level filter." },
+                    "sources": {
+                        "type": "array",
+                        "items": { "type": "string", "enum": ["native", "riveUser", "ui", "mcp"] },
+                        "description": "Event console source toggles to enable. Sources omitted from the array are hidden."
+                    },
+                    "search": { "type": "string", "description": "Substring filter applied to entry text. Empty string clears the search filter." }
+                },
+                "additionalProperties": false
+            }
+        }),
+        json!({
+            "name": "rav_console_clear",
+            "description": "Clear the visible transcript of the bottom console panel (Events or JS mode). Does not close the panel.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "mode": { "type": "string", "enum": ["events", "js"], "description": "Optional — clear a specific mode's transcript. Defaults to the currently active mode." }
+                },
+                "additionalProperties": false
+            }
+        }),
+        json!({
+            "name": "rav_console_read",
+            "description": "Read the JS console transcript, including REPL input/result rows and captured console.log/warn/error/info/debug output. Returns recent entries in transcript order with method, timestamp, and args.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "limit": { "type": "number", "minimum": 1, "description": "Maximum entries to return (default 50)" }
+                },
+                "additionalProperties": false
+            }
+        }),
+        json!({
+            "name": "rav_console_exec",
+            "description": "Execute JavaScript in the REPL console. The code is evaluated in the browser context with output displayed in the console panel. Use rav_console_read to inspect the resulting transcript, including REPL input/result rows. Opens the console automatically if not already open.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "code": { "type": "string", "description": "JavaScript code to execute in the console REPL" }
+                },
+                "required": ["code"],
+                "additionalProperties": false
+            }
+        }),
+    ]
+}
@@ -0,0 +1,93 @@
+use serde_json::Value;
+use std::time::Duration;
+
+use crate::support::constants::FILE_OPEN_COMMAND_TIMEOUT_MS;
+
+mod core_tools;
+mod demo_setup_tools;
+mod editor_tools;
+mod export_tools;
+mod validation;
+
+pub use validation::validate_arguments;
+
+pub fn tools_list() -> Value {
+    Value::Array(
+        core_tools::core_tools()
+            .into_iter()
+            .chain(editor_tools::editor_tools())
+            .chain(export_tools::export_tools())
+            .chain(demo_setup_tools::demo_setup_tools())
+            .collect(),
+    )
+}
+
+/// Accepts an app-sent catalog only when every entry is a named tool with an object schema.
+pub fn parse_tool_catalog(tools: &Value) -> Option<Value> {
+    let entries = tools.as_array().filter(|entries| !entries.is_empty())?;
+    entries
+        .iter()
+        .all(|tool| {
+            tool.get("name").is_some_and(Value::is_string)
+                && tool.get("inputSchema").is_some_and(Value::is_object)
+        })
+        .then(|| tools.clone())
+}
+
+pub fn input_schema(catalog: &Value, name: &str) -> Option<Value> {
+    catalog
+        .as_array()?
+        .iter()
+        .find(|tool| tool.get("name").and_then(Value::as_str) == Some(name))
+        .and_then(|tool| tool.get("inputSchema").cloned())
+}
+
+/// Bridge timeout for one tool, shared by live calls and `--replay`; `None` keeps the default.
+pub fn command_timeout(tool: &str) -> Option<Duration> {
+    (tool == "rav_open_file").then(|| Duration::from_millis(FILE_OPEN_COMMAND_TIMEOUT_MS))
+}
+
+/// Tools that touch the same app state share a lane and run one at a time in arrival order.
+/// Playback and input writes share the animation lane because file, artboard and code changes
+/// replace the instance they act on. Tools without a lane (reads and snippet generation) run
+/// concurrently with everything.
+pub fn ordering_lane(tool: &str) -> Option<&'static str> {
+    match tool {
+        "rav_open_file"
+        | "rav_switch_artboard"
+        | "rav_reset_artboard"
+        | "rav_set_runtime"
+        | "rav_set_layout"
+        | "rav_set_canvas_color"
+        | "rav_set_canvas_size"
+        | "rav_set_editor_code"
+        | "rav_apply_code"
+        | "rav_configure_workspace"
+        | "rav_eval"
+        | "rav_play"
+        | "rav_pause"
+        | "rav_reset"
+        | "rav_vm_set"
+        | "rav_vm_fire"
+        | "rav_set_sm_input"
+        | "rav_record_timeline" => Some("animation"),
+        "rav_console_open"
+        | "rav_console_close"
+        | "rav_console_set_mode"
+        | "rav_console_set_filter"
+        | "rav_console_clear"
+        | "rav_console_read"
+        | "rav_console_exec" => Some("console"),
+        "rav_toggle_instantiation_controls_dialog"
+        | "rav_export_demo_visual"
+        | "rav_export_demo"
+        | "rav_export_spec_sheet"
+        | "rav_export_web_component"
+        | "rav_export_framework_starter"
+        | "rav_export_typescript_bindings"
+        | "rav_export_pwa"
+        | "rav_export_presets"
+        | "rav_export_watch" => Some("export"),
+        _ => None,
+    }
+}
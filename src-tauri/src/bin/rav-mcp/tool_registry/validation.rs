@@ -0,0 +1,226 @@
+use serde_json::{Map, Value};
+
+/// Checks tool arguments against the subset of JSON Schema the tool registry uses: `type`,
+/// `enum`, `required`, `properties`, `additionalProperties: false`, `items`, `oneOf` and numeric
+/// `minimum`/`maximum`. Returns every violation, each prefixed with its argument path.
+pub fn validate_arguments(schema: &Value, arguments: &Value) -> Result<(), Vec<String>> {
+    let mut violations = Vec::new();
+    check(schema, arguments, "arguments", &mut violations);
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(violations)
+    }
+}
+
+fn check(schema: &Value, value: &Value, path: &str, violations: &mut Vec<String>) {
+    if let Some(branches) = schema.get("oneOf").and_then(Value::as_array) {
+        let matches = branches
+            .iter()
+            .filter(|branch| validate_arguments(branch, value).is_ok())
+            .count();
+        if matches != 1 {
+            let forms: Vec<String> = branches.iter().map(describe).collect();
+            violations.push(format!(
+                "{path}: expected {}, got {}",
+                forms.join(" or "),
+                describe_value(value)
+            ));
+        }
+        return;
+    }
+
+    if let Some(expected) = schema.get("type") {
+        let allowed: Vec<&str> = match expected {
+            Value::String(kind) => vec![kind.as_str()],
+            Value::Array(kinds) => kinds.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        if !allowed.is_empty() && !allowed.iter().any(|kind| has_type(value, kind)) {
+            violations.push(format!(
+                "{path}: expected {}, got {}",
+                allowed.join(" or "),
+                type_name(value)
+            ));
+            return;
+        }
+    }
+
+    if let Some(options) = schema.get("enum").and_then(Value::as_array) {
+        if !options.contains(value) {
+            violations.push(format!("{path}: must be one of {}", list_values(options)));
+        }
+    }
+
+    if let Some(number) = value.as_f64() {
+        if let Some(minimum) = schema.get("minimum").and_then(Value::as_f64) {
+            if number < minimum {
+                violations.push(format!("{path}: must be at least {minimum}, got {value}"));
+            }
+        }
+        if let Some(maximum) = schema.get("maximum").and_then(Value::as_f64) {
+            if number > maximum {
+                violations.push(format!("{path}: must be at most {maximum}, got {value}"));
+            }
+        }
+    }
+
+    match value {
+        Value::Object(object) => check_object(schema, object, path, violations),
+        Value::Array(items) => {
+            if let Some(item_schema) = schema.get("items") {
+                for (index, item) in items.iter().enumerate() {
+                    check(item_schema, item, &format!("{path}[{index}]"), violations);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn check_object(
+    schema: &Value,
+    object: &Map<String, Value>,
+    path: &str,
+    violations: &mut Vec<String>,
+) {
+    let properties = schema.get("properties").and_then(Value::as_object);
+    let child = |key: &str| {
+        if path == "arguments" {
+            key.to_string()
+        } else {
+            format!("{path}.{key}")
+        }
+    };
+
+    for key in schema
+        .get("required")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+    {
+        if !object.contains_key(key) {
+            violations.push(format!("{}: required", child(key)));
+        }
+    }
+
+    let closed = schema.get("additionalProperties") == Some(&Value::Bool(false));
+    for (key, value) in object {
+        match properties.and_then(|properties| properties.get(key)) {
+            Some(property_schema) => check(property_schema, value, &child(key), violations),
+            None if closed => violations.push(format!("{}: unknown argument", child(key))),
+            None => {}
+        }
+    }
+}
+
+fn has_type(value: &Value, kind: &str) -> bool {
+    match kind {
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "boolean" => value.is_boolean(),
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "null" => value.is_null(),
+        _ => true,
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn describe(schema: &Value) -> String {
+    let kind = schema
+        .get("type")
+        .and_then(Value::as_str)
+        .unwrap_or("value");
+    match (
+        schema.get("enum").and_then(Value::as_array),
+        schema.get("items"),
+    ) {
+        (Some(options), _) => list_values(options),
+        (None, Some(items)) => format!("{kind} of {}", describe(items)),
+        (None, None) => kind.to_string(),
+    }
+}
+
+fn describe_value(value: &Value) -> String {
+    match value {
+        Value::String(_) | Value::Number(_) | Value::Bool(_) => value.to_string(),
+        _ => type_name(value).to_string(),
+    }
+}
+
+fn list_values(options: &[Value]) -> String {
+    options
+        .iter()
+        .map(Value::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tool_registry::{input_schema, tools_list};
+    use serde_json::json;
+
+    fn violations(tool: &str, arguments: Value) -> Vec<String> {
+        let schema = input_schema(&tools_list(), tool).expect("known tool");
+        validate_arguments(&schema, &arguments)
+            .err()
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn reports_every_violation_with_its_argument_path() {
+        assert_eq!(violations("rav_open_file", json!({})), ["path: required"]);
+        assert_eq!(
+            violations(
+                "rav_set_canvas_size",
+                json!({ "mode": "stretch", "width": "640", "height": 0, "extra": true })
+            ),
+            [
+                "extra: unknown argument",
+                "height: must be at least 1, got 0",
+                "mode: must be one of \"auto\", \"fixed\"",
+                "width: expected number, got string",
+            ]
+        );
+        assert_eq!(
+            violations("rav_export_demo_visual", json!({ "output_path": "/tmp/a.html", "selection": "some" })),
+            ["selection: expected \"all\", \"changed\", \"none\" or array of string, got \"some\""]
+        );
+        assert_eq!(
+            violations(
+                "rav_export_demo_visual",
+                json!({ "output_path": "/tmp/a.html", "selection": ["a", 2] })
+            ),
+            ["selection: expected \"all\", \"changed\", \"none\" or array of string, got array"]
+        );
+        assert_eq!(
+            violations("rav_status", json!([])),
+            ["arguments: expected object, got array"]
+        );
+    }
+
+    #[test]
+    fn accepts_valid_arguments_and_untyped_values() {
+        assert!(violations("rav_vm_set", json!({ "path": "volume", "value": 0.5 })).is_empty());
+        assert!(violations(
+            "rav_export_demo_visual",
+            json!({ "output_path": "/tmp/a.html", "selection": ["vm:volume:number"] })
+        )
+        .is_empty());
+    }
+}
@@ -0,0 +1,191 @@
+use serde_json::Value;
+use std::collections::HashMap;
+use tokio::sync::{mpsc, oneshot};
+use tokio::task::JoinSet;
+
+use crate::bridge::Bridge;
+use crate::rpc::{handle_request, Frame};
+use crate::session::Session;
+use crate::tool_registry::ordering_lane;
+
+/// Runs each request on its own task and sends the response, carrying the request id, to a
+/// single writer. Calls in the same ordering lane wait for the previous call in that lane.
+pub struct Dispatcher {
+    bridge: Bridge,
+    session: Session,
+    responses: mpsc::UnboundedSender<Value>,
+    lanes: HashMap<&'static str, oneshot::Receiver<()>>,
+    tasks: JoinSet<()>,
+}
+
+impl Dispatcher {
+    pub fn new(bridge: Bridge, session: Session, responses: mpsc::UnboundedSender<Value>) -> Self {
+        Self {
+            bridge,
+            session,
+            responses,
+            lanes: HashMap::new(),
+            tasks: JoinSet::new(),
+        }
+    }
+
+    /// Dispatches a frame's requests. A batch is answered with one array once every member
+    /// has finished; its members still follow their ordering lanes.
+    pub fn dispatch_frame(&mut self, frame: Frame) {
+        if !frame.batch {
+            for error in &frame.errors {
+                let _ = self.responses.send(error.clone());
+            }
+            for request in frame.requests.iter().cloned() {
+                let responses = self.responses.clone();
+                self.spawn_request(request, move |response| {
+                    let _ = responses.send(response);
+                });
+            }
+            return;
+        }
+
+        let replies: Vec<oneshot::Receiver<Value>> = frame
+            .requests
+            .iter()
+            .cloned()
+            .map(|request| {
+                let (reply, response) = oneshot::channel();
+                self.spawn_request(request, move |value| {
+                    let _ = reply.send(value);
+                });
+                response
+            })
+            .collect();
+        let responses = self.responses.clone();
+        self.tasks.spawn(async move {
+            let mut answers = frame.errors.clone();
+            for reply in replies {
+                if let Ok(answer) = reply.await {
+                    answers.push(answer);
+                }
+            }
+            if let Some(batch) = frame.reply(answers) {
+                let _ = responses.send(batch);
+            }
+        });
+    }
+
+    fn spawn_request(&mut self, request: Value, reply: impl FnOnce(Value) + Send + 'static) {
+        let id = request.get("id").cloned().unwrap_or(Value::Null);
+        self.session.begin_request(&id);
+        let (done, next) = oneshot::channel::<()>();
+        let previous = request_lane(&request).and_then(|lane| self.lanes.insert(lane, next));
+        let bridge = self.bridge.clone();
+        let session = self.session.clone();
+        self.tasks.spawn(async move {
+            if let Some(previous) = previous {
+                let _ = previous.await;
+            }
+            let response = handle_request(&bridge, &session, request).await;
+            session.finish_request(&id);
+            reply(response);
+            drop(done);
+        });
+    }
+
+    /// Reaps one finished task; `None` once nothing is running.
+    pub async fn join_next(&mut self) -> Option<()> {
+        self.tasks.join_next().await.map(|_| ())
+    }
+
+    pub fn is_idle(&self) -> bool {
+        self.tasks.is_empty()
+    }
+}
+
+fn request_lane(request: &Value) -> Option<&'static str> {
+    if request.get("method").and_then(Value::as_str) != Some("tools/call") {
+        return None;
+    }
+    request
+        .get("params")
+        .and_then(|params| params.get("name"))
+        .and_then(Value::as_str)
+        .and_then(ordering_lane)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rpc::split_frame;
+    use serde_json::json;
+    use std::time::Duration;
+
+    fn tool_call(id: u64, name: &str, arguments: Value) -> Value {
+        json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": "tools/call",
+            "params": { "name": name, "arguments": arguments }
+        })
+    }
+
+    async fn next_command(app: &mut mpsc::UnboundedReceiver<String>) -> Value {
+        serde_json::from_str(&app.recv().await.expect("app command")).expect("json")
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn slow_calls_do_not_block_others_and_lanes_keep_arrival_order() {
+        let bridge = Bridge::new(Duration::from_secs(5));
+        let (app_tx, mut app) = mpsc::unbounded_channel::<String>();
+        bridge.register_connection(app_tx).await;
+        let (responses_tx, mut responses) = mpsc::unbounded_channel();
+        let mut dispatcher = Dispatcher::new(bridge.clone(), Session::default(), responses_tx);
+
+        dispatcher.dispatch_frame(split_frame(tool_call(
+            1,
+            "rav_open_file",
+            json!({ "path": "/tmp/a.riv" }),
+        )));
+        dispatcher.dispatch_frame(split_frame(tool_call(
+            2,
+            "rav_set_editor_code",
+            json!({ "code": "({})" }),
+        )));
+        dispatcher.dispatch_frame(split_frame(tool_call(3, "rav_apply_code", json!({}))));
+        dispatcher.dispatch_frame(split_frame(
+            json!({ "jsonrpc": "2.0", "id": 4, "method": "ping" }),
+        ));
+        dispatcher.dispatch_frame(split_frame(tool_call(
+            5,
+            "rav_vm_set",
+            json!({ "path": "volume", "value": 1 }),
+        )));
+
+        assert_eq!(responses.recv().await.expect("ping")["id"], 4);
+        let open_file = next_command(&mut app).await;
+        assert_eq!(open_file["command"], "rav_open_file");
+        assert!(app.try_recv().is_err(), "lane waits for rav_open_file");
+
+        bridge
+            .handle_incoming_message(json!({ "id": open_file["id"], "result": { "ok": true } }))
+            .await;
+        assert_eq!(responses.recv().await.expect("open")["id"], 1);
+        let set_code = next_command(&mut app).await;
+        assert_eq!(set_code["command"], "rav_set_editor_code");
+        tokio::task::yield_now().await;
+        assert!(app.try_recv().is_err(), "apply waits for set");
+
+        bridge
+            .handle_incoming_message(json!({ "id": set_code["id"], "result": {} }))
+            .await;
+        let apply = next_command(&mut app).await;
+        assert_eq!(apply["command"], "rav_apply_code");
+        tokio::task::yield_now().await;
+        assert!(
+            app.try_recv().is_err(),
+            "input writes wait for the new instance"
+        );
+
+        bridge
+            .handle_incoming_message(json!({ "id": apply["id"], "result": {} }))
+            .await;
+        assert_eq!(next_command(&mut app).await["command"], "rav_vm_set");
+    }
+}
@@ -0,0 +1,370 @@
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{broadcast, mpsc};
+
+use crate::bridge::Bridge;
+use crate::rpc::{handle_frame, jsonrpc_error};
+use crate::transport::app_event_notifications;
+use crate::transport::http_message::{
+    empty_response, json_response, read_request, text_response, HttpRequest,
+};
+use crate::transport::http_sessions::{create_session, find_session, touch_session, Sessions};
+
+const MCP_PATH: &str = "/mcp";
+const SSE_KEEP_ALIVE: Duration = Duration::from_secs(15);
+
+/// Serves the MCP Streamable HTTP transport on 127.0.0.1: POST for messages, GET for an SSE
+/// notification stream and DELETE to end a session.
+pub async fn run_http_server(bridge: Bridge, http_port: u16) -> Result<()> {
+    let listener = TcpListener::bind(("127.0.0.1", http_port))
+        .await
+        .with_context(|| format!("failed to bind MCP HTTP server on 127.0.0.1:{http_port}"))?;
+    eprintln!("[rav-mcp] Streamable HTTP listening on http://127.0.0.1:{http_port}{MCP_PATH}");
+    serve_http(listener, bridge).await
+}
+
+pub async fn serve_http(listener: TcpListener, bridge: Bridge) -> Result<()> {
+    let sessions = Sessions::default();
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let bridge = bridge.clone();
+        let sessions = Arc::clone(&sessions);
+        tokio::spawn(async move {
+            if let Err(error) = serve_connection(stream, bridge, sessions).await {
+                eprintln!("[rav-mcp] HTTP connection error: {error}");
+            }
+        });
+    }
+}
+
+async fn serve_connection(stream: TcpStream, bridge: Bridge, sessions: Sessions) -> Result<()> {
+    let mut reader = BufReader::new(stream);
+    loop {
+        let request = match read_request(&mut reader).await {
+            Ok(Some(request)) => request,
+            Ok(None) => return Ok(()),
+            Err(error) => {
+                let response = text_response(400, &error.to_string());
+                reader.get_mut().write_all(&response).await?;
+                return Ok(());
+            }
+        };
+        let keep_alive = !request
+            .header("connection")
+            .is_some_and(|value| value.eq_ignore_ascii_case("close"));
+
+        if request.path.split('?').next() != Some(MCP_PATH) {
+            reader
+                .get_mut()
+                .write_all(&text_response(404, "Not found"))
+                .await?;
+        } else if !is_allowed_origin(request.header("origin")) {
+            reader
+                .get_mut()
+                .write_all(&text_response(403, "Origin not allowed"))
+                .await?;
+        } else if request.method == "GET" {
+            return stream_notifications(reader.into_inner(), &request, &bridge, &sessions).await;
+        } else if request.method == "POST" {
+            if !handle_post(reader.get_mut(), &request, &bridge, &sessions).await? {
+                return Ok(());
+            }
+        } else {
+            let response = match request.method.as_str() {
+                "DELETE" => handle_delete(&request, &sessions).await,
+                _ => text_response(405, "Method not allowed"),
+            };
+            reader.get_mut().write_all(&response).await?;
+        }
+        if !keep_alive {
+            return Ok(());
+        }
+    }
+}
+
+/// Answers with JSON, or with an SSE stream carrying the call's progress and then its response
+/// when the client accepts one and sent a progress token. Returns false once the connection is
+/// used up by a stream.
+async fn handle_post(
+    stream: &mut TcpStream,
+    request: &HttpRequest,
+    bridge: &Bridge,
+    sessions: &Sessions,
+) -> Result<bool> {
+    let message = match serde_json::from_slice::<Value>(&request.body) {
+        Ok(message) => message,
+        Err(error) => {
+            let error = jsonrpc_error(Value::Null, -32700, format!("Parse error: {error}"));
+            stream.write_all(&json_response(400, &error, None)).await?;
+            return Ok(true);
+        }
+    };
+
+    let is_initialize = message.get("method").and_then(Value::as_str) == Some("initialize");
+    let (session_id, session) = if is_initialize {
+        create_session(sessions).await
+    } else {
+        match find_session(request, sessions).await {
+            Ok(found) => found,
+            Err(response) => {
+                stream.write_all(&response).await?;
+                return Ok(true);
+            }
+        }
+    };
+
+    let wants_progress =
+        message.pointer("/params/_meta/progressToken").is_some() && accepts_event_stream(request);
+    if !wants_progress {
+        let response = match handle_frame(bridge, &session, message).await {
+            Some(response) => json_response(200, &response, Some(&session_id)),
+            None => empty_response(202),
+        };
+        stream.write_all(&response).await?;
+        return Ok(true);
+    }
+
+    let headers = format!("HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nCache-Control: no-cache\r\nMcp-Session-Id: {session_id}\r\nConnection: close\r\n\r\n");
+    stream.write_all(headers.as_bytes()).await?;
+    // The channel lives only as long as this request, so nothing queues up for absent streams.
+    let (notifications_tx, mut notifications) = mpsc::unbounded_channel();
+    let session = session.routing_notifications_to(notifications_tx);
+    let response = handle_frame(bridge, &session, message);
+    tokio::pin!(response);
+    let response = loop {
+        tokio::select! {
+            Some(notification) = notifications.recv() => write_event(stream, &notification).await?,
+            response = &mut response => break response,
+        }
+    };
+    while let Ok(notification) = notifications.try_recv() {
+        write_event(stream, &notification).await?;
+    }
+    if let Some(response) = response {
+        write_event(stream, &response).await?;
+    }
+    Ok(false)
+}
+
+async fn handle_delete(request: &HttpRequest, sessions: &Sessions) -> Vec<u8> {
+    match find_session(request, sessions).await {
+        Ok((session_id, _)) => {
+            sessions.lock().await.remove(&session_id);
+            empty_response(204)
+        }
+        Err(response) => response,
+    }
+}
+
+async fn stream_notifications(
+    mut stream: TcpStream,
+    request: &HttpRequest,
+    bridge: &Bridge,
+    sessions: &Sessions,
+) -> Result<()> {
+    if !accepts_event_stream(request) {
+        stream
+            .write_all(&text_response(
+                406,
+                "GET requires Accept: text/event-stream",
+            ))
+            .await?;
+        return Ok(());
+    }
+    let (session_id, session) = match find_session(request, sessions).await {
+        Ok(found) => found,
+        Err(response) => {
+            stream.write_all(&response).await?;
+            return Ok(());
+        }
+    };
+
+    let mut app_events = bridge.subscribe_events();
+    stream
+        .write_all(
+            b"HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nCache-Control: no-cache\r\nConnection: keep-alive\r\n\r\n",
+        )
+        .await?;
+    loop {
+        let event = match tokio::time::timeout(SSE_KEEP_ALIVE, app_events.recv()).await {
+            Err(_) => {
+                stream.write_all(b": keep-alive\n\n").await?;
+                // An open stream keeps its session from expiring.
+                if !touch_session(sessions, &session_id).await {
+                    return Ok(());
+                }
+                continue;
+            }
+            Ok(Err(broadcast::error::RecvError::Lagged(_))) => continue,
+            Ok(Err(broadcast::error::RecvError::Closed)) => return Ok(()),
+            Ok(Ok(event)) => event,
+        };
+        if !sessions.lock().await.contains_key(&session_id) {
+            return Ok(());
+        }
+        for notification in app_event_notifications(&session, &event) {
+            write_event(&mut stream, &notification).await?;
+        }
+    }
+}
+
+async fn write_event(stream: &mut TcpStream, message: &Value) -> Result<()> {
+    let event = format!("event: message\ndata: {message}\n\n");
+    stream.write_all(event.as_bytes()).await?;
+    Ok(())
+}
+
+fn accepts_event_stream(request: &HttpRequest) -> bool {
+    request
+        .header("accept")
+        .is_some_and(|accept| accept.contains("text/event-stream"))
+}
+
+/// Browsers always send Origin; only local pages may reach the server (DNS rebinding guard).
+fn is_allowed_origin(origin: Option<&str>) -> bool {
+    let Some(origin) = origin else {
+        return true;
+    };
+    let authority = origin
+        .split_once("://")
+        .map_or(origin, |(_, rest)| rest)
+        .split('/')
+        .next()
+        .unwrap_or_default();
+    let host = match authority.strip_prefix('[') {
+        Some(ipv6) => ipv6.split(']').next(),
+        None => authority.split(':').next(),
+    };
+    matches!(host, Some("localhost" | "127.0.0.1" | "::1"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tokio::io::AsyncReadExt;
+
+    async fn send(address: std::net::SocketAddr, request: String) -> String {
+        let mut stream = TcpStream::connect(address).await.expect("connect");
+        stream.write_all(request.as_bytes()).await.expect("write");
+        let mut response = String::new();
+        stream
+            .read_to_string(&mut response)
+            .await
+            .expect("response");
+        response
+    }
+
+    fn post(body: &str, session_id: Option<&str>) -> String {
+        let session_header = session_id
+            .map(|session_id| format!("Mcp-Session-Id: {session_id}\r\n"))
+            .unwrap_or_default();
+        format!(
+            "POST /mcp HTTP/1.1\r\nHost: 127.0.0.1\r\nConnection: close\r\nContent-Type: application/json\r\n{session_header}Content-Length: {}\r\n\r\n{body}",
+            body.len()
+        )
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn sessions_are_issued_on_initialize_and_required_afterwards() {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await.expect("bind");
+        let address = listener.local_addr().expect("address");
+        tokio::spawn(serve_http(listener, Bridge::new(Duration::from_millis(10))));
+
+        let initialize = send(
+            address,
+            post(
+                r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{}}"#,
+                None,
+            ),
+        )
+        .await;
+        assert!(initialize.starts_with("HTTP/1.1 200 OK"), "{initialize}");
+        let session_id = initialize
+            .lines()
+            .find_map(|line| line.strip_prefix("Mcp-Session-Id: "))
+            .expect("session id")
+            .to_string();
+
+        let ping = r#"{"jsonrpc":"2.0","id":2,"method":"ping"}"#;
+        let pong = send(address, post(ping, Some(&session_id))).await;
+        assert!(
+            pong.ends_with(r#"{"id":2,"jsonrpc":"2.0","result":{}}"#),
+            "{pong}"
+        );
+        let initialized = r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#;
+        assert!(send(address, post(initialized, Some(&session_id)))
+            .await
+            .starts_with("HTTP/1.1 202"));
+        assert!(send(address, post(ping, None))
+            .await
+            .starts_with("HTTP/1.1 400"));
+
+        let delete = format!(
+            "DELETE /mcp HTTP/1.1\r\nConnection: close\r\nMcp-Session-Id: {session_id}\r\n\r\n"
+        );
+        assert!(send(address, delete).await.starts_with("HTTP/1.1 204"));
+        assert!(send(address, post(ping, Some(&session_id)))
+            .await
+            .starts_with("HTTP/1.1 404"));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn progress_streams_on_the_post_response_that_asked_for_it() {
+        let bridge = Bridge::new(Duration::from_millis(500));
+        let (app_tx, mut app_rx) = mpsc::unbounded_channel::<String>();
+        bridge.register_connection(app_tx).await;
+        let app = bridge.clone();
+        tokio::spawn(async move {
+            while let Some(message) = app_rx.recv().await {
+                let id = serde_json::from_str::<Value>(&message).expect("request")["id"].clone();
+                let progress = json!({ "bridgeEvent": crate::bridge::PROGRESS_EVENT, "id": id, "progress": 1 });
+                app.handle_incoming_message(progress).await;
+                app.handle_incoming_message(json!({ "id": id, "result": {} }))
+                    .await;
+            }
+        });
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await.expect("bind");
+        let address = listener.local_addr().expect("address");
+        tokio::spawn(serve_http(listener, bridge));
+
+        let initialize = r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{}}"#;
+        let initialize = send(address, post(initialize, None)).await;
+        let session_id = initialize
+            .lines()
+            .find_map(|line| line.strip_prefix("Mcp-Session-Id: "))
+            .expect("session id");
+        let call = r#"{"jsonrpc":"2.0","id":2,"method":"tools/call","params":{"name":"rav_status","arguments":{},"_meta":{"progressToken":"tok"}}}"#;
+
+        let plain = send(address, post(call, Some(session_id))).await;
+        assert!(plain.contains("Content-Type: application/json"), "{plain}");
+        assert!(!plain.contains("notifications/progress"));
+
+        let streamed = post(call, Some(session_id)).replacen(
+            "Content-Type: application/json\r\n",
+            "Content-Type: application/json\r\nAccept: application/json, text/event-stream\r\n",
+            1,
+        );
+        let streamed = send(address, streamed).await;
+        assert!(
+            streamed.contains("Content-Type: text/event-stream"),
+            "{streamed}"
+        );
+        let progress = streamed.find(r#""progressToken":"tok""#).expect("progress");
+        let result = streamed.find(r#""id":2"#).expect("result");
+        assert!(progress < result, "{streamed}");
+    }
+
+    #[test]
+    fn only_local_origins_are_allowed() {
+        assert!(is_allowed_origin(None));
+        assert!(is_allowed_origin(Some("http://localhost:5173")));
+        assert!(is_allowed_origin(Some("http://[::1]:8080")));
+        assert!(!is_allowed_origin(Some("https://evil.example")));
+        assert!(!is_allowed_origin(Some("http://127.0.0.1.evil.example")));
+    }
+}
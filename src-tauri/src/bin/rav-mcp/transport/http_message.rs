@@ -0,0 +1,110 @@
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, BufReader};
+use tokio::net::TcpStream;
+
+use crate::transport::MAX_BODY_BYTES;
+
+pub struct HttpRequest {
+    pub method: String,
+    pub path: String,
+    pub headers: HashMap<String, String>,
+    pub body: Vec<u8>,
+}
+
+impl HttpRequest {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).map(String::as_str)
+    }
+}
+
+pub async fn read_request(reader: &mut BufReader<TcpStream>) -> Result<Option<HttpRequest>> {
+    let mut request_line = String::new();
+    if reader.read_line(&mut request_line).await? == 0 {
+        return Ok(None);
+    }
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(path)) = (parts.next(), parts.next()) else {
+        anyhow::bail!("Malformed request line");
+    };
+
+    let mut headers = HashMap::new();
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).await? == 0 {
+            anyhow::bail!("Connection closed inside headers");
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.insert(name.trim().to_ascii_lowercase(), value.trim().to_string());
+        }
+    }
+
+    let length = headers
+        .get("content-length")
+        .map(|value| value.parse::<usize>())
+        .transpose()
+        .context("Invalid Content-Length header")?
+        .unwrap_or(0);
+    if length > MAX_BODY_BYTES {
+        anyhow::bail!("Request body exceeds {MAX_BODY_BYTES} bytes");
+    }
+    let mut body = vec![0_u8; length];
+    reader.read_exact(&mut body).await?;
+
+    Ok(Some(HttpRequest {
+        method: method.to_ascii_uppercase(),
+        path: path.to_string(),
+        headers,
+        body,
+    }))
+}
+
+pub fn json_response(status: u16, body: &Value, session_id: Option<&str>) -> Vec<u8> {
+    let body = body.to_string();
+    let session_header = session_id
+        .map(|session_id| format!("Mcp-Session-Id: {session_id}\r\n"))
+        .unwrap_or_default();
+    format!(
+        "HTTP/1.1 {status} {}\r\nContent-Type: application/json\r\n{session_header}Content-Length: {}\r\n\r\n{body}",
+        status_text(status),
+        body.len()
+    )
+    .into_bytes()
+}
+
+pub fn text_response(status: u16, message: &str) -> Vec<u8> {
+    let body = json!({ "error": message }).to_string();
+    format!(
+        "HTTP/1.1 {status} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{body}",
+        status_text(status),
+        body.len()
+    )
+    .into_bytes()
+}
+
+pub fn empty_response(status: u16) -> Vec<u8> {
+    format!(
+        "HTTP/1.1 {status} {}\r\nContent-Length: 0\r\n\r\n",
+        status_text(status)
+    )
+    .into_bytes()
+}
+
+fn status_text(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        202 => "Accepted",
+        204 => "No Content",
+        400 => "Bad Request",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        406 => "Not Acceptable",
+        _ => "Error",
+    }
+}
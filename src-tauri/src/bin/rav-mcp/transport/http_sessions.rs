@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+use crate::session::Session;
+use crate::transport::http_message::{text_response, HttpRequest};
+
+const SESSION_HEADER: &str = "mcp-session-id";
+const SESSION_IDLE_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+const MAX_SESSIONS: usize = 32;
+
+pub type Sessions = Arc<Mutex<HashMap<String, HttpSession>>>;
+
+pub struct HttpSession {
+    session: Session,
+    last_active: Instant,
+}
+
+/// Issues a session id for `initialize`, making room for it first.
+pub async fn create_session(sessions: &Sessions) -> (String, Session) {
+    let session_id = Uuid::new_v4().to_string();
+    let session = Session::default();
+    let mut sessions = sessions.lock().await;
+    prune_sessions(&mut sessions, Instant::now());
+    sessions.insert(
+        session_id.clone(),
+        HttpSession {
+            session: session.clone(),
+            last_active: Instant::now(),
+        },
+    );
+    (session_id, session)
+}
+
+/// Marks the session active; false once it has ended or expired.
+pub async fn touch_session(sessions: &Sessions, session_id: &str) -> bool {
+    match sessions.lock().await.get_mut(session_id) {
+        Some(entry) => {
+            entry.last_active = Instant::now();
+            true
+        }
+        None => false,
+    }
+}
+
+pub async fn find_session(
+    request: &HttpRequest,
+    sessions: &Sessions,
+) -> Result<(String, Session), Vec<u8>> {
+    let Some(session_id) = request.header(SESSION_HEADER) else {
+        return Err(text_response(400, "Missing Mcp-Session-Id header"));
+    };
+    let session = sessions.lock().await.get_mut(session_id).map(|entry| {
+        entry.last_active = Instant::now();
+        entry.session.clone()
+    });
+    session
+        .map(|session| (session_id.to_string(), session))
+        .ok_or_else(|| text_response(404, "Unknown or expired session"))
+}
+
+/// Drops sessions idle for longer than the timeout, then the least recently used ones so a new
+/// session fits under the cap.
+fn prune_sessions(sessions: &mut HashMap<String, HttpSession>, now: Instant) {
+    sessions.retain(|_, entry| now.duration_since(entry.last_active) < SESSION_IDLE_TIMEOUT);
+    while sessions.len() >= MAX_SESSIONS {
+        let Some(oldest) = sessions
+            .iter()
+            .min_by_key(|(_, entry)| entry.last_active)
+            .map(|(session_id, _)| session_id.clone())
+        else {
+            break;
+        };
+        sessions.remove(&oldest);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn idle_and_excess_sessions_are_pruned() {
+        let now = Instant::now();
+        let entry = |idle_secs: u64| HttpSession {
+            session: Session::default(),
+            last_active: now - Duration::from_secs(idle_secs),
+        };
+        let mut sessions: HashMap<String, HttpSession> = (0..MAX_SESSIONS as u64)
+            .map(|index| (format!("s{index}"), entry(index)))
+            .collect();
+        sessions.insert("stale".into(), entry(SESSION_IDLE_TIMEOUT.as_secs() + 1));
+
+        prune_sessions(&mut sessions, now);
+
+        assert_eq!(sessions.len(), MAX_SESSIONS - 1);
+        assert!(!sessions.contains_key("stale"));
+        assert!(!sessions.contains_key(&format!("s{}", MAX_SESSIONS - 1)));
+        assert!(sessions.contains_key("s0"));
+    }
+}
@@ -0,0 +1,23 @@
+use serde_json::{json, Value};
+
+use crate::bridge::TOOL_CATALOG_EVENT;
+use crate::resources::resource_update_notifications;
+use crate::session::Session;
+
+pub mod dispatch;
+pub mod http;
+pub mod http_message;
+pub mod http_sessions;
+pub mod stdio;
+pub mod websocket;
+
+/// Largest JSON-RPC message body either transport reads.
+pub const MAX_BODY_BYTES: usize = 4 * 1024 * 1024;
+
+/// MCP notifications a session should receive for one app event.
+pub fn app_event_notifications(session: &Session, event: &Value) -> Vec<Value> {
+    if event.get("bridgeEvent").and_then(Value::as_str) == Some(TOOL_CATALOG_EVENT) {
+        return vec![json!({ "jsonrpc": "2.0", "method": "notifications/tools/list_changed" })];
+    }
+    resource_update_notifications(&session.subscriptions(), event)
+}
@@ -0,0 +1,260 @@
+use anyhow::Result;
+use serde_json::Value;
+use tokio::io::{
+    self, AsyncBufRead, AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader, BufWriter,
+};
+use tokio::sync::{broadcast, mpsc};
+
+use crate::bridge::Bridge;
+use crate::rpc::{handle_notification, jsonrpc_error, split_frame};
+use crate::session::Session;
+use crate::transport::dispatch::Dispatcher;
+use crate::transport::{app_event_notifications, MAX_BODY_BYTES};
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum StdioMessageFormat {
+    ContentLength,
+    JsonLine,
+}
+
+/// A frame's JSON, or why it could not be parsed so the caller can answer `-32700`.
+pub type ParsedFrame = std::result::Result<Value, String>;
+
+/// Lines are read as bytes, so invalid UTF-8 becomes a parse error instead of ending the server.
+pub async fn read_message<R: AsyncBufRead + Unpin>(
+    reader: &mut R,
+) -> Result<Option<(ParsedFrame, StdioMessageFormat)>> {
+    let mut content_length: Option<std::result::Result<usize, String>> = None;
+    let mut in_headers = false;
+
+    loop {
+        let mut line = Vec::new();
+        let limit = MAX_BODY_BYTES as u64 + 1;
+        if (&mut *reader)
+            .take(limit)
+            .read_until(b'\n', &mut line)
+            .await?
+            == 0
+        {
+            return Ok(None);
+        }
+        if line.len() > MAX_BODY_BYTES && line.last() != Some(&b'\n') {
+            skip_line(reader).await?;
+            let format = if in_headers {
+                StdioMessageFormat::ContentLength
+            } else {
+                StdioMessageFormat::JsonLine
+            };
+            let error = format!("Line exceeds {MAX_BODY_BYTES} bytes");
+            return Ok(Some((Err(error), format)));
+        }
+        let text = String::from_utf8_lossy(&line);
+        let trimmed = text.trim();
+
+        if !in_headers {
+            if trimmed.is_empty() {
+                continue;
+            }
+            let is_header = !trimmed.starts_with(['{', '[']) && trimmed.contains(':');
+            if !is_header {
+                let parsed =
+                    serde_json::from_slice::<Value>(&line).map_err(|error| error.to_string());
+                return Ok(Some((parsed, StdioMessageFormat::JsonLine)));
+            }
+            in_headers = true;
+        }
+
+        if trimmed.is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = trimmed.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = Some(
+                    value
+                        .trim()
+                        .parse::<usize>()
+                        .map_err(|_| format!("invalid Content-Length header: {}", value.trim())),
+                );
+            }
+        }
+    }
+
+    let length = match content_length {
+        Some(Ok(length)) => length,
+        Some(Err(error)) => return Ok(Some((Err(error), StdioMessageFormat::ContentLength))),
+        None => {
+            let error = "missing Content-Length header".to_string();
+            return Ok(Some((Err(error), StdioMessageFormat::ContentLength)));
+        }
+    };
+    if length > MAX_BODY_BYTES {
+        // Skip the body without buffering it so the next frame still lines up.
+        io::copy(&mut reader.take(length as u64), &mut io::sink()).await?;
+        let error = format!("Content-Length {length} exceeds {MAX_BODY_BYTES} bytes");
+        return Ok(Some((Err(error), StdioMessageFormat::ContentLength)));
+    }
+    let mut payload = vec![0_u8; length];
+    reader.read_exact(&mut payload).await?;
+    let parsed = serde_json::from_slice::<Value>(&payload).map_err(|error| error.to_string());
+    Ok(Some((parsed, StdioMessageFormat::ContentLength)))
+}
+
+/// Discards the rest of an oversized line without buffering it.
+async fn skip_line<R: AsyncBufRead + Unpin>(reader: &mut R) -> Result<()> {
+    loop {
+        let available = reader.fill_buf().await?;
+        let Some(end) = available.iter().position(|byte| *byte == b'\n') else {
+            if available.is_empty() {
+                return Ok(());
+            }
+            let skipped = available.len();
+            reader.consume(skipped);
+            continue;
+        };
+        reader.consume(end + 1);
+        return Ok(());
+    }
+}
+
+pub async fn write_message(
+    writer: &mut BufWriter<tokio::io::Stdout>,
+    value: &Value,
+    format: StdioMessageFormat,
+) -> Result<()> {
+    let payload = serde_json::to_vec(value)?;
+    match format {
+        StdioMessageFormat::ContentLength => {
+            let header = format!("Content-Length: {}\r\n\r\n", payload.len());
+            writer.write_all(header.as_bytes()).await?;
+            writer.write_all(&payload).await?;
+        }
+        StdioMessageFormat::JsonLine => {
+            writer.write_all(&payload).await?;
+            writer.write_all(b"\n").await?;
+        }
+    }
+    writer.flush().await?;
+    Ok(())
+}
+
+pub async fn run_stdio_server(bridge: Bridge) -> Result<()> {
+    let stdout = io::stdout();
+    let mut writer = BufWriter::new(stdout);
+    let (outgoing_tx, mut outgoing) = mpsc::unbounded_channel::<Value>();
+    let session = Session::with_notifications(outgoing_tx.clone());
+    let mut app_events = bridge.subscribe_events();
+    let mut incoming = spawn_stdin_reader();
+    let mut dispatcher = Dispatcher::new(bridge, session.clone(), outgoing_tx);
+    // Notifications go out in whatever framing the client last used.
+    let mut format = StdioMessageFormat::JsonLine;
+
+    loop {
+        tokio::select! {
+            message = incoming.recv() => {
+                let Some((message, incoming_format)) = message.unwrap_or(Ok(None))? else {
+                    break;
+                };
+                format = incoming_format;
+
+                let message = match message {
+                    Ok(message) => message,
+                    Err(error) => {
+                        let error = jsonrpc_error(Value::Null, -32700, format!("Parse error: {error}"));
+                        write_message(&mut writer, &error, format).await?;
+                        continue;
+                    }
+                };
+                let frame = split_frame(message);
+                for notification in &frame.notifications {
+                    handle_notification(&session, notification);
+                }
+                dispatcher.dispatch_frame(frame);
+            }
+            Some(message) = outgoing.recv() => {
+                write_message(&mut writer, &message, format).await?;
+            }
+            Some(()) = dispatcher.join_next(), if !dispatcher.is_idle() => {}
+            event = app_events.recv() => {
+                let event = match event {
+                    Ok(event) => event,
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                };
+                for notification in app_event_notifications(&session, &event) {
+                    write_message(&mut writer, &notification, format).await?;
+                }
+            }
+        }
+    }
+
+    // Stdin closed: answer everything already read before exiting.
+    loop {
+        tokio::select! {
+            Some(message) = outgoing.recv() => write_message(&mut writer, &message, format).await?,
+            finished = dispatcher.join_next() => if finished.is_none() { break },
+        }
+    }
+    while let Ok(message) = outgoing.try_recv() {
+        write_message(&mut writer, &message, format).await?;
+    }
+
+    Ok(())
+}
+
+type IncomingMessage = Result<Option<(ParsedFrame, StdioMessageFormat)>>;
+
+/// Reads stdin on its own task so app events can be written while waiting for input.
+fn spawn_stdin_reader() -> mpsc::Receiver<IncomingMessage> {
+    let (tx, rx) = mpsc::channel(16);
+    tokio::spawn(async move {
+        let mut reader = BufReader::new(io::stdin());
+        loop {
+            let message = read_message(&mut reader).await;
+            let done = !matches!(message, Ok(Some(_)));
+            if tx.send(message).await.is_err() || done {
+                break;
+            }
+        }
+    });
+    rx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn read_all(input: &[u8]) -> Vec<(ParsedFrame, StdioMessageFormat)> {
+        let mut reader = BufReader::new(input);
+        let mut messages = Vec::new();
+        while let Some(message) = read_message(&mut reader).await.expect("read") {
+            messages.push(message);
+        }
+        messages
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn invalid_utf8_and_oversized_frames_are_parse_errors_not_fatal() {
+        let ping = br#"{"jsonrpc":"2.0","id":1,"method":"ping"}"#;
+        let mut input = b"{\"id\":\"\xff\"}\n".to_vec();
+        input.extend(std::iter::repeat_n(b'[', MAX_BODY_BYTES + 1));
+        input.extend_from_slice(b"]]]\n");
+        input.extend_from_slice(
+            format!("Content-Length: {}\r\n\r\n", MAX_BODY_BYTES + 1).as_bytes(),
+        );
+        input.extend(std::iter::repeat_n(b' ', MAX_BODY_BYTES + 1));
+        input.extend_from_slice(format!("Content-Length: {}\r\n\r\n", ping.len()).as_bytes());
+        input.extend_from_slice(ping);
+        let messages = read_all(&input).await;
+
+        assert_eq!(messages.len(), 4);
+        assert!(messages[0].0.is_err());
+        assert!(messages[0].1 == StdioMessageFormat::JsonLine);
+        let oversized_line = messages[1].0.as_ref().expect_err("oversized line");
+        assert!(oversized_line.contains("exceeds"), "{oversized_line}");
+        assert!(messages[1].1 == StdioMessageFormat::JsonLine);
+        let oversized = messages[2].0.as_ref().expect_err("oversized");
+        assert!(oversized.contains("exceeds"), "{oversized}");
+        assert_eq!(messages[3].0.as_ref().expect("ping")["method"], "ping");
+    }
+}
@@ -66,6 +66,9 @@ pub async fn run_websocket_bridge(bridge: Bridge, ws_port: u16) -> Result<()> {
                 return;
             };
             let connection_id = bridge_clone.register_bridge_peer(role, tx.clone()).await;
+            if role == BridgePeerRole::App {
+                bridge_clone.publish_tool_catalog(&first_value).await;
+            }
 
             match role {
                 BridgePeerRole::App => eprintln!("[rav-mcp] RAV connected"),
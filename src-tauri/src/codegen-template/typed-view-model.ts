@@ -0,0 +1,76 @@
+type ValueAccessor<T> = { value: T };
+type TriggerAccessor = { trigger(): void };
+type ListAccessor = { instanceAt(index: number): RiveViewModelInstance | null };
+type PropertyKindKey = keyof typeof VIEW_MODEL_PROPERTY_KINDS;
+type PropertyKind = (typeof VIEW_MODEL_PROPERTY_KINDS)[PropertyKindKey];
+
+export interface RiveViewModelInstance {
+  number(path: string): ValueAccessor<number> | null;
+  string(path: string): ValueAccessor<string> | null;
+  boolean(path: string): ValueAccessor<boolean> | null;
+  color(path: string): ValueAccessor<number> | null;
+  enum(path: string): ValueAccessor<string> | null;
+  trigger(path: string): TriggerAccessor | null;
+  list(path: string): ListAccessor | null;
+  viewModel(path: string): RiveViewModelInstance | null;
+}
+
+export interface TypedViewModel {
+  get<P extends ViewModelPropertyPath>(path: P): ViewModelPropertyTypes[P] | undefined;
+  set<P extends ViewModelPropertyPath>(path: P, value: ViewModelPropertyTypes[P]): boolean;
+  fire(path: ViewModelTriggerPath): boolean;
+}
+
+function resolveOwner(
+  root: RiveViewModelInstance,
+  path: string,
+): { owner: RiveViewModelInstance; name: string } | null {
+  const segments = path.split('/');
+  const name = segments.pop() as string;
+  let owner: RiveViewModelInstance | null = root;
+  for (let i = 0; owner && i < segments.length; i += 1) {
+    const nested: RiveViewModelInstance | null = owner.viewModel(segments[i]);
+    if (nested) {
+      owner = nested;
+      continue;
+    }
+    const index = Number(segments[i + 1]);
+    owner = Number.isInteger(index) ? owner.list(segments[i])?.instanceAt(index) ?? null : null;
+    i += 1;
+  }
+  return owner ? { owner, name } : null;
+}
+
+function kindKey(path: string): PropertyKindKey {
+  if (path in VIEW_MODEL_PROPERTY_KINDS) return path as PropertyKindKey;
+  return path.replace(/\/\d+(?=\/)/g, '/*') as PropertyKindKey;
+}
+
+function valueAccessor(root: RiveViewModelInstance, path: ViewModelPropertyPath): ValueAccessor<unknown> | null {
+  const target = resolveOwner(root, path);
+  if (!target) return null;
+  const kind: PropertyKind = VIEW_MODEL_PROPERTY_KINDS[kindKey(path)];
+  const lookup = target.owner[kind] as unknown as (name: string) => ValueAccessor<unknown> | null;
+  return lookup.call(target.owner, target.name);
+}
+
+export function createTypedViewModel(root: RiveViewModelInstance): TypedViewModel {
+  return {
+    get(path) {
+      return valueAccessor(root, path)?.value as ViewModelPropertyTypes[typeof path] | undefined;
+    },
+    set(path, value) {
+      const accessor = valueAccessor(root, path);
+      if (!accessor) return false;
+      accessor.value = value;
+      return true;
+    },
+    fire(path) {
+      const target = resolveOwner(root, path);
+      const accessor = target ? target.owner.trigger(target.name) : null;
+      if (!accessor) return false;
+      accessor.trigger();
+      return true;
+    },
+  };
+}
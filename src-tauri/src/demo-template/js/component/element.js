@@ -0,0 +1,234 @@
+/* ── Custom element ──────────────────────────────────── */
+
+let runtimePromise = null;
+
+function loadRiveRuntime(runtimeSrc) {
+    if (window.rive && typeof window.rive.Rive === 'function') {
+        return Promise.resolve(window.rive);
+    }
+    if (!runtimePromise) {
+        runtimePromise = new Promise(function (resolve, reject) {
+            const script = document.createElement('script');
+            script.src = runtimeSrc;
+            script.async = true;
+            script.onload = function () {
+                if (window.rive && typeof window.rive.Rive === 'function') resolve(window.rive);
+                else reject(new Error('Rive runtime did not register window.rive'));
+            };
+            script.onerror = function () {
+                runtimePromise = null;
+                reject(new Error('Failed to load the Rive runtime from ' + runtimeSrc));
+            };
+            document.head.appendChild(script);
+        });
+    }
+    return runtimePromise;
+}
+
+function decodeEmbeddedAnimation() {
+    const binaryString = atob(CONFIG.animationBase64);
+    const bytes = new Uint8Array(binaryString.length);
+    for (let i = 0; i < binaryString.length; i++) {
+        bytes[i] = binaryString.charCodeAt(i);
+    }
+    return bytes.buffer;
+}
+
+function coerceAttributeValue(kind, raw) {
+    if (kind === 'boolean') return raw !== null && raw !== 'false';
+    if (raw === null) return null;
+    if (kind === 'number') return Number(raw);
+    if (kind === 'color') {
+        const hex = String(raw).trim().replace(/^#/, '');
+        if (/^[0-9a-f]{6}$/i.test(hex)) return parseInt('ff' + hex, 16) >>> 0;
+        if (/^[0-9a-f]{8}$/i.test(hex)) return parseInt(hex, 16) >>> 0;
+        return Number(raw) >>> 0;
+    }
+    return String(raw);
+}
+
+function vmRootOf(instance) {
+    if (!instance) return null;
+    if (instance.viewModelInstance) return instance.viewModelInstance;
+    const definition = safeVmCall(instance, 'defaultViewModel');
+    return safeVmCall(definition, 'defaultInstance') || safeVmCall(definition, 'instance');
+}
+
+class RiveExportElement extends HTMLElement {
+    static get observedAttributes() {
+        return ['src'].concat(COMPONENT.properties.map(function (property) { return property.attribute; }));
+    }
+
+    constructor() {
+        super();
+        const root = this.attachShadow({ mode: 'open' });
+        const style = document.createElement('style');
+        style.textContent = ':host{display:block;position:relative;width:100%;aspect-ratio:' + COMPONENT.aspectRatio
+            + ';background:' + COMPONENT.background + '}canvas{display:block;width:100%;height:100%}';
+        this.canvas = document.createElement('canvas');
+        root.append(style, this.canvas);
+        this._rive = null;
+        this._pendingValues = new Map();
+        this._resizeObserver = typeof ResizeObserver === 'function'
+            ? new ResizeObserver(() => this._resize())
+            : null;
+    }
+
+    get riveInstance() {
+        return this._rive;
+    }
+
+    connectedCallback() {
+        if (this._resizeObserver) this._resizeObserver.observe(this);
+        this._load();
+    }
+
+    disconnectedCallback() {
+        if (this._resizeObserver) this._resizeObserver.disconnect();
+        this._cleanup();
+    }
+
+    attributeChangedCallback(name, oldValue, newValue) {
+        if (oldValue === newValue) return;
+        if (name === 'src') {
+            if (this.isConnected) this._load();
+            return;
+        }
+        const property = COMPONENT.properties.find(function (entry) { return entry.attribute === name; });
+        if (property) this._setValue(property, coerceAttributeValue(property.kind, newValue));
+    }
+
+    fire(path) {
+        const accessor = this._accessor(path, 'trigger');
+        if (!accessor || typeof accessor.trigger !== 'function') return false;
+        accessor.trigger();
+        return true;
+    }
+
+    _resize() {
+        const dpr = window.devicePixelRatio || 1;
+        this.canvas.width = Math.max(1, Math.round(this.canvas.clientWidth * dpr));
+        this.canvas.height = Math.max(1, Math.round(this.canvas.clientHeight * dpr));
+        if (this._rive) this._rive.resizeDrawingSurfaceToCanvas();
+    }
+
+    _cleanup() {
+        if (!this._rive) return;
+        try { this._rive.cleanup(); } catch (e) { /* noop */ }
+        this._rive = null;
+    }
+
+    _accessor(path, kind) {
+        const root = vmRootOf(this._rive);
+        const nav = root ? navigateToVmInstance(root, path) : null;
+        const info = nav ? getVmAccessor(nav.instance, nav.propertyName) : null;
+        return info && (!kind || info.kind === kind) ? info.accessor : null;
+    }
+
+    _getValue(property) {
+        const accessor = this._accessor(property.path, property.kind);
+        if (accessor && 'value' in accessor) return accessor.value;
+        return this._pendingValues.has(property.path) ? this._pendingValues.get(property.path) : property.default;
+    }
+
+    _setValue(property, value) {
+        const accessor = this._accessor(property.path, property.kind);
+        if (accessor && 'value' in accessor) {
+            accessor.value = value;
+            return;
+        }
+        this._pendingValues.set(property.path, value);
+    }
+
+    _applySnapshot(entries, attempt) {
+        const remaining = entries.filter((entry) => {
+            const descriptor = (entry && entry.descriptor) || {};
+            const kind = entry && (entry.kind || descriptor.kind);
+            if (!kind || kind === 'trigger') return false;
+            const accessor = descriptor.source === 'state-machine'
+                ? this._stateMachineInput(descriptor.stateMachineName, descriptor.name)
+                : this._accessor(descriptor.path, kind);
+            if (!accessor || !('value' in accessor)) return true;
+            accessor.value = entry.value;
+            return false;
+        });
+        if (remaining.length && attempt < SNAPSHOT_RETRY_LIMIT) {
+            setTimeout(() => this._applySnapshot(remaining, attempt + 1), SNAPSHOT_RETRY_INTERVAL_MS);
+        }
+    }
+
+    _stateMachineInput(stateMachineName, inputName) {
+        if (!this._rive || typeof this._rive.stateMachineInputs !== 'function') return null;
+        try {
+            const inputs = this._rive.stateMachineInputs(stateMachineName) || [];
+            return inputs.find(function (input) { return input && input.name === inputName; }) || null;
+        } catch (e) {
+            return null;
+        }
+    }
+
+    _load() {
+        const token = {};
+        this._loadToken = token;
+        loadRiveRuntime(COMPONENT.runtimeSrc).then((rive) => {
+            if (this._loadToken !== token || !this.isConnected) return;
+            this._cleanup();
+            this._resize();
+            const src = this.getAttribute('src') || COMPONENT.rivUrl;
+            const stateMachines = Array.isArray(CONFIG.stateMachines) ? CONFIG.stateMachines : [];
+            const animations = Array.isArray(CONFIG.animations) ? CONFIG.animations : [];
+            const riveConfig = {
+                canvas: this.canvas,
+                autoplay: CONFIG.autoplay !== false,
+                autoBind: !CONFIG.viewModelInstanceName,
+            };
+            if (src) riveConfig.src = src;
+            else riveConfig.buffer = decodeEmbeddedAnimation();
+            if (CONFIG.artboardName) riveConfig.artboard = CONFIG.artboardName;
+            if (stateMachines.length) {
+                riveConfig.stateMachines = stateMachines.length === 1 ? stateMachines[0] : stateMachines;
+            } else if (animations.length) {
+                riveConfig.animations = animations[0];
+            }
+            if (rive.Layout) {
+                riveConfig.layout = new rive.Layout({
+                    fit: resolveRiveLayoutFit(rive, CONFIG.layoutFit),
+                    alignment: resolveRiveLayoutAlignment(rive, CONFIG.layoutAlignment),
+                });
+            }
+            riveConfig.onLoad = () => {
+                this._resize();
+                bindViewModelInstanceByKey(this._rive, CONFIG.viewModelInstanceName);
+                this._applySnapshot(CONTROL_SNAPSHOT, 0);
+                const pending = Array.from(this._pendingValues.entries());
+                this._pendingValues.clear();
+                pending.forEach(([path, value]) => {
+                    const property = COMPONENT.properties.find(function (entry) { return entry.path === path; });
+                    if (property) this._setValue(property, value);
+                });
+                this.dispatchEvent(new CustomEvent('rive-load', { detail: { rive: this._rive } }));
+            };
+            this._rive = new rive.Rive(riveConfig);
+        }).catch((error) => {
+            this.dispatchEvent(new CustomEvent('rive-error', { detail: { error } }));
+        });
+    }
+}
+
+COMPONENT.properties.forEach(function (property) {
+    Object.defineProperty(RiveExportElement.prototype, property.property, {
+        configurable: true,
+        enumerable: true,
+        get: function () { return this._getValue(property); },
+        set: function (value) { this._setValue(property, value); },
+    });
+});
+
+if (!customElements.get(COMPONENT.tagName)) {
+    customElements.define(COMPONENT.tagName, RiveExportElement);
+}
+
+export const TAG_NAME = COMPONENT.tagName;
+export const PROPERTIES = COMPONENT.properties;
+export { RiveExportElement };
+export default RiveExportElement;
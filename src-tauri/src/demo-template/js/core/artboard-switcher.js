@@ -0,0 +1,73 @@
+        /* ── Artboard switcher ───────────────────────────────── */
+
+        function getEmbeddedAnimationUrl() {
+            if (embeddedAnimationUrl) return embeddedAnimationUrl;
+
+            // Decode the embedded .riv once; every artboard reuses the same blob URL.
+            var binaryString = atob(CONFIG.animationBase64);
+            var bytes = new Uint8Array(binaryString.length);
+            for (var i = 0; i < binaryString.length; i++) {
+                bytes[i] = binaryString.charCodeAt(i);
+            }
+            var animationBlob = new Blob([bytes], { type: 'application/octet-stream' });
+            embeddedAnimationUrl = URL.createObjectURL(animationBlob);
+            return embeddedAnimationUrl;
+        }
+
+        function setupArtboardSwitcher() {
+            var select = els.artboardSelect;
+            if (!select || ARTBOARD_CONFIGS.length < 2) return;
+
+            select.innerHTML = '';
+            ARTBOARD_CONFIGS.forEach(function (entry, index) {
+                var option = document.createElement('option');
+                option.value = String(index);
+                option.textContent = 'ARTBOARD: ' + entry.artboardName;
+                select.appendChild(option);
+            });
+            select.value = String(activeArtboardIndex);
+            select.hidden = false;
+
+            select.addEventListener('change', function () {
+                selectArtboard(Number.parseInt(select.value, 10));
+            });
+        }
+
+        function selectArtboard(index) {
+            var entry = ARTBOARD_CONFIGS[index];
+            if (!entry || index === activeArtboardIndex) return;
+
+            // Keep the values of the artboard being left so switching back restores them.
+            ARTBOARD_CONFIGS[activeArtboardIndex].controlSnapshot = captureVmControlSnapshot();
+            activeArtboardIndex = index;
+            applyArtboardConfig(entry);
+            logEvent('ui', 'artboard-change', 'Artboard set to ' + entry.artboardName);
+            loadAnimation();
+        }
+
+        function applyArtboardConfig(entry) {
+            CONFIG.artboardName = entry.artboardName;
+            CONFIG.stateMachines = Array.isArray(entry.stateMachines) ? entry.stateMachines : [];
+            CONFIG.animations = Array.isArray(entry.animations) ? entry.animations : [];
+            CONFIG.viewModelInstanceName = entry.viewModelInstanceName || null;
+            currentControlSnapshot = JSON.parse(JSON.stringify(
+                Array.isArray(entry.controlSnapshot) ? entry.controlSnapshot : []
+            ));
+            if (LAYOUT_FITS.includes(entry.layoutFit)) currentLayoutFit = entry.layoutFit;
+            if (LAYOUT_ALIGNMENTS.includes(entry.layoutAlignment)) currentLayoutAlignment = entry.layoutAlignment;
+            if (els.layoutSelect) els.layoutSelect.value = currentLayoutFit;
+            if (els.alignmentSelect) els.alignmentSelect.value = currentLayoutAlignment;
+
+            var keys = Array.isArray(entry.controlSelectionKeys)
+                ? entry.controlSelectionKeys
+                : currentControlSnapshot.map(function (item) {
+                    return controlSelectionKeyForDescriptor(item && item.descriptor ? item.descriptor : item);
+                });
+            ALLOWED_CONTROL_KEYS.clear();
+            keys.forEach(function (key) {
+                var normalized = normalizeControlSelectionKey(key);
+                if (normalized) ALLOWED_CONTROL_KEYS.add(normalized);
+            });
+            // An artboard exported without a selection shows every control it has.
+            showAllControls = !Array.isArray(entry.controlSelectionKeys) && !currentControlSnapshot.length;
+        }
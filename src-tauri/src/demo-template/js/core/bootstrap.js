@@ -12,12 +12,14 @@
             setupPanelVisibilityToggles();
             setupSettingsPopover();
             setupCopyInstantiationButton();
+            setupArtboardSwitcher();
             setupLayoutSelect();
             setupAlignmentSelect();
             setupCanvasColor();
             setupTransparencyControls();
             setupEventLog();
             setupFullscreen();
+            setupTimelineControls();
             window.addEventListener('resize', handleResize);
             loadAnimation();
         }
@@ -40,7 +40,16 @@
                 .map(function (key) { return normalizeControlSelectionKey(key); })
                 .filter(Boolean)
         );
+        const ARTBOARD_CONFIGS = Array.isArray(CONFIG.artboards)
+            ? CONFIG.artboards.filter(function (entry) { return entry && entry.artboardName; })
+            : [];
+        const TIMELINE = (CONFIG.timeline && Array.isArray(CONFIG.timeline.steps) && CONFIG.timeline.steps.length)
+            ? CONFIG.timeline
+            : null;
         let riveInstance = null;
+        let embeddedAnimationUrl = null;
+        let activeArtboardIndex = 0;
+        let showAllControls = false;
         let currentControlSnapshot = JSON.parse(JSON.stringify(CONTROL_SNAPSHOT));
         let currentInstantiationPackageSource = CONFIG.defaultInstantiationPackageSource === 'local' ? 'local' : 'cdn';
         let currentLayoutAlignment = CONFIG.layoutAlignment || 'center';
@@ -71,6 +80,11 @@
         let lastFpsUpdate = 0;
         let frameCount = 0;
         let isFallbackFullscreenMode = false;
+        let isTimelinePlaying = Boolean(TIMELINE && TIMELINE.autoplay !== false);
+        let timelineElapsedMs = 0;
+        let timelineStartedAt = 0;
+        let timelineNextStepIndex = 0;
+        let timelineFrameId = null;
 
         function controlSnapshotKeyForDescriptor(descriptor) {
             if (!descriptor) return null;
@@ -167,6 +181,7 @@
             showRightPanelBtn: document.getElementById('show-right-panel-btn'),
             settingsBtn: document.getElementById('settings-btn'),
             settingsPopover: document.getElementById('settings-popover'),
+            artboardSelect: document.getElementById('artboard-select'),
             layoutSelect: document.getElementById('layout-select'),
             alignmentSelect: document.getElementById('alignment-select'),
             canvasColorInput: document.getElementById('canvas-color-input'),
@@ -176,6 +191,10 @@
             btnPause: document.getElementById('btn-pause'),
             btnReset: document.getElementById('btn-reset'),
             fullscreenToggleBtn: document.getElementById('fullscreen-toggle-btn'),
+            timelineControls: document.getElementById('timeline-controls'),
+            timelineToggleBtn: document.getElementById('timeline-toggle-btn'),
+            timelineScrubber: document.getElementById('timeline-scrubber'),
+            timelineTimeChip: document.getElementById('timeline-time-chip'),
         };
 
         /* ── Initialization ──────────────────────────────────── */
@@ -64,15 +64,7 @@
                 // Clean up previous instance
                 cleanupInstance();
 
-                // Decode embedded animation from base64
-                var base64Data = CONFIG.animationBase64;
-                var binaryString = atob(base64Data);
-                var bytes = new Uint8Array(binaryString.length);
-                for (var i = 0; i < binaryString.length; i++) {
-                    bytes[i] = binaryString.charCodeAt(i);
-                }
-                var animationBlob = new Blob([bytes], { type: 'application/octet-stream' });
-                var animationUrl = URL.createObjectURL(animationBlob);
+                var animationUrl = getEmbeddedAnimationUrl();
 
                 resizeCanvas();
 
@@ -175,6 +167,7 @@
                     applyControlSnapshot(currentControlSnapshot);
                     // Render VM controls
                     renderVmControls();
+                    restartTimeline();
                 };
 
                 riveConfig.onLoadError = function (error) {
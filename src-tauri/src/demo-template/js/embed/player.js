@@ -0,0 +1,78 @@
+        /* ── Embed playback ──────────────────────────────────── */
+
+        var canvas = document.getElementById('rive-canvas');
+
+        function resizeCanvas() {
+            var dpr = window.devicePixelRatio || 1;
+            canvas.width = Math.max(1, Math.round(canvas.clientWidth * dpr));
+            canvas.height = Math.max(1, Math.round(canvas.clientHeight * dpr));
+            if (riveInstance) riveInstance.resizeDrawingSurfaceToCanvas();
+        }
+
+        function decodeAnimationUrl() {
+            var binaryString = atob(CONFIG.animationBase64);
+            var bytes = new Uint8Array(binaryString.length);
+            for (var i = 0; i < binaryString.length; i++) {
+                bytes[i] = binaryString.charCodeAt(i);
+            }
+            return URL.createObjectURL(new Blob([bytes], { type: 'application/octet-stream' }));
+        }
+
+        function applyEmbeddedSnapshot(attempt) {
+            var remaining = CONTROL_SNAPSHOT.filter(function (entry) {
+                var descriptor = (entry && entry.descriptor) || {};
+                var kind = entry && (entry.kind || descriptor.kind);
+                if (!kind || kind === 'trigger') return false;
+                var accessor = descriptor.source === 'state-machine'
+                    ? resolveStateMachineInputAccessor(descriptor.stateMachineName, descriptor.name, kind)
+                    : resolveLiveAccessor(descriptor.path, kind);
+                if (!accessor || !('value' in accessor)) return true;
+                accessor.value = entry.value;
+                return false;
+            });
+            if (remaining.length && attempt < SNAPSHOT_RETRY_LIMIT) {
+                setTimeout(function () { applyEmbeddedSnapshot(attempt + 1); }, SNAPSHOT_RETRY_INTERVAL_MS);
+            }
+        }
+
+        function loadEmbed() {
+            var rive = window.rive || window.RiveModule;
+            if (!rive || typeof rive.Rive !== 'function') return;
+
+            resizeCanvas();
+            var stateMachines = Array.isArray(CONFIG.stateMachines) ? CONFIG.stateMachines : [];
+            var animations = Array.isArray(CONFIG.animations) ? CONFIG.animations : [];
+            var riveConfig = {
+                src: decodeAnimationUrl(),
+                canvas: canvas,
+                autoplay: CONFIG.autoplay !== false,
+                autoBind: !CONFIG.viewModelInstanceName,
+            };
+            if (CONFIG.artboardName) riveConfig.artboard = CONFIG.artboardName;
+            if (stateMachines.length) {
+                riveConfig.stateMachines = stateMachines.length === 1 ? stateMachines[0] : stateMachines;
+            } else if (animations.length) {
+                riveConfig.animations = animations[0];
+            }
+            if (rive.Layout) {
+                riveConfig.layout = new rive.Layout({
+                    fit: resolveRiveLayoutFit(rive, CONFIG.layoutFit),
+                    alignment: resolveRiveLayoutAlignment(rive, CONFIG.layoutAlignment),
+                });
+            }
+            if (CONFIG.canvasTransparent && CONFIG.runtimeName !== 'canvas') {
+                riveConfig.useOffscreenRenderer = true;
+            }
+            riveConfig.onLoad = function () {
+                resizeCanvas();
+                bindViewModelInstanceByKey(riveInstance, CONFIG.viewModelInstanceName);
+                applyEmbeddedSnapshot(0);
+            };
+
+            riveInstance = new rive.Rive(riveConfig);
+            window.riveInst = riveInstance;
+        }
+
+        window.addEventListener('resize', resizeCanvas);
+        loadEmbed();
+    })();
@@ -0,0 +1,12 @@
+    (function () {
+        'use strict';
+
+        /* ── Configuration from Rust placeholders ────────────── */
+
+        const CONFIG = JSON.parse('__CONFIG_JSON__');
+        const CONTROL_SNAPSHOT = Array.isArray(CONFIG.controlSnapshot) ? CONFIG.controlSnapshot : [];
+        const LAYOUT_FITS = ['cover', 'contain', 'fill', 'fitWidth', 'fitHeight', 'scaleDown', 'none', 'layout'];
+        const LAYOUT_ALIGNMENTS = ['topLeft', 'topCenter', 'topRight', 'centerLeft', 'center', 'centerRight', 'bottomLeft', 'bottomCenter', 'bottomRight'];
+        const SNAPSHOT_RETRY_LIMIT = 20;
+        const SNAPSHOT_RETRY_INTERVAL_MS = 100;
+        let riveInstance = null;
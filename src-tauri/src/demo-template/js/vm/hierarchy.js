@@ -12,6 +12,7 @@
 
         function isControlDescriptorAllowed(descriptor) {
             if (!descriptor) return false;
+            if (showAllControls) return true;
             var exactKey = controlSnapshotKeyForDescriptor(descriptor);
             if (exactKey && ALLOWED_CONTROL_KEYS.has(exactKey)) return true;
             var selectionKey = controlSelectionKeyForDescriptor(descriptor);
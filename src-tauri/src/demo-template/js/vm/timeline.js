@@ -0,0 +1,112 @@
+        /* ── Scripted interaction timeline ───────────────────── */
+
+        function setupTimelineControls() {
+            if (!TIMELINE || !els.timelineControls) return;
+
+            els.timelineControls.hidden = false;
+            els.timelineScrubber.max = String(TIMELINE.durationMs);
+            els.timelineToggleBtn.addEventListener('click', function () {
+                if (isTimelinePlaying) {
+                    pauseTimeline();
+                } else {
+                    playTimeline();
+                }
+                logEvent('ui', 'timeline', isTimelinePlaying ? 'Timeline playing.' : 'Timeline paused.');
+            });
+            els.timelineScrubber.addEventListener('input', function () {
+                seekTimeline(Number(els.timelineScrubber.value));
+            });
+            syncTimelineControls();
+        }
+
+        function restartTimeline() {
+            if (!TIMELINE) return;
+            seekTimeline(0);
+            if (isTimelinePlaying) playTimeline();
+        }
+
+        function playTimeline() {
+            if (!TIMELINE) return;
+            if (timelineElapsedMs >= TIMELINE.durationMs) seekTimeline(0);
+            isTimelinePlaying = true;
+            timelineStartedAt = performance.now() - timelineElapsedMs;
+            if (timelineFrameId === null) timelineFrameId = requestAnimationFrame(tickTimeline);
+            syncTimelineControls();
+        }
+
+        function pauseTimeline() {
+            isTimelinePlaying = false;
+            if (timelineFrameId !== null) cancelAnimationFrame(timelineFrameId);
+            timelineFrameId = null;
+            syncTimelineControls();
+        }
+
+        function tickTimeline(now) {
+            timelineFrameId = null;
+            if (!isTimelinePlaying) return;
+
+            timelineElapsedMs = Math.max(0, now - timelineStartedAt);
+            while (timelineNextStepIndex < TIMELINE.steps.length
+                && TIMELINE.steps[timelineNextStepIndex].atMs <= timelineElapsedMs) {
+                applyTimelineStep(TIMELINE.steps[timelineNextStepIndex]);
+                timelineNextStepIndex += 1;
+            }
+
+            if (timelineElapsedMs >= TIMELINE.durationMs) {
+                if (!TIMELINE.loop) {
+                    timelineElapsedMs = TIMELINE.durationMs;
+                    pauseTimeline();
+                    return;
+                }
+                seekTimeline(0);
+                timelineStartedAt = now;
+            }
+            syncTimelineControls();
+            timelineFrameId = requestAnimationFrame(tickTimeline);
+        }
+
+        function seekTimeline(ms) {
+            if (!TIMELINE) return;
+
+            // Rebuild the state at `ms` from the exported snapshot; triggers are moments, not state.
+            timelineElapsedMs = Math.max(0, Math.min(TIMELINE.durationMs, Number(ms) || 0));
+            timelineStartedAt = performance.now() - timelineElapsedMs;
+            applyControlSnapshot(currentControlSnapshot);
+            timelineNextStepIndex = 0;
+            while (timelineNextStepIndex < TIMELINE.steps.length
+                && TIMELINE.steps[timelineNextStepIndex].atMs < timelineElapsedMs) {
+                var step = TIMELINE.steps[timelineNextStepIndex];
+                if (step.kind !== 'trigger') applyTimelineStep(step);
+                timelineNextStepIndex += 1;
+            }
+            syncTimelineControls();
+        }
+
+        function applyTimelineStep(step) {
+            var accessor = step.stateMachine
+                ? resolveStateMachineInputAccessor(step.stateMachine, step.input, step.kind)
+                : resolveLiveAccessor(step.path, step.kind);
+            if (!accessor) return false;
+
+            try {
+                if (step.kind !== 'trigger') {
+                    accessor.value = step.value;
+                } else if (typeof accessor.trigger === 'function') {
+                    accessor.trigger();
+                } else if (typeof accessor.fire === 'function') {
+                    accessor.fire();
+                }
+                return true;
+            } catch (e) {
+                return false;
+            }
+        }
+
+        function syncTimelineControls() {
+            if (!TIMELINE || !els.timelineControls) return;
+            els.timelineToggleBtn.textContent = isTimelinePlaying ? 'PAUSE' : 'PLAY';
+            els.timelineToggleBtn.setAttribute('aria-pressed', String(isTimelinePlaying));
+            els.timelineScrubber.value = String(Math.round(timelineElapsedMs));
+            els.timelineTimeChip.textContent = (timelineElapsedMs / 1000).toFixed(1) + 's / '
+                + (TIMELINE.durationMs / 1000).toFixed(1) + 's';
+        }
@@ -0,0 +1,61 @@
+// Generated by Rive Animation Viewer. Pre-caches the demo so it runs offline after the first visit.
+const CACHE_NAME = '__CACHE_NAME__';
+const CACHE_PREFIX = 'rav-demo-';
+const APP_SHELL = __APP_SHELL_JSON__;
+const REMOTE_ASSETS = __REMOTE_ASSETS_JSON__;
+
+self.addEventListener('install', (event) => {
+    event.waitUntil((async () => {
+        const cache = await caches.open(CACHE_NAME);
+        await cache.addAll(APP_SHELL);
+        await Promise.all(REMOTE_ASSETS.map(async (url) => {
+            try {
+                await cache.add(new Request(url, { mode: 'cors', credentials: 'omit' }));
+            } catch (error) {
+                console.warn(`[rav-demo] Could not pre-cache ${url}; it will be cached on first use.`, error);
+            }
+        }));
+        await self.skipWaiting();
+    })());
+});
+
+self.addEventListener('activate', (event) => {
+    event.waitUntil((async () => {
+        const names = await caches.keys();
+        await Promise.all(names
+            .filter((name) => name.startsWith(CACHE_PREFIX) && name !== CACHE_NAME)
+            .map((name) => caches.delete(name)));
+        await self.clients.claim();
+    })());
+});
+
+self.addEventListener('fetch', (event) => {
+    const { request } = event;
+    if (request.method !== 'GET' || !request.url.startsWith('http')) {
+        return;
+    }
+
+    event.respondWith((async () => {
+        const cache = await caches.open(CACHE_NAME);
+        const cached = await cache.match(request, { ignoreVary: true });
+        if (cached) {
+            return cached;
+        }
+
+        try {
+            const response = await fetch(request);
+            if (response.ok || response.type === 'opaque') {
+                await cache.put(request, response.clone());
+            }
+            return response;
+        } catch (error) {
+            if (request.mode === 'navigate') {
+                const shell = await cache.match('./index.html');
+                if (shell) {
+                    return shell;
+                }
+            }
+            throw error;
+        }
+    })());
+});
@@ -12,10 +12,11 @@ use tauri::menu::{PredefinedMenuItem, Submenu, WINDOW_SUBMENU_ID};
 use tauri::{Emitter, Manager};
 
 use crate::app::constants::{ABOUT_MENU_ID, DEFAULT_MCP_PORT, ONLINE_DOCS_MENU_ID, RAV_DOCS_URL};
+use crate::app::export::watch::stop_active_watch;
 use crate::app::mcp::bridge::{
     initialize_mcp_bridge, kill_spawned_mcp_bridge, refresh_mcp_client_launcher_if_present,
 };
-use crate::app::state::{McpBridgeManager, OpenedFiles, PendingAppUpdate};
+use crate::app::state::{ExportWatchManager, McpBridgeManager, OpenedFiles, PendingAppUpdate};
 use crate::app::support::{
     extract_opened_riv_file_args, extract_opened_riv_file_args_from_iter, looks_like_riv_file,
     queue_pending_opened_file, try_emit_open_file,
@@ -58,6 +59,7 @@ fn main() {
         .manage(OpenedFiles(Mutex::new(VecDeque::from(opened_files))))
         .manage(McpBridgeManager::new(DEFAULT_MCP_PORT))
         .manage(PendingAppUpdate::default())
+        .manage(ExportWatchManager::default())
         .setup(|app| {
             #[cfg(desktop)]
             {
@@ -84,8 +86,21 @@ fn main() {
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
-            app::demo_bundle::make_demo_bundle,
-            app::demo_bundle::make_demo_bundle_to_path,
+            app::export::demo_bundle::make_demo_bundle,
+            app::export::demo_bundle::make_demo_bundle_to_path,
+            app::export::demo_config::presets::apply_export_preset,
+            app::export::demo_config::presets::delete_export_preset,
+            app::export::demo_config::presets::list_export_presets,
+            app::export::demo_config::presets::save_export_preset,
+            app::export::spec_sheet::make_spec_sheet_to_path,
+            app::export::targets::native::generate_native_instantiation_code,
+            app::export::targets::pwa::make_pwa_demo_to_path,
+            app::export::targets::starter::make_framework_starter_to_path,
+            app::export::targets::typescript::make_typescript_bindings_to_path,
+            app::export::targets::web_component::make_web_component_to_path,
+            app::export::watch::get_export_watch_status,
+            app::export::watch::start_export_watch,
+            app::export::watch::stop_export_watch,
             app::mcp::bridge::get_mcp_server_path,
             app::mcp::bridge::get_mcp_port,
             app::mcp::bridge::set_mcp_port,
@@ -114,6 +129,9 @@ fn main() {
                 if let Some(manager) = app.try_state::<McpBridgeManager>() {
                     kill_spawned_mcp_bridge(app, &manager);
                 }
+                if let Some(watch) = app.try_state::<ExportWatchManager>() {
+                    stop_active_watch(&watch);
+                }
             }
 
             if let tauri::RunEvent::WindowEvent {
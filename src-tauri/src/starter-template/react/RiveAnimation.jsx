@@ -0,0 +1,26 @@
+import { useEffect, useRef } from 'react';
+import { createRiveAnimation } from './rive-setup.js';
+
+const RIVE_OPTIONS = __RIVE_OPTIONS__;
+
+export default function RiveAnimation({ style }) {
+  const canvasRef = useRef(null);
+
+  useEffect(() => {
+    let instance = null;
+    let disposed = false;
+    createRiveAnimation(canvasRef.current, RIVE_OPTIONS).then((created) => {
+      if (disposed) created.cleanup();
+      else instance = created;
+    });
+    const onResize = () => instance?.resizeDrawingSurfaceToCanvas();
+    window.addEventListener('resize', onResize);
+    return () => {
+      disposed = true;
+      window.removeEventListener('resize', onResize);
+      instance?.cleanup();
+    };
+  }, []);
+
+  return <canvas ref={canvasRef} style={{ display: 'block', width: '100%', height: '100%', ...style }} />;
+}
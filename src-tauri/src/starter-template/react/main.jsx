@@ -0,0 +1,9 @@
+import { StrictMode } from 'react';
+import { createRoot } from 'react-dom/client';
+import RiveAnimation from './RiveAnimation.jsx';
+
+createRoot(document.getElementById('app')).render(
+  <StrictMode>
+    <RiveAnimation />
+  </StrictMode>,
+);
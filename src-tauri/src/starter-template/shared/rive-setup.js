@@ -0,0 +1,45 @@
+import { loadRive } from './rive-runtime.js';
+
+function applyControlSnapshot(instance, entries) {
+  const viewModelInstance = instance.viewModelInstance;
+  entries.forEach((entry) => {
+    const descriptor = entry?.descriptor || {};
+    const kind = entry?.kind || descriptor.kind;
+    if (!kind || kind === 'trigger') return;
+
+    let accessor = null;
+    if (descriptor.source === 'state-machine') {
+      const inputs = instance.stateMachineInputs(descriptor.stateMachineName) || [];
+      accessor = inputs.find((input) => input.name === descriptor.name);
+    } else if (viewModelInstance && typeof viewModelInstance[kind] === 'function') {
+      accessor = viewModelInstance[kind](descriptor.path);
+    }
+    if (accessor && 'value' in accessor) accessor.value = entry.value;
+  });
+}
+
+function bindViewModelInstance(instance, instanceName) {
+  const definition = instance.defaultViewModel();
+  const selected = definition?.instanceByName(instanceName);
+  if (selected) instance.bindViewModelInstance(selected);
+}
+
+export async function createRiveAnimation(canvas, options) {
+  const rive = await loadRive();
+  const { controlSnapshot = [], layout, viewModelInstanceName, ...config } = options;
+  const instance = new rive.Rive({
+    ...config,
+    canvas,
+    autoBind: !viewModelInstanceName,
+    layout: new rive.Layout({
+      fit: rive.Fit[layout.fit],
+      alignment: rive.Alignment[layout.alignment],
+    }),
+    onLoad: () => {
+      instance.resizeDrawingSurfaceToCanvas();
+      if (viewModelInstanceName) bindViewModelInstance(instance, viewModelInstanceName);
+      applyControlSnapshot(instance, controlSnapshot);
+    },
+  });
+  return instance;
+}
@@ -0,0 +1,18 @@
+const RUNTIME_SRC = 'https://unpkg.com/__RUNTIME_PACKAGE__@__RUNTIME_VERSION__';
+
+let runtimePromise = null;
+
+export function loadRive() {
+  if (window.rive) return Promise.resolve(window.rive);
+  runtimePromise ??= new Promise((resolve, reject) => {
+    const script = document.createElement('script');
+    script.src = RUNTIME_SRC;
+    script.onload = () => resolve(window.rive);
+    script.onerror = () => {
+      runtimePromise = null;
+      reject(new Error(`Failed to load the Rive runtime from ${RUNTIME_SRC}`));
+    };
+    document.head.appendChild(script);
+  });
+  return runtimePromise;
+}
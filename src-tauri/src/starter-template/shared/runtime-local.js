@@ -0,0 +1,5 @@
+import * as rive from '__RUNTIME_PACKAGE__';
+
+export async function loadRive() {
+  return rive;
+}
@@ -0,0 +1,4 @@
+import { mount } from 'svelte';
+import RiveAnimation from './RiveAnimation.svelte';
+
+mount(RiveAnimation, { target: document.getElementById('app') });
@@ -0,0 +1,4 @@
+import { createApp } from 'vue';
+import RiveAnimation from './RiveAnimation.vue';
+
+createApp(RiveAnimation).mount('#app');
@@ -197,6 +197,7 @@ export function createControllerStack({
             getRuntimeVersionToken,
             getSelectedControlKeys: () => platformStack.instantiationControlsDialogController?.getSelectedControlKeys() ?? null,
             getSidebarVisibility: () => platformStack.shellController?.getSidebarVisibility?.() ?? { left: false, right: true },
+            getTimelineRecorder: () => riveStack.timelineRecorder,
             getTauriEventListener,
             getTauriInvoker,
             getTransparencyStateSnapshot,
@@ -219,6 +220,7 @@ export function createControllerStack({
             resetToDefaultArtboard: riveStack.resetToDefaultArtboard,
             resetVmInputControls: riveStack.resetVmInputControls,
             serializeControlHierarchy: riveStack.serializeControlHierarchy,
+            serializeStateMachineHierarchy: riveStack.serializeStateMachineHierarchy,
             serializeVmHierarchy: riveStack.serializeVmHierarchy,
             setCurrentFile: (...args) => platformStack.fileSessionController?.setCurrentFile(...args),
             setCurrentCanvasSizing,
@@ -1,4 +1,6 @@
 import { createDemoExportController } from '../../platform/export/demo-export.js';
+import { createExportPresetsClient } from '../../platform/export/export-presets.js';
+import { createExportWatchClient } from '../../platform/export/export-watch.js';
 import { createFileSessionController } from '../../platform/session/file-session.js';
 import { createGlobalBindingsController } from '../../platform/global-bindings.js';
 import { createShellController } from '../../ui/shell-controller.js';
@@ -45,6 +47,7 @@ export function createPlatformStack({
         getSidebarVisibility,
         getTauriEventListener,
         getTauriInvoker,
+        getTimelineRecorder,
         getTransparencyStateSnapshot,
         getVmExplorerSnippetState,
         getEditorCode,
@@ -66,6 +69,7 @@ export function createPlatformStack({
         resetToDefaultArtboard,
         resetVmInputControls,
         serializeControlHierarchy,
+        serializeStateMachineHierarchy,
         serializeVmHierarchy,
         setCurrentFile,
         setCurrentCanvasSizing,
@@ -140,6 +144,13 @@ export function createPlatformStack({
         elements,
     });
 
+    const exportPresetsClient = createExportPresetsClient({ getTauriInvoker });
+    const exportWatchClient = createExportWatchClient({
+        getCurrentFileSourcePath: () => fileSessionController.getCurrentFileSourcePath(),
+        getTauriEventListener,
+        getTauriInvoker,
+        logEvent,
+    });
     const demoExportController = createDemoExportController({
         callbacks: {
             ensureRuntime,
@@ -166,6 +177,7 @@ export function createPlatformStack({
         getSelectedControlKeys,
         getTransparencyStateSnapshot,
         getChangedVmControlSnapshot,
+        serializeStateMachineHierarchy,
         serializeVmHierarchy,
     });
 
@@ -191,7 +203,11 @@ export function createPlatformStack({
             applyCodeAndReload,
             createDemoBundle,
             ensureEditorReady,
+            exportArtifactToPath: (command, outputPath, options) => demoExportController.exportArtifactToPath(command, outputPath, options),
             exportDemoToPath: (outputPath, options) => demoExportController.exportDemoToPath(outputPath, options),
+            invokeArtifactCommand: (command, options) => demoExportController.invokeArtifactCommand(command, options),
+            runExportPresetAction: (action, args) => exportPresetsClient.run(action, args),
+            runExportWatchAction: (action, args) => exportWatchClient.run(action, args),
             getArtboardStateSnapshot,
             getCurrentFileBuffer,
             getCurrentFileMimeType,
@@ -206,6 +222,7 @@ export function createPlatformStack({
             getRuntimeVersion,
             getScriptConsoleEntries: (limit) => scriptConsoleController.readCaptured(limit),
             getSidebarVisibility,
+            getTimelineRecorder,
             getCurrentCanvasSizing,
             getVmExplorerSnippetState,
             handleFileButtonClick,
@@ -3,6 +3,12 @@ import { detectDefaultStateMachineName } from '../../rive/default-state-machine.
 import { createRiveInstanceController } from '../../rive/instance-controller.js';
 import { createPlaybackController } from '../../rive/playback-controls.js';
 import { createVmControlsController } from '../../rive/vm-controls.js';
+import { createTimelineRecorder } from '../../rive/view-model/timeline-recorder.js';
+import {
+    RESOURCE_URIS,
+    announceResourceUpdates,
+    vmPropertyUri,
+} from '../../platform/mcp/resource-updates.js';
 
 export function createRiveStack({
     elements,
@@ -40,6 +46,7 @@ export function createRiveStack({
         updatePlaybackChips,
     } = callbacks;
 
+    const timelineRecorder = createTimelineRecorder();
     const vmControlsController = createVmControlsController({
         elements,
         getCurrentRuntime,
@@ -48,6 +55,10 @@ export function createRiveStack({
         callbacks: {
             initLucideIcons,
             logEvent,
+            recordControlChange: (descriptor, value) => {
+                timelineRecorder.record(descriptor, value);
+                if (descriptor?.path) announceResourceUpdates([vmPropertyUri(descriptor.path)]);
+            },
         },
     });
     const {
@@ -57,6 +68,7 @@ export function createRiveStack({
         renderVmInputControls: renderVmInputs,
         resetVmInputControls: resetVmInputs,
         serializeControlHierarchy,
+        serializeStateMachineHierarchy,
         serializeVmHierarchy,
         setVmControlBaselineSnapshot: setVmBaseline,
     } = vmControlsController;
@@ -125,7 +137,10 @@ export function createRiveStack({
             setVmControlBaselineSnapshot: setVmBaseline,
             showError,
             getPlaybackState: getArtboardStateSnapshot,
-            syncArtboardStateAfterLoad: syncArtboardAfterLoad,
+            syncArtboardStateAfterLoad: (...args) => {
+                syncArtboardAfterLoad(...args);
+                announceResourceUpdates([RESOURCE_URIS.fileInfo, RESOURCE_URIS.vmTree]);
+            },
             syncArtboardStateFromConfig: syncArtboardFromConfig,
             updateInfo,
             updatePlaybackChips: updatePlaybackIndicators,
@@ -155,6 +170,7 @@ export function createRiveStack({
         resetToDefaultArtboard,
         resetVmInputControls: resetVmInputs,
         serializeControlHierarchy,
+        serializeStateMachineHierarchy,
         serializeVmHierarchy,
         setVmControlBaselineSnapshot: setVmBaseline,
         setupArtboardSwitcher,
@@ -162,6 +178,7 @@ export function createRiveStack({
         switchVmInstance,
         syncArtboardStateAfterLoad: syncArtboardAfterLoad,
         syncArtboardStateFromConfig: syncArtboardFromConfig,
+        timelineRecorder,
         updatePlaybackChips: updatePlaybackIndicators,
         vmControlsController,
     };
@@ -1,4 +1,5 @@
 import { createAppUpdaterController } from '../../platform/app-updater.js';
+import { RESOURCE_URIS, announceResourceUpdates } from '../../platform/mcp/resource-updates.js';
 import { createEventLogController } from '../../ui/event-log.js';
 import { createMcpSetupController } from '../../ui/mcp-setup.js';
 import { createAboutDialogController } from '../../ui/about/about-dialog.js';
@@ -47,6 +48,10 @@ export function createUiStack({
         },
         elements,
         handleResize,
+        // MCP traffic is logged too; announcing it would make every resource read trigger another update.
+        onEntryAdded: (entry) => {
+            if (entry.source !== 'mcp') announceResourceUpdates([RESOURCE_URIS.eventLog]);
+        },
     });
     const {
         getEntriesSnapshot: getEventLogEntries,
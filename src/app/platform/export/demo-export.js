@@ -8,75 +8,15 @@ import {
     buildEffectiveInstantiationDescriptor,
     buildWebInstantiationResult,
 } from './web-instantiation.js';
+import { buildDemoBundlePayload } from './demo-payload.js';
 
-export function arrayBufferToBase64(buffer) {
-    if (!(buffer instanceof ArrayBuffer)) {
-        return '';
-    }
-    let binary = '';
-    const bytes = new Uint8Array(buffer);
-    const chunkSize = 0x8000;
-    for (let index = 0; index < bytes.length; index += chunkSize) {
-        const chunk = bytes.subarray(index, index + chunkSize);
-        binary += String.fromCharCode.apply(null, chunk);
-    }
-    return btoa(binary);
-}
+export { arrayBufferToBase64, buildDemoBundlePayload } from './demo-payload.js';
 
 export function resolveExportStateMachines(configStateMachines, detectedStateMachines = []) {
     const configuredStateMachines = normalizeStateMachineSelection(configStateMachines);
     return configuredStateMachines.length ? configuredStateMachines : detectedStateMachines;
 }
 
-export function buildDemoBundlePayload({
-    artboardState = {},
-    controlSelectionKeys = [],
-    controlSnapshot = null,
-    currentFileBuffer,
-    currentLayoutAlignment = 'center',
-    currentCanvasSizing = null,
-    currentFileName,
-    currentLayoutFit = 'contain',
-    defaultInstantiationPackageSource = 'cdn',
-    editorConfig = {},
-    layoutState = {},
-    instantiationSnippets = null,
-    runtimeName,
-    runtimeScript,
-    runtimeVersion,
-    stateMachines = [],
-    transparencyState = {},
-    vmHierarchy = null,
-    instantiationCode = '',
-    instantiationSourceMode = 'internal',
-} = {}) {
-    return {
-        file_name: currentFileName,
-        animation_base64: arrayBufferToBase64(currentFileBuffer),
-        runtime_name: runtimeName,
-        runtime_version: runtimeVersion,
-        runtime_script: runtimeScript,
-        autoplay: typeof editorConfig.autoplay === 'boolean' ? editorConfig.autoplay : true,
-        layout_alignment: currentLayoutAlignment,
-        layout_fit: currentLayoutFit,
-        state_machines: stateMachines,
-        animations: artboardState.currentPlaybackType === 'animation' && artboardState.currentPlaybackName ? [artboardState.currentPlaybackName] : [],
-        artboard_name: artboardState.currentArtboard,
-        canvas_color: transparencyState.canvasTransparent ? null : transparencyState.canvasColor,
-        canvas_sizing: currentCanvasSizing ? JSON.stringify(currentCanvasSizing) : null,
-        canvas_transparent: transparencyState.canvasTransparent,
-        control_selection_keys: JSON.stringify(controlSelectionKeys),
-        control_snapshot: controlSnapshot ? JSON.stringify(controlSnapshot) : null,
-        default_instantiation_package_source: defaultInstantiationPackageSource,
-        instantiation_code: instantiationCode,
-        instantiation_snippets: instantiationSnippets ? JSON.stringify(instantiationSnippets) : null,
-        instantiation_source_mode: instantiationSourceMode,
-        layout_state: JSON.stringify(layoutState),
-        view_model_instance_name: artboardState.currentVmInstanceName ?? null,
-        vm_hierarchy: vmHierarchy ? JSON.stringify(vmHierarchy) : null,
-    };
-}
-
 export function createDemoExportController({
     callbacks = {},
     getArtboardStateSnapshot = () => ({}),
@@ -100,6 +40,7 @@ export function createDemoExportController({
     getSelectedControlKeys = () => null,
     getTransparencyStateSnapshot = () => ({}),
     getChangedVmControlSnapshot = () => [],
+    serializeStateMachineHierarchy = () => null,
     serializeVmHierarchy = () => null,
 } = {}) {
     const {
@@ -187,7 +128,7 @@ export function createDemoExportController({
         };
     }
 
-    async function buildExportContext({ packageSource = 'cdn', selectedControlKeys, snippetMode = 'compact' } = {}) {
+    async function buildExportContext({ artboards = [], packageSource = 'cdn', selectedControlKeys, snippetMode = 'compact', timeline = null } = {}) {
         const currentFileBuffer = getCurrentFileBuffer();
         const currentFileName = getCurrentFileName();
         if (!currentFileBuffer || !currentFileName) {
@@ -237,6 +178,7 @@ export function createDemoExportController({
             }),
         };
         const payload = buildDemoBundlePayload({
+            artboards,
             artboardState: {
                 currentArtboard: descriptor.artboard,
                 currentPlaybackName: descriptor.animations[0] || descriptor.stateMachines[0] || null,
@@ -264,7 +206,9 @@ export function createDemoExportController({
             runtimeName,
             runtimeScript: runtimeAsset.text,
             runtimeVersion: selectedRuntimeSemver,
+            stateMachineHierarchy: serializeStateMachineHierarchy(),
             stateMachines: descriptor.stateMachines,
+            timeline,
             transparencyState: getTransparencyStateSnapshot(),
             vmHierarchy: serializeVmHierarchy(),
         });
@@ -308,7 +252,16 @@ export function createDemoExportController({
         );
 
         try {
-            const outputPath = await invoke('make_demo_bundle', { payload: context.payload });
+            const report = await invoke('make_demo_bundle', { payload: context.payload });
+            if (report?.verification?.passed === false) {
+                const failed = report.verification.checks
+                    .filter((check) => !check.passed)
+                    .map((check) => (check.detail ? `${check.name}: ${check.detail}` : check.name));
+                showError(`Demo export failed verification (${failed.join('; ')})`);
+                logEvent('ui', 'demo-build-failed', 'Demo bundle failed verification.', report.verification);
+                return null;
+            }
+            const outputPath = report?.path ?? null;
             updateInfo(`Demo bundle saved to: ${outputPath}`);
             logEvent('ui', 'demo-build-success', `Demo bundle saved: ${outputPath}`);
             return outputPath;
@@ -325,19 +278,43 @@ export function createDemoExportController({
         }
     }
 
-    async function exportDemoToPath(outputPath, options = {}) {
+    async function exportArtifactToPath(command, outputPath, options = {}) {
         const invoke = getTauriInvoker();
         if (!invoke) {
             throw new Error('Export requires the Tauri desktop app');
         }
 
         const context = await buildExportContext(options);
-        logEvent('mcp', 'export', `Exporting demo to ${outputPath}`);
-        const result = await invoke('make_demo_bundle_to_path', { payload: context.payload, outputPath });
-        logEvent('mcp', 'export-complete', `Demo saved: ${result}`);
+        logEvent('mcp', 'export', `Exporting ${command} to ${outputPath}`);
+        const result = await invoke(command, {
+            ...(options.commandArgs || {}),
+            outputPath,
+            payload: context.payload,
+        });
+        logEvent('mcp', 'export-complete', `Export saved: ${typeof result === 'string' ? result : result?.path}`);
         return result;
     }
 
+    async function invokeArtifactCommand(command, options = {}) {
+        const invoke = getTauriInvoker();
+        if (!invoke) {
+            throw new Error('Export requires the Tauri desktop app');
+        }
+
+        const context = await buildExportContext(options);
+        return invoke(command, {
+            ...(options.commandArgs || {}),
+            payload: context.payload,
+        });
+    }
+
+    async function exportDemoToPath(outputPath, options = {}) {
+        return exportArtifactToPath('make_demo_bundle_to_path', outputPath, {
+            ...options,
+            commandArgs: { options: options.exportOptions ?? null, preset: options.preset ?? null },
+        });
+    }
+
     async function generateWebInstantiationCode({ packageSource = 'cdn', selectedControlKeys, snippetMode = 'compact' } = {}) {
         const context = await buildInstantiationContext({ packageSource, selectedControlKeys, snippetMode });
         return context.result;
@@ -346,7 +323,9 @@ export function createDemoExportController({
     return {
         buildExportContext,
         createDemoBundle,
+        exportArtifactToPath,
         exportDemoToPath,
         generateWebInstantiationCode,
+        invokeArtifactCommand,
     };
 }
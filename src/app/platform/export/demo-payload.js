@@ -0,0 +1,82 @@
+export function arrayBufferToBase64(buffer) {
+    if (!(buffer instanceof ArrayBuffer)) {
+        return '';
+    }
+    let binary = '';
+    const bytes = new Uint8Array(buffer);
+    const chunkSize = 0x8000;
+    for (let index = 0; index < bytes.length; index += chunkSize) {
+        const chunk = bytes.subarray(index, index + chunkSize);
+        binary += String.fromCharCode.apply(null, chunk);
+    }
+    return btoa(binary);
+}
+
+function serializeArtboardConfig(entry = {}) {
+    const toJson = (value) => (Array.isArray(value) ? JSON.stringify(value) : null);
+    return {
+        artboard_name: String(entry.artboard_name || ''),
+        state_machines: Array.isArray(entry.state_machines) ? entry.state_machines : [],
+        animations: Array.isArray(entry.animations) ? entry.animations : [],
+        layout_fit: entry.layout_fit ?? null,
+        layout_alignment: entry.layout_alignment ?? null,
+        control_snapshot: toJson(entry.control_snapshot),
+        control_selection_keys: toJson(entry.control_selection_keys),
+        view_model_instance_name: entry.view_model_instance_name ?? null,
+    };
+}
+
+export function buildDemoBundlePayload({
+    artboards = [],
+    artboardState = {},
+    controlSelectionKeys = [],
+    controlSnapshot = null,
+    currentFileBuffer,
+    currentLayoutAlignment = 'center',
+    currentCanvasSizing = null,
+    currentFileName,
+    currentLayoutFit = 'contain',
+    defaultInstantiationPackageSource = 'cdn',
+    editorConfig = {},
+    layoutState = {},
+    instantiationSnippets = null,
+    runtimeName,
+    runtimeScript,
+    runtimeVersion,
+    stateMachines = [],
+    stateMachineHierarchy = null,
+    timeline = null,
+    transparencyState = {},
+    vmHierarchy = null,
+    instantiationCode = '',
+    instantiationSourceMode = 'internal',
+} = {}) {
+    return {
+        file_name: currentFileName,
+        animation_base64: arrayBufferToBase64(currentFileBuffer),
+        runtime_name: runtimeName,
+        runtime_version: runtimeVersion,
+        runtime_script: runtimeScript,
+        autoplay: typeof editorConfig.autoplay === 'boolean' ? editorConfig.autoplay : true,
+        layout_alignment: currentLayoutAlignment,
+        layout_fit: currentLayoutFit,
+        state_machines: stateMachines,
+        state_machine_hierarchy: stateMachineHierarchy ? JSON.stringify(stateMachineHierarchy) : null,
+        animations: artboardState.currentPlaybackType === 'animation' && artboardState.currentPlaybackName ? [artboardState.currentPlaybackName] : [],
+        artboard_name: artboardState.currentArtboard,
+        artboards: Array.isArray(artboards) ? artboards.map(serializeArtboardConfig) : [],
+        canvas_color: transparencyState.canvasTransparent ? null : transparencyState.canvasColor,
+        canvas_sizing: currentCanvasSizing ? JSON.stringify(currentCanvasSizing) : null,
+        canvas_transparent: transparencyState.canvasTransparent,
+        control_selection_keys: JSON.stringify(controlSelectionKeys),
+        control_snapshot: controlSnapshot ? JSON.stringify(controlSnapshot) : null,
+        default_instantiation_package_source: defaultInstantiationPackageSource,
+        instantiation_code: instantiationCode,
+        instantiation_snippets: instantiationSnippets ? JSON.stringify(instantiationSnippets) : null,
+        instantiation_source_mode: instantiationSourceMode,
+        layout_state: JSON.stringify(layoutState),
+        timeline: timeline ?? null,
+        view_model_instance_name: artboardState.currentVmInstanceName ?? null,
+        vm_hierarchy: vmHierarchy ? JSON.stringify(vmHierarchy) : null,
+    };
+}
@@ -0,0 +1,30 @@
+const PRESET_COMMANDS = {
+    apply: 'apply_export_preset',
+    delete: 'delete_export_preset',
+    list: 'list_export_presets',
+    save: 'save_export_preset',
+};
+
+export function createExportPresetsClient({
+    getTauriInvoker = () => null,
+} = {}) {
+    async function run(action, args = {}) {
+        const command = PRESET_COMMANDS[action];
+        if (!command) {
+            throw new Error(`Unknown export preset action: ${action}`);
+        }
+        const invoke = getTauriInvoker();
+        if (!invoke) {
+            throw new Error('Export presets require the Tauri desktop app');
+        }
+        if (action === 'list') {
+            return invoke(command);
+        }
+        if (action === 'save') {
+            return invoke(command, { preset: args.preset });
+        }
+        return invoke(command, { name: args.name });
+    }
+
+    return { run };
+}
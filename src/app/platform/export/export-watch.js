@@ -0,0 +1,72 @@
+const WATCH_COMMANDS = {
+    start: 'start_export_watch',
+    status: 'get_export_watch_status',
+    stop: 'stop_export_watch',
+};
+
+export const EXPORT_WATCH_EVENT = 'export-watch';
+
+export function describeExportWatchEvent(event = {}) {
+    if (!event.ok) {
+        return `Watch export failed: ${event.error || 'unknown error'}`;
+    }
+    const diff = event.sizeDiff;
+    if (!diff) {
+        return `Re-exported ${event.outputPath}`;
+    }
+    const sign = diff.deltaBytes >= 0 ? '+' : '';
+    return `Re-exported ${event.outputPath} (${diff.currentBytes} bytes, ${sign}${diff.deltaBytes})`;
+}
+
+export function createExportWatchClient({
+    getCurrentFileSourcePath = () => '',
+    getTauriEventListener = async () => null,
+    getTauriInvoker = () => null,
+    logEvent = () => {},
+} = {}) {
+    let listening = false;
+    let lastEvent = null;
+
+    async function ensureListener() {
+        if (listening) {
+            return;
+        }
+        const listen = await getTauriEventListener();
+        if (typeof listen !== 'function') {
+            return;
+        }
+        listening = true;
+        try {
+            await listen(EXPORT_WATCH_EVENT, (event) => {
+                lastEvent = event?.payload ?? null;
+                logEvent('ui', 'export-watch', describeExportWatchEvent(lastEvent ?? {}), lastEvent);
+            });
+        } catch (error) {
+            listening = false;
+            console.warn('[rive-viewer] failed to register export-watch listener:', error);
+        }
+    }
+
+    async function run(action, args = {}) {
+        const command = WATCH_COMMANDS[action];
+        if (!command) {
+            throw new Error(`Unknown export watch action: ${action}`);
+        }
+        const invoke = getTauriInvoker();
+        if (!invoke) {
+            throw new Error('Export watch mode requires the Tauri desktop app');
+        }
+        if (action !== 'start') {
+            return { ...(await invoke(command)), lastEvent };
+        }
+        const sourcePath = args.sourcePath || getCurrentFileSourcePath();
+        if (!sourcePath) {
+            throw new Error('No source .riv path to watch; open a file from disk or pass source_path');
+        }
+        await ensureListener();
+        lastEvent = null;
+        return invoke(command, { sourcePath });
+    }
+
+    return { run };
+}
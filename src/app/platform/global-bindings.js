@@ -1,3 +1,5 @@
+import { RESOURCE_URIS, announceResourceUpdates } from './mcp/resource-updates.js';
+
 export function updateMcpStatusChip(chip, state) {
     if (!chip) {
         return;
@@ -24,7 +26,11 @@ export function createGlobalBindingsController({
         applyCodeAndReload = async () => {},
         createDemoBundle = async () => {},
         ensureEditorReady = async () => true,
+        exportArtifactToPath = async () => {},
         exportDemoToPath = async () => {},
+        invokeArtifactCommand = async () => null,
+        runExportPresetAction = async () => null,
+        runExportWatchAction = async () => null,
         getArtboardStateSnapshot = () => ({}),
         getCurrentCanvasSizing = () => null,
         getCurrentFileBuffer = () => null,
@@ -39,6 +45,7 @@ export function createGlobalBindingsController({
             sourceMode: 'internal',
         }),
         getSidebarVisibility = () => ({ left: false, right: true }),
+        getTimelineRecorder = () => null,
         getScriptConsoleEntries = () => ({ total: 0, returned: 0, entries: [] }),
         getVmExplorerSnippetState = () => ({ injected: false }),
         getRuntimeSourceText = () => '',
@@ -141,7 +148,9 @@ export function createGlobalBindingsController({
         };
         windowRef._mcpSetEditorCode = async (code) => {
             await ensureEditorReady();
-            return setEditorCode(code);
+            const applied = setEditorCode(code);
+            announceResourceUpdates([RESOURCE_URIS.editorCode], windowRef.document);
+            return applied;
         };
         windowRef._mcpLogEvent = (type, message, payload) => logEvent('mcp', type, message, payload);
         windowRef._mcpUpdateStatus = (state) => {
@@ -151,7 +160,11 @@ export function createGlobalBindingsController({
             elements.mcpStatusChip,
             windowRef._mcpBridge?.indicatorState || windowRef._mcpBridge?.state || 'off',
         );
+        windowRef._mcpExportArtifactToPath = async (command, outputPath, options) => exportArtifactToPath(command, outputPath, options);
         windowRef._mcpExportDemoToPath = async (outputPath, options) => exportDemoToPath(outputPath, options);
+        windowRef._mcpInvokeArtifactCommand = async (command, options) => invokeArtifactCommand(command, options);
+        windowRef._mcpExportPresets = async (action, args) => runExportPresetAction(action, args);
+        windowRef._mcpExportWatch = async (action, args) => runExportWatchAction(action, args);
         windowRef._mcpGenerateWebInstantiationCode = async (packageSource, snippetMode) => getGenerateWebInstantiationCode(packageSource, snippetMode);
         windowRef._mcpSwitchArtboard = switchArtboard;
         windowRef._mcpResetArtboard = resetToDefaultArtboard;
@@ -164,6 +177,7 @@ export function createGlobalBindingsController({
         );
         windowRef._mcpGetLiveConfigState = () => getLiveConfigState();
         windowRef._mcpGetSidebarVisibility = () => getSidebarVisibility();
+        windowRef._mcpGetTimelineRecorder = () => getTimelineRecorder();
         windowRef._mcpGetVmExplorerSnippetState = () => getVmExplorerSnippetState();
         windowRef._mcpSetLiveConfigSource = async (sourceMode) => setLiveConfigSource(sourceMode);
         windowRef._mcpSetSidebarVisibility = (visibility) => setSidebarVisibility(visibility);
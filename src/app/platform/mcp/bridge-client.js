@@ -1,3 +1,4 @@
+import { TOOLS } from '../../../../mcp-server/tools/index.js';
 import {
     DEFAULT_MCP_BRIDGE_PORT,
     getTauriInvoker,
@@ -7,6 +8,11 @@ import {
     readInitialBridgePort,
 } from './bridge-port.js';
 import { createMcpCommandHandlers } from './command-handlers.js';
+import {
+    RESOURCE_URIS,
+    RESOURCES_UPDATED_EVENT,
+    createResourceUpdateRelay,
+} from './resource-updates.js';
 import { assertMcpScriptAccess } from './script-access.js';
 import { createMcpBridgeTransport } from './transport.js';
 import { buildViewModelSnapshot } from './view-model-snapshot.js';
@@ -152,6 +158,8 @@ const transport = createMcpBridgeTransport({
     getReconnectDelay: () => state.reconnectDelay,
     getSocket: () => state.socket,
     getState: () => ({ ...state, indicatorState: getIndicatorState() }),
+    // Advertise only the tools this build can run, so the sidecar lists what the app supports.
+    getToolCatalog: () => TOOLS.filter((tool) => typeof commandHandlers[tool.name] === 'function'),
     getWatchdogIntervalMs: () => WATCHDOG_INTERVAL_MS,
     onConnected: () => {
         clearCommandActivity();
@@ -200,6 +208,11 @@ const transport = createMcpBridgeTransport({
     windowRef: window,
 });
 
+const resourceUpdateRelay = createResourceUpdateRelay({
+    send: (payload) => transport.sendBridgeEvent(payload),
+    setTimeoutFn: (callback, delay) => window.setTimeout(callback, delay),
+});
+
 window._mcpBridge = {
     commands: commandHandlers,
     get connected() { return state.connected; },
@@ -286,6 +299,13 @@ window._mcpBridge = {
     },
 };
 
+document.addEventListener(RESOURCES_UPDATED_EVENT, (event) => {
+    resourceUpdateRelay.queue(event.detail?.uris);
+});
+document.addEventListener('rav:vm-topology-changed', () => {
+    resourceUpdateRelay.queue([RESOURCE_URIS.vmTree]);
+});
+
 window.addEventListener('focus', () => transport.reconnectNow());
 window.addEventListener('pageshow', () => transport.reconnectNow());
 window.addEventListener('online', () => transport.reconnectNow());
@@ -5,11 +5,50 @@ export function createExportWorkspaceCommands({
     windowRef = globalThis.window,
 } = {}) {
     const sleep = (ms) => new Promise((resolve) => setTimeout(resolve, Math.max(0, ms || 0)));
+    // A failed verification still returns the report; the previous file is kept and `ok` is false.
+    const verifiedOk = (report) => report?.verification?.passed !== false;
 
     return {
-        async rav_export_demo({ output_path } = {}) {
-            if (output_path && typeof windowRef._mcpExportDemoToPath === 'function') {
-                return { ok: true, path: await windowRef._mcpExportDemoToPath(output_path) };
+        async rav_export_demo({
+            output_path,
+            artboards = [],
+            content_hash,
+            csp,
+            embed,
+            embed_base_url = null,
+            interface_manifest,
+            max_size_bytes = null,
+            preset = null,
+            timeline = null,
+        } = {}) {
+            if ((output_path || preset) && typeof windowRef._mcpExportDemoToPath === 'function') {
+                const explicitOptions = [content_hash, csp, embed, embed_base_url, interface_manifest, max_size_bytes]
+                    .some((value) => value !== undefined && value !== null);
+                // A preset's saved export options apply unless the call sets its own.
+                const exportOptions = preset && !explicitOptions ? null : {
+                    content_hash: Boolean(content_hash),
+                    csp: Boolean(csp),
+                    embed: Boolean(embed),
+                    embed_base_url,
+                    interface_manifest: Boolean(interface_manifest),
+                    max_size_bytes,
+                };
+                const presetSettings = preset ? await windowRef._mcpExportPresets('apply', { name: preset }) : null;
+                const report = await windowRef._mcpExportDemoToPath(output_path || '', {
+                    artboards,
+                    exportOptions,
+                    packageSource: presetSettings?.package_source ?? undefined,
+                    preset,
+                    selectedControlKeys: presetSettings?.control_selection_keys ?? undefined,
+                    timeline,
+                });
+                return {
+                    ok: verifiedOk(report),
+                    path: report?.path,
+                    verification: report?.verification,
+                    ...(report?.contentSecurityPolicy ? { content_security_policy: report.contentSecurityPolicy } : {}),
+                    ...(report?.warnings?.length ? { warnings: report.warnings } : {}),
+                };
             }
             if (!output_path && typeof windowRef.createDemoBundle === 'function') {
                 const result = await windowRef.createDemoBundle();
@@ -18,6 +57,68 @@ export function createExportWorkspaceCommands({
             throw new Error('Export not available');
         },
 
+        async rav_export_spec_sheet({ output_path, format = null, package_source = 'cdn' } = {}) {
+            if (!output_path) throw new Error('output_path is required');
+            if (typeof windowRef._mcpExportArtifactToPath !== 'function') {
+                throw new Error('Export-to-path binding not available');
+            }
+            const path = await windowRef._mcpExportArtifactToPath('make_spec_sheet_to_path', output_path, {
+                commandArgs: { format },
+                packageSource: package_source,
+            });
+            return { ok: true, path };
+        },
+
+        async rav_export_web_component({ output_path, tag_name = null, riv_url = null } = {}) {
+            if (!output_path) throw new Error('output_path is required');
+            if (typeof windowRef._mcpExportArtifactToPath !== 'function') {
+                throw new Error('Export-to-path binding not available');
+            }
+            const path = await windowRef._mcpExportArtifactToPath('make_web_component_to_path', output_path, {
+                commandArgs: { options: { tag_name, riv_url } },
+            });
+            return { ok: true, path };
+        },
+
+        async rav_export_framework_starter({ output_path, framework, package_source = 'cdn' } = {}) {
+            if (!output_path) throw new Error('output_path is required');
+            if (!framework) throw new Error('framework is required');
+            if (typeof windowRef._mcpExportArtifactToPath !== 'function') {
+                throw new Error('Export-to-path binding not available');
+            }
+            const path = await windowRef._mcpExportArtifactToPath('make_framework_starter_to_path', output_path, {
+                commandArgs: { framework },
+                packageSource: package_source,
+            });
+            return { ok: true, path };
+        },
+
+        async rav_export_typescript_bindings({ output_path } = {}) {
+            if (!output_path) throw new Error('output_path is required');
+            if (typeof windowRef._mcpExportArtifactToPath !== 'function') {
+                throw new Error('Export-to-path binding not available');
+            }
+            const path = await windowRef._mcpExportArtifactToPath('make_typescript_bindings_to_path', output_path);
+            return { ok: true, path };
+        },
+
+        async rav_export_pwa({ output_path, csp, max_size_bytes = null } = {}) {
+            if (!output_path) throw new Error('output_path is required');
+            if (typeof windowRef._mcpExportArtifactToPath !== 'function') {
+                throw new Error('Export-to-path binding not available');
+            }
+            const report = await windowRef._mcpExportArtifactToPath('make_pwa_demo_to_path', output_path, {
+                commandArgs: { options: { csp: Boolean(csp), max_size_bytes } },
+            });
+            return {
+                ok: verifiedOk(report),
+                path: report?.path,
+                verification: report?.verification,
+                ...(report?.contentSecurityPolicy ? { content_security_policy: report.contentSecurityPolicy } : {}),
+                ...(report?.warnings?.length ? { warnings: report.warnings } : {}),
+            };
+        },
+
         async generate_web_instantiation_code({ package_source = 'cdn', snippet_mode = 'compact' } = {}) {
             if (typeof windowRef._mcpGenerateWebInstantiationCode !== 'function') {
                 throw new Error('Web instantiation generator not available');
@@ -25,6 +126,47 @@ export function createExportWorkspaceCommands({
             return windowRef._mcpGenerateWebInstantiationCode(package_source, snippet_mode);
         },
 
+        async generate_native_instantiation_code({ platform } = {}) {
+            if (!platform) throw new Error('platform is required');
+            if (typeof windowRef._mcpInvokeArtifactCommand !== 'function') {
+                throw new Error('Native instantiation generator not available');
+            }
+            return windowRef._mcpInvokeArtifactCommand('generate_native_instantiation_code', {
+                commandArgs: { platform },
+            });
+        },
+
+        async rav_export_presets({ action = 'list', name, preset } = {}) {
+            if (typeof windowRef._mcpExportPresets !== 'function') {
+                throw new Error('Export presets not available');
+            }
+            if (!['list', 'save', 'delete'].includes(action)) throw new Error(`Unknown action: ${action}`);
+            if (action === 'delete' && !name) throw new Error('name is required');
+            if (action === 'save' && !preset?.name) throw new Error('preset.name is required');
+            return { ok: true, presets: await windowRef._mcpExportPresets(action, { name, preset }) };
+        },
+
+        async rav_export_watch({ action = 'status', source_path } = {}) {
+            if (typeof windowRef._mcpExportWatch !== 'function') {
+                throw new Error('Export watch mode not available');
+            }
+            if (!['start', 'stop', 'status'].includes(action)) throw new Error(`Unknown action: ${action}`);
+            return { ok: true, ...(await windowRef._mcpExportWatch(action, { sourcePath: source_path })) };
+        },
+
+        async rav_record_timeline({ action = 'status' } = {}) {
+            const recorder = windowRef._mcpGetTimelineRecorder?.();
+            if (!recorder) throw new Error('Timeline recorder not available');
+            if (action === 'start') return { ok: true, ...recorder.start() };
+            if (action === 'stop') {
+                const timeline = recorder.stop();
+                if (!timeline) throw new Error('No timeline recording in progress');
+                return { ok: true, timeline };
+            }
+            if (action === 'status') return { ok: true, ...recorder.status() };
+            throw new Error(`Unknown action: ${action}`);
+        },
+
         async rav_toggle_instantiation_controls_dialog({ action = 'toggle' } = {}) {
             if (typeof windowRef._mcpToggleInstantiationControlsDialog !== 'function') {
                 throw new Error('Instantiation controls dialog not available');
@@ -38,7 +180,7 @@ export function createExportWorkspaceCommands({
             package_source,
             snippet_mode,
             step_delay_ms = 250,
-        } = {}) {
+        } = {}, { reportProgress = () => {}, signal } = {}) {
             if (!output_path) throw new Error('output_path is required');
             if (typeof windowRef._mcpToggleInstantiationControlsDialog !== 'function') {
                 throw new Error('Instantiation controls dialog binding not available');
@@ -47,8 +189,18 @@ export function createExportWorkspaceCommands({
                 throw new Error('Export-to-path binding not available');
             }
 
+            const step = async (progress, message) => {
+                if (signal?.aborted) {
+                    await windowRef._mcpToggleInstantiationControlsDialog('close');
+                    throw new Error('Export cancelled');
+                }
+                reportProgress(progress, 4, message);
+            };
+
+            reportProgress(0, 4, 'Opening instantiation controls');
             await windowRef._mcpToggleInstantiationControlsDialog('open');
             await sleep(step_delay_ms);
+            await step(1, 'Selecting controls');
 
             if (selection === 'all') {
                 documentRef.getElementById('instantiation-preset-all-btn')?.click();
@@ -86,6 +238,7 @@ export function createExportWorkspaceCommands({
                 }
             }
             if (selection !== undefined) await sleep(step_delay_ms);
+            await step(2, 'Applying snippet options');
 
             if (package_source) {
                 const select = documentRef.getElementById('instantiation-package-source-select');
@@ -105,6 +258,7 @@ export function createExportWorkspaceCommands({
                 }
             }
 
+            await step(3, 'Writing demo bundle');
             const exportBtn = documentRef.getElementById('instantiation-dialog-export-btn');
             if (exportBtn) {
                 exportBtn.classList.add('is-pressing');
@@ -117,14 +271,15 @@ export function createExportWorkspaceCommands({
             const selectedControlKeys = Array.from(new Set(Array.from(documentRef.querySelectorAll(
                 '#instantiation-controls-tree input[type="checkbox"][data-control-key]:checked',
             )).map((checkbox) => checkbox.getAttribute('data-control-key')).filter(Boolean)));
-            const savedPath = await windowRef._mcpExportDemoToPath(output_path, {
+            const report = await windowRef._mcpExportDemoToPath(output_path, {
                 packageSource: packageSourceSelect?.value === 'local' ? 'local' : 'cdn',
                 selectedControlKeys,
                 snippetMode: snippetModeSelect?.value === 'scaffold' ? 'scaffold' : 'compact',
             });
             await windowRef._mcpToggleInstantiationControlsDialog('close');
+            reportProgress(4, 4, 'Export complete');
 
-            return { ok: true, path: savedPath };
+            return { ok: verifiedOk(report), path: report?.path, verification: report?.verification };
         },
 
         async rav_configure_workspace({
@@ -69,7 +69,7 @@ export function createStatusPlaybackCommands({
             return { ok: true };
         },
 
-        async rav_open_file({ path }) {
+        async rav_open_file({ path }, { reportProgress = () => {}, signal } = {}) {
             if (!path) throw new Error('path is required');
             const invoke = getTauriInvoker(windowRef);
             if (!invoke) {
@@ -77,7 +77,11 @@ export function createStatusPlaybackCommands({
                     'File opening requires the Tauri desktop app. In the browser, drag and drop a .riv file onto the canvas instead.',
                 );
             }
+            reportProgress(0, 2, 'Reading file');
             const base64 = await invoke('read_riv_file', { path });
+            // Past this point the current file is replaced, so a cancel must land before it.
+            if (signal?.aborted) throw new Error('Open cancelled');
+            reportProgress(1, 2, 'Loading animation');
             const binary = atob(base64);
             const bytes = new Uint8Array(binary.length);
             for (let index = 0; index < binary.length; index += 1) {
@@ -95,6 +99,7 @@ export function createStatusPlaybackCommands({
                 windowRef._mcpSetCurrentFile(fileUrl, fileName, true, buffer, blob.type, buffer.byteLength, { sourcePath: path });
                 transferredToSession = true;
                 await windowRef._mcpLoadAnimation(fileUrl, fileName, { forceAutoplay: true });
+                reportProgress(2, 2, 'Loaded');
                 return { ok: true, file: fileName, sizeBytes: buffer.byteLength };
             } catch (error) {
                 if (!transferredToSession) {
@@ -142,9 +147,17 @@ export function createStatusPlaybackCommands({
             return { artboards: [{ name: inst.artboardName || '(default)' }] };
         },
 
-        async rav_get_state_machines() {
+        async rav_get_state_machines({ artboard } = {}) {
             const inst = windowRef.riveInst;
             if (!inst) throw new Error('No animation loaded');
+            if (artboard && artboard !== inst.artboardName) {
+                const match = inst.contents?.artboards?.find((entry) => entry?.name === artboard);
+                if (!match) throw new Error(`Artboard "${artboard}" not found`);
+                return {
+                    artboard,
+                    stateMachines: (match.stateMachines || []).map((stateMachine) => stateMachine.name || stateMachine),
+                };
+            }
             const names = inst.stateMachineNames;
             if (Array.isArray(names) && names.length > 0) {
                 return { stateMachines: names };
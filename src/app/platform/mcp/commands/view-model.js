@@ -4,6 +4,7 @@ import {
     getVmListLength,
     safeVmMethodCall,
 } from '../../../rive/view-model/accessors.js';
+import { announceResourceUpdates, vmPropertyUri } from '../resource-updates.js';
 
 const VALUE_KINDS = new Set(['number', 'boolean', 'string', 'enum', 'color']);
 const LIST_INDEX_PATTERN = /^(0|[1-9]\d*)$/;
@@ -137,6 +138,7 @@ export function createViewModelCommands({
             }
 
             resolved.accessor.value = value;
+            announceResourceUpdates([vmPropertyUri(resolved.path)], windowRef?.document);
             let appliedValue = value;
             try {
                 appliedValue = resolved.accessor.value;
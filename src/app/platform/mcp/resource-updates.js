@@ -0,0 +1,50 @@
+export const RESOURCES_UPDATED_EVENT = 'rav:mcp-resources-updated';
+
+export const RESOURCE_URIS = {
+    editorCode: 'rav://current/editor-code',
+    eventLog: 'rav://current/event-log',
+    fileInfo: 'rav://current/file-info',
+    vmTree: 'rav://current/vm-tree',
+};
+
+export function vmPropertyUri(path) {
+    return `rav://vm/${String(path || '').split('/').map(encodeURIComponent).join('/')}`;
+}
+
+export function announceResourceUpdates(uris, documentRef = globalThis.document) {
+    const EventConstructor = documentRef?.defaultView?.CustomEvent;
+    if (!EventConstructor || !Array.isArray(uris) || uris.length === 0) {
+        return;
+    }
+    documentRef.dispatchEvent(new EventConstructor(RESOURCES_UPDATED_EVENT, { detail: { uris } }));
+}
+
+export function createResourceUpdateRelay({
+    delayMs = 150,
+    send = () => false,
+    setTimeoutFn = globalThis.setTimeout,
+} = {}) {
+    const pending = new Set();
+    let flushTimer = null;
+
+    function flush() {
+        flushTimer = null;
+        if (pending.size === 0) {
+            return;
+        }
+        const uris = [...pending].sort();
+        pending.clear();
+        send({ bridgeEvent: 'resources-updated', uris });
+    }
+
+    function queue(uris = []) {
+        uris.forEach((uri) => {
+            if (typeof uri === 'string' && uri) pending.add(uri);
+        });
+        if (pending.size > 0 && flushTimer === null) {
+            flushTimer = setTimeoutFn(flush, delayMs);
+        }
+    }
+
+    return { flush, queue };
+}
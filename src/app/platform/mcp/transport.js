@@ -42,6 +42,7 @@ export function createMcpBridgeTransport({
     getReconnectDelay,
     getSocket,
     getState,
+    getToolCatalog = () => [],
     getWatchdogIntervalMs = () => 1500,
     onConnected = () => {},
     onConnecting = () => {},
@@ -59,6 +60,7 @@ export function createMcpBridgeTransport({
 } = {}) {
     let connectTimeoutTimer = null;
     let connectStartedAt = 0;
+    const runningCommands = new Map();
 
     function syncState() {
         const state = getState();
@@ -126,7 +128,7 @@ export function createMcpBridgeTransport({
                     return;
                 }
                 try {
-                    nextSocket.send(JSON.stringify({ bridgeHello: 'rav-app' }));
+                    nextSocket.send(JSON.stringify({ bridgeHello: 'rav-app', tools: getToolCatalog() }));
                 } catch (error) {
                     console.warn('[rav-mcp-bridge] Failed to send bridge handshake', error);
                     nextSocket.close();
@@ -158,6 +160,15 @@ export function createMcpBridgeTransport({
                     return;
                 }
 
+                if (message?.bridgeEvent === 'cancel') {
+                    const controller = runningCommands.get(message.id);
+                    if (controller && !controller.signal.aborted) {
+                        controller.abort();
+                        mcpLog('error', `Request ${message.id} cancelled by the MCP client`, undefined, windowRef);
+                    }
+                    return;
+                }
+
                 const { id, command, params } = message;
                 if (!id || !command) return;
                 const handler = commandHandlers[command];
@@ -170,16 +181,32 @@ export function createMcpBridgeTransport({
                 mcpLog('recv', formatCommandSummary(command, params), undefined, windowRef);
                 onCommandStart(command);
                 const startedAt = performance.now();
+                const controller = new AbortController();
+                runningCommands.set(id, controller);
+                // Handlers may report progress and should stop early once the signal aborts;
+                // a cancelled command's reply is dropped because the server has moved on.
+                const context = {
+                    reportProgress(progress, total, progressMessage) {
+                        if (controller.signal.aborted) return;
+                        sendBridgeEvent({ bridgeEvent: 'progress', id, progress, total, message: progressMessage });
+                    },
+                    signal: controller.signal,
+                };
                 try {
-                    const result = await handler(params || {});
+                    const result = await handler(params || {}, context);
                     const elapsed = Math.round(performance.now() - startedAt);
-                    mcpLog('reply', `${command.replace(/^rav_/, '')} → ${formatResultSummary(command, result)}  (${elapsed}ms)`, undefined, windowRef);
-                    nextSocket.send(JSON.stringify({ id, result }));
-                    onCommandEnd(command);
+                    if (!controller.signal.aborted) {
+                        mcpLog('reply', `${command.replace(/^rav_/, '')} → ${formatResultSummary(command, result)}  (${elapsed}ms)`, undefined, windowRef);
+                        nextSocket.send(JSON.stringify({ id, result }));
+                    }
                 } catch (error) {
                     const elapsed = Math.round(performance.now() - startedAt);
-                    mcpLog('error', `${command.replace(/^rav_/, '')} failed: ${error.message}  (${elapsed}ms)`, undefined, windowRef);
-                    nextSocket.send(JSON.stringify({ id, error: error.message }));
+                    if (!controller.signal.aborted) {
+                        mcpLog('error', `${command.replace(/^rav_/, '')} failed: ${error.message}  (${elapsed}ms)`, undefined, windowRef);
+                        nextSocket.send(JSON.stringify({ id, error: error.message }));
+                    }
+                } finally {
+                    runningCommands.delete(id);
                     onCommandEnd(command);
                 }
             };
@@ -188,6 +215,7 @@ export function createMcpBridgeTransport({
                 if (getSocket() !== nextSocket) return;
                 clearConnectTimeout();
                 setSocket(null);
+                runningCommands.forEach((controller) => controller.abort());
                 const wasConnected = getState().connected;
                 onDisconnected({ unexpected: getEnabled(), wasConnected });
                 syncState();
@@ -248,6 +276,20 @@ export function createMcpBridgeTransport({
         connect();
     }
 
+    function sendBridgeEvent(payload) {
+        const socket = getSocket();
+        if (!getState().connected || !socket || socket.readyState !== WebSocket.OPEN) {
+            return false;
+        }
+        try {
+            socket.send(JSON.stringify(payload));
+            return true;
+        } catch (error) {
+            console.warn('[rav-mcp-bridge] Failed to send bridge event', error);
+            return false;
+        }
+    }
+
     function startWatchdog() {
         if (getState().watchdogTimer) return;
         const timer = setInterval(() => {
@@ -272,6 +314,7 @@ export function createMcpBridgeTransport({
         disconnect,
         reconnectNow,
         scheduleReconnect,
+        sendBridgeEvent,
         startWatchdog,
         syncState,
     };
@@ -44,6 +44,7 @@ export function createVmControlsController({
     const {
         initLucideIcons = () => {},
         logEvent = () => {},
+        recordControlChange = () => {},
     } = callbacks;
 
     let vmControlBindings = [];
@@ -202,6 +203,7 @@ export function createVmControlsController({
         fireStateMachineTriggerByName,
         getRiveInstance,
         logEvent,
+        onControlChange: recordControlChange,
         registerVmControlBinding,
         resolveControlAccessor,
         resolveVmAccessor,
@@ -310,6 +312,7 @@ export function createVmControlsController({
         renderVmInputControls,
         resetVmInputControls: resetControls,
         serializeControlHierarchy: snapshotController.serializeControlHierarchy,
+        serializeStateMachineHierarchy: snapshotController.serializeStateMachineHierarchy,
         serializeVmHierarchy: snapshotController.serializeVmHierarchy,
         setVmControlBaselineSnapshot: snapshotController.setVmControlBaselineSnapshot,
         stopVmControlSync,
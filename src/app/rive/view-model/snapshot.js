@@ -308,6 +308,13 @@ export function createVmSnapshotController({
         return serializeHierarchyNode(stripNestedRootVmInputs(hierarchy), resolveControlAccessor);
     }
 
+    function serializeStateMachineHierarchy() {
+        const stateMachineHierarchy = buildStateMachineHierarchy();
+        return stateMachineHierarchy
+            ? serializeHierarchyNode(stateMachineHierarchy, resolveControlAccessor)
+            : null;
+    }
+
     function serializeControlHierarchy() {
         const rootVm = resolveVmRootInstance(getRiveInstance());
         const vmHierarchy = rootVm ? stripNestedRootVmInputs(buildVmHierarchy(rootVm)) : null;
@@ -347,6 +354,7 @@ export function createVmSnapshotController({
         reconcileVmControlBaselineSnapshot,
         retryPendingVmControlSnapshot,
         serializeControlHierarchy,
+        serializeStateMachineHierarchy,
         serializeVmHierarchy,
         setVmControlBaselineSnapshot,
     };
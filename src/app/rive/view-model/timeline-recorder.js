@@ -0,0 +1,68 @@
+export function createTimelineRecorder({
+    now = () => globalThis.performance.now(),
+} = {}) {
+    let startedAt = null;
+    let steps = [];
+
+    function isRecording() {
+        return startedAt !== null;
+    }
+
+    function status() {
+        return {
+            recording: isRecording(),
+            elapsedMs: isRecording() ? Math.round(now() - startedAt) : 0,
+            stepCount: steps.length,
+        };
+    }
+
+    function start() {
+        startedAt = now();
+        steps = [];
+        return status();
+    }
+
+    function stop() {
+        if (!isRecording()) {
+            return null;
+        }
+        const durationMs = Math.round(now() - startedAt);
+        const timeline = {
+            loop: false,
+            autoplay: true,
+            duration_ms: durationMs,
+            steps,
+        };
+        startedAt = null;
+        steps = [];
+        return timeline;
+    }
+
+    function record(descriptor, value) {
+        if (!isRecording() || !descriptor?.kind) {
+            return;
+        }
+        const step = {
+            at_ms: Math.round(now() - startedAt),
+            kind: descriptor.kind,
+        };
+        if (descriptor.source === 'state-machine') {
+            step.state_machine = descriptor.stateMachineName;
+            step.input = descriptor.name;
+        } else {
+            step.path = descriptor.path;
+        }
+        if (descriptor.kind !== 'trigger') {
+            step.value = value;
+        }
+        steps.push(step);
+    }
+
+    return {
+        isRecording,
+        record,
+        start,
+        status,
+        stop,
+    };
+}
@@ -20,6 +20,7 @@ export function createVmControlRowFactory({
     fireStateMachineTriggerByName,
     getRiveInstance,
     logEvent,
+    onControlChange = () => {},
     registerVmControlBinding,
     resolveControlAccessor,
     resolveVmAccessor,
@@ -55,6 +56,7 @@ export function createVmControlRowFactory({
                     liveAccessor.value = nextValue;
                     const source = descriptor.source === 'state-machine' ? 'sm-number' : 'vm-number';
                     logEvent('ui', source, `Set ${descriptor.path} = ${nextValue}`);
+                    onControlChange(descriptor, nextValue);
                 }
             });
             registerVmControlBinding(descriptor, { input: numberInput, kind: 'number' });
@@ -70,6 +72,7 @@ export function createVmControlRowFactory({
                     liveAccessor.value = checkbox.checked;
                     const source = descriptor.source === 'state-machine' ? 'sm-boolean' : 'vm-boolean';
                     logEvent('ui', source, `Set ${descriptor.path} = ${checkbox.checked}`);
+                    onControlChange(descriptor, checkbox.checked);
                 }
             });
             registerVmControlBinding(descriptor, { input: checkbox, kind: 'boolean' });
@@ -87,6 +90,7 @@ export function createVmControlRowFactory({
                 if (liveAccessor) {
                     liveAccessor.value = textInput.value;
                     logEvent('ui', 'vm-string', `Set ${descriptor.path} = ${textInput.value}`);
+                    onControlChange(descriptor, textInput.value);
                 }
             });
             registerVmControlBinding(descriptor, { input: textInput, kind: 'string' });
@@ -115,6 +119,7 @@ export function createVmControlRowFactory({
                 if (liveAccessor) {
                     liveAccessor.value = select.value;
                     logEvent('ui', 'vm-enum', `Set ${descriptor.path} = ${select.value}`);
+                    onControlChange(descriptor, select.value);
                 }
             });
             registerVmControlBinding(descriptor, { input: select, kind: 'enum' });
@@ -146,13 +151,14 @@ export function createVmControlRowFactory({
                 const alphaPercent = clamp(Number(alphaInput.value), 0, 100);
                 alphaInput.value = String(Math.round(alphaPercent));
                 const alpha = Math.round((alphaPercent / 100) * 255);
+                const argb = rgbAlphaToArgb(rgb.r, rgb.g, rgb.b, alpha);
                 if (typeof liveAccessor.argb === 'function') {
                     liveAccessor.argb(alpha, rgb.r, rgb.g, rgb.b);
-                    logEvent('ui', 'vm-color', `Set ${descriptor.path} color to ${colorInput.value} (${alphaPercent}%).`);
-                    return;
+                } else {
+                    liveAccessor.value = argb;
                 }
-                liveAccessor.value = rgbAlphaToArgb(rgb.r, rgb.g, rgb.b, alpha);
                 logEvent('ui', 'vm-color', `Set ${descriptor.path} color to ${colorInput.value} (${alphaPercent}%).`);
+                onControlChange(descriptor, argb);
             };
 
             colorInput.addEventListener('input', applyColor);
@@ -195,6 +201,7 @@ export function createVmControlRowFactory({
                     const suffix = firedStateMachineCount > 0 ? ` (+${firedStateMachineCount} state machine trigger matches)` : '';
                     const source = descriptor.source === 'state-machine' ? 'sm-trigger' : 'vm-trigger';
                     logEvent('ui', source, `Fired trigger ${descriptor.path}${suffix}`);
+                    onControlChange(descriptor, null);
                 } else {
                     const source = descriptor.source === 'state-machine' ? 'sm-trigger-miss' : 'vm-trigger-miss';
                     logEvent('ui', source, `No trigger accessor or state machine trigger matched ${descriptor.path}`);
@@ -8,6 +8,7 @@ export function createEventLogController({
     handleResize,
     navigatorRef = globalThis.navigator,
     onCollapsedChange = () => {},
+    onEntryAdded = () => {},
     setTimeoutFn = globalThis.setTimeout,
 }) {
     const eventLogEntries = [];
@@ -177,18 +178,20 @@ export function createEventLogController({
     }
 
     function logEvent(source, type, message, payload) {
-        eventLogEntries.push({
+        const entry = {
             id: ++eventLogSequence,
             source,
             type,
             message,
             payload,
             timestamp: Date.now(),
-        });
+        };
+        eventLogEntries.push(entry);
         if (eventLogEntries.length > EVENT_LOG_LIMIT) {
             eventLogEntries.splice(0, eventLogEntries.length - EVENT_LOG_LIMIT);
         }
         renderEventLog();
+        onEntryAdded(entry);
     }
 
     function getVisibleEntries() {
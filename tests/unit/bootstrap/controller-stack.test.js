@@ -94,6 +94,7 @@ describe('bootstrap/controller-stack', () => {
             resetToDefaultArtboard: vi.fn(),
             resetVmInputControls: vi.fn(),
             serializeControlHierarchy: vi.fn(() => null),
+            serializeStateMachineHierarchy: vi.fn(() => null),
             serializeVmHierarchy: vi.fn(() => null),
             switchArtboard: vi.fn(),
         });
@@ -36,6 +36,7 @@ describe('platform/demo-export', () => {
             runtimeName: 'webgl2',
             runtimeScript: 'runtime();',
             runtimeVersion: '2.0.0',
+            stateMachineHierarchy: { kind: 'state-machines', children: [{ kind: 'state-machine', label: 'main-sm' }] },
             stateMachines: ['main-sm'],
             transparencyState: {
                 canvasColor: '#112233',
@@ -58,12 +59,44 @@ describe('platform/demo-export', () => {
             runtime_name: 'webgl2',
             runtime_script: 'runtime();',
             runtime_version: '2.0.0',
+            state_machine_hierarchy: '{"kind":"state-machines","children":[{"kind":"state-machine","label":"main-sm"}]}',
             state_machines: ['main-sm'],
             view_model_instance_name: 'Preview',
             vm_hierarchy: '{"root":"vm"}',
         }));
     });
 
+    it('serializes extra artboard configurations for the demo switcher', () => {
+        const payload = buildDemoBundlePayload({
+            artboards: [
+                {
+                    artboard_name: 'Card',
+                    state_machines: ['card-sm'],
+                    layout_fit: 'cover',
+                    control_snapshot: [{ descriptor: { kind: 'string', path: 'title' }, kind: 'string', value: 'Hi' }],
+                },
+                {},
+            ],
+            currentFileBuffer: Uint8Array.from([1]).buffer,
+            currentFileName: 'demo.riv',
+        });
+
+        expect(payload.artboards).toEqual([
+            {
+                artboard_name: 'Card',
+                state_machines: ['card-sm'],
+                animations: [],
+                layout_fit: 'cover',
+                layout_alignment: null,
+                control_snapshot: '[{"descriptor":{"kind":"string","path":"title"},"kind":"string","value":"Hi"}]',
+                control_selection_keys: null,
+                view_model_instance_name: null,
+            },
+            expect.objectContaining({ artboard_name: '', state_machines: [] }),
+        ]);
+        expect(buildDemoBundlePayload({ currentFileName: 'demo.riv' }).artboards).toEqual([]);
+    });
+
     it('creates demo bundles and exports directly to a path', async () => {
         const buffer = Uint8Array.from([1, 2]).buffer;
         const fullSnapshot = [
@@ -91,7 +124,7 @@ describe('platform/demo-export', () => {
                 expect(payload.payload.file_name).toBe('demo.riv');
                 expect(payload.payload.view_model_instance_name).toBe('Board');
                 expect(JSON.parse(payload.payload.control_snapshot)).toEqual([fullSnapshot[0]]);
-                return '/tmp/demo-app';
+                return { path: '/tmp/demo-app', verification: { passed: true } };
             }
             if (command === 'make_demo_bundle_to_path') {
                 expect(payload.outputPath).toBe('/tmp/out');
@@ -100,7 +133,7 @@ describe('platform/demo-export', () => {
                 expect(payload.payload.instantiation_code).toBe(snippets.local);
                 expect(snippets.local).toContain('import * as rive from "@rive-app/webgl2";');
                 expect(snippets.local).toContain('bindRavViewModelInstance(riveInst, "Board");');
-                return '/tmp/out';
+                return { path: '/tmp/out', verification: { passed: true } };
             }
             return null;
         });
@@ -153,7 +186,7 @@ describe('platform/demo-export', () => {
         await expect(controller.exportDemoToPath('/tmp/out', {
             packageSource: 'local',
             snippetMode: 'scaffold',
-        })).resolves.toBe('/tmp/out');
+        })).resolves.toEqual({ path: '/tmp/out', verification: { passed: true } });
         const instantiationResult = await controller.generateWebInstantiationCode({ packageSource: 'cdn' });
         expect(instantiationResult).toEqual(expect.objectContaining({
             helperApi: expect.objectContaining({
@@ -347,12 +380,42 @@ describe('platform/demo-export', () => {
         expect(logEvent).toHaveBeenCalledWith('ui', 'demo-build-failed', 'Failed to build demo bundle.', expect.any(Error));
     });
 
+    it('shows the failed checks when the written demo does not pass verification', async () => {
+        const showError = vi.fn();
+        const logEvent = vi.fn();
+        const verification = {
+            passed: false,
+            checks: [
+                { name: 'placeholders', passed: true, detail: null },
+                { name: 'size-budget', passed: false, detail: '900 bytes exceeds the 1-byte budget' },
+            ],
+        };
+        const failingVerificationController = createDemoExportController({
+            callbacks: {
+                ensureRuntime: vi.fn().mockResolvedValue(undefined),
+                getTauriInvoker: () => vi.fn(async () => ({ path: '/tmp/demo.html', verification })),
+                showError,
+                logEvent,
+                updateInfo: vi.fn(),
+            },
+            getCurrentFileBuffer: () => Uint8Array.from([1, 2]).buffer,
+            getCurrentFileName: () => 'demo.riv',
+            getRuntimeAsset: () => ({ text: 'runtime();', version: '2.0.0' }),
+        });
+
+        await expect(failingVerificationController.createDemoBundle()).resolves.toBeNull();
+        expect(showError).toHaveBeenCalledWith(
+            'Demo export failed verification (size-budget: 900 bytes exceeds the 1-byte budget)',
+        );
+        expect(logEvent).toHaveBeenCalledWith('ui', 'demo-build-failed', expect.any(String), verification);
+    });
+
     it('executes default callback paths during export operations', async () => {
         const invoke = vi.fn(async (command, payload) => {
             if (command === 'make_demo_bundle') {
-                return `/tmp/${payload.payload.file_name}`;
+                return { path: `/tmp/${payload.payload.file_name}`, verification: { passed: true } };
             }
-            return '/tmp/out';
+            return { path: '/tmp/out', verification: { passed: true } };
         });
         const controller = createDemoExportController({
             callbacks: {
@@ -365,7 +428,10 @@ describe('platform/demo-export', () => {
         const noInvokeController = createDemoExportController();
 
         await expect(controller.createDemoBundle()).resolves.toBe('/tmp/default.riv');
-        await expect(controller.exportDemoToPath('/tmp/out')).resolves.toBe('/tmp/out');
+        await expect(controller.exportDemoToPath('/tmp/out')).resolves.toEqual({
+            path: '/tmp/out',
+            verification: { passed: true },
+        });
         await expect(controller.generateWebInstantiationCode()).resolves.toEqual(
             expect.objectContaining({
                 packageSource: 'cdn',
@@ -0,0 +1,46 @@
+import {
+    createExportWatchClient,
+    describeExportWatchEvent,
+} from '../../../src/app/platform/export/export-watch.js';
+
+describe('platform/export/export-watch', () => {
+    it('starts watching the open file and logs each re-export with its size delta', async () => {
+        let handler = null;
+        const listen = vi.fn(async (_name, callback) => {
+            handler = callback;
+        });
+        const invoke = vi.fn(async (command) => (
+            command === 'start_export_watch'
+                ? { watching: true, sourcePath: '/art/card.riv', outputPath: '/tmp/card.html', exportCount: 0 }
+                : { watching: true, exportCount: 1 }
+        ));
+        const logEvent = vi.fn();
+        const client = createExportWatchClient({
+            getCurrentFileSourcePath: () => '/art/card.riv',
+            getTauriEventListener: async () => listen,
+            getTauriInvoker: () => invoke,
+            logEvent,
+        });
+
+        await expect(client.run('start')).resolves.toMatchObject({ watching: true });
+        expect(invoke).toHaveBeenCalledWith('start_export_watch', { sourcePath: '/art/card.riv' });
+        expect(listen).toHaveBeenCalledWith('export-watch', expect.any(Function));
+
+        const payload = { ok: true, outputPath: '/tmp/card.html', sizeDiff: { currentBytes: 1200, deltaBytes: 200 } };
+        handler({ payload });
+        expect(logEvent).toHaveBeenCalledWith('ui', 'export-watch', 'Re-exported /tmp/card.html (1200 bytes, +200)', payload);
+        await expect(client.run('status')).resolves.toEqual({ watching: true, exportCount: 1, lastEvent: payload });
+
+        await client.run('start', { sourcePath: '/art/other.riv' });
+        expect(listen).toHaveBeenCalledTimes(1);
+        await expect(client.run('rewind')).rejects.toThrow('Unknown export watch action: rewind');
+    });
+
+    it('requires a source path and the desktop app', async () => {
+        const client = createExportWatchClient({ getTauriInvoker: () => vi.fn() });
+        await expect(client.run('start')).rejects.toThrow('No source .riv path to watch');
+        await expect(createExportWatchClient().run('stop')).rejects.toThrow('requires the Tauri desktop app');
+        expect(describeExportWatchEvent({ ok: false, error: 'Failed to read x' }))
+            .toBe('Watch export failed: Failed to read x');
+    });
+});
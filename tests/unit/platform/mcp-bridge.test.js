@@ -252,6 +252,35 @@ describe('platform/mcp-bridge', () => {
         });
     });
 
+    it('lists state machines for another artboard of the loaded file', async () => {
+        vi.stubGlobal('WebSocket', FakeWebSocket);
+        vi.stubGlobal('setInterval', vi.fn(() => 1));
+        vi.stubGlobal('clearInterval', vi.fn());
+        window._mcpLogEvent = vi.fn();
+        window._mcpUpdateStatus = vi.fn();
+        window.riveInst = {
+            artboardName: 'Main',
+            stateMachineNames: ['Main SM'],
+            contents: {
+                artboards: [
+                    { name: 'Main', stateMachines: [{ name: 'Main SM' }] },
+                    { name: 'Menu', stateMachines: [{ name: 'Open' }, { name: 'Hover' }] },
+                ],
+            },
+        };
+
+        await import('../../../src/app/platform/mcp/bridge-client.js?test=bridge-artboard-state-machines');
+        await flushBridgeMicrotasks();
+        const { commands } = window._mcpBridge;
+
+        await expect(commands.rav_get_state_machines()).resolves.toEqual({ stateMachines: ['Main SM'] });
+        await expect(commands.rav_get_state_machines({ artboard: 'Menu' }))
+            .resolves.toEqual({ artboard: 'Menu', stateMachines: ['Open', 'Hover'] });
+        await expect(commands.rav_get_state_machines({ artboard: 'Nope' }))
+            .rejects.toThrow('Artboard "Nope" not found');
+        delete window.riveInst;
+    });
+
     it('decodes non-string websocket payloads and replies to bridge commands', async () => {
         vi.stubGlobal('WebSocket', FakeWebSocket);
         vi.stubGlobal('setInterval', vi.fn(() => 1));
@@ -306,6 +335,69 @@ describe('platform/mcp-bridge', () => {
         });
     });
 
+    it('advertises the tool catalog it can run in the bridge handshake', async () => {
+        vi.stubGlobal('WebSocket', FakeWebSocket);
+        vi.stubGlobal('setInterval', vi.fn(() => 1));
+        vi.stubGlobal('clearInterval', vi.fn());
+
+        await import('../../../src/app/platform/mcp/bridge-client.js?test=bridge-tool-catalog');
+        await flushBridgeMicrotasks();
+        const socket = FakeWebSocket.instances[0];
+        socket.accept();
+
+        const hello = JSON.parse(socket.sent[0]);
+        expect(hello.bridgeHello).toBe('rav-app');
+        expect(hello.tools.map((tool) => tool.name)).toContain('rav_open_file');
+        expect(hello.tools.every((tool) => (
+            typeof window._mcpBridge.commands[tool.name] === 'function' && tool.inputSchema?.type === 'object'
+        ))).toBe(true);
+    });
+
+    it('relays command progress and drops the reply of a cancelled command', async () => {
+        vi.stubGlobal('WebSocket', FakeWebSocket);
+        vi.stubGlobal('setInterval', vi.fn(() => 1));
+        vi.stubGlobal('clearInterval', vi.fn());
+        window._mcpLogEvent = vi.fn();
+        window._mcpUpdateStatus = vi.fn();
+        let openDialog;
+        window._mcpToggleInstantiationControlsDialog = vi.fn((action) => (
+            action === 'open' ? new Promise((resolve) => { openDialog = resolve; }) : Promise.resolve()
+        ));
+        window._mcpExportDemoToPath = vi.fn();
+
+        await import('../../../src/app/platform/mcp/bridge-client.js?test=bridge-cancel-progress');
+        await flushBridgeMicrotasks();
+        const socket = FakeWebSocket.instances[0];
+        socket.accept();
+        await flushBridgeMicrotasks();
+
+        const pending = socket.onmessage?.({
+            data: JSON.stringify({
+                id: 'req-export',
+                command: 'rav_export_demo_visual',
+                params: { output_path: '/tmp/demo.html', step_delay_ms: 0 },
+            }),
+        });
+        await flushBridgeMicrotasks();
+        await socket.onmessage?.({ data: JSON.stringify({ bridgeEvent: 'cancel', id: 'req-export' }) });
+        openDialog();
+        await vi.runAllTimersAsync();
+        await pending;
+
+        const sent = socket.sent.map((entry) => JSON.parse(entry));
+        expect(sent).toContainEqual({
+            bridgeEvent: 'progress',
+            id: 'req-export',
+            progress: 0,
+            total: 4,
+            message: 'Opening instantiation controls',
+        });
+        expect(sent.filter((entry) => entry.id === 'req-export' && !entry.bridgeEvent)).toEqual([]);
+        expect(window._mcpExportDemoToPath).not.toHaveBeenCalled();
+        delete window._mcpToggleInstantiationControlsDialog;
+        delete window._mcpExportDemoToPath;
+    });
+
     it('stays green while ready, turns active only for recent commands, and resets the 30-second window', async () => {
         vi.stubGlobal('WebSocket', FakeWebSocket);
         vi.stubGlobal('setInterval', vi.fn(() => 1));
@@ -54,7 +54,7 @@ describe('platform/mcp/export-workspace', () => {
         renderTree();
 
         const windowRef = {
-            _mcpExportDemoToPath: vi.fn(async (path) => path),
+            _mcpExportDemoToPath: vi.fn(async (path) => ({ path, verification: { passed: true } })),
             _mcpToggleInstantiationControlsDialog: vi.fn(async () => ({ open: true })),
         };
         const commands = createExportWorkspaceCommands({ documentRef: document, windowRef });
@@ -79,7 +79,11 @@ describe('platform/mcp/export-workspace', () => {
             step_delay_ms: 0,
         });
         await vi.runAllTimersAsync();
-        await expect(exportPromise).resolves.toEqual({ ok: true, path: '/tmp/dynamic-rows.html' });
+        await expect(exportPromise).resolves.toEqual({
+            ok: true,
+            path: '/tmp/dynamic-rows.html',
+            verification: { passed: true },
+        });
 
         expect(Array.from(selected)).toEqual(expectedSelection);
         expect(windowRef._mcpExportDemoToPath).toHaveBeenCalledWith('/tmp/dynamic-rows.html', {
@@ -122,4 +126,222 @@ describe('platform/mcp/export-workspace', () => {
             ['close'],
         ]);
     });
+
+    it('reports export progress and stops before writing once the request is cancelled', async () => {
+        const windowRef = {
+            _mcpExportDemoToPath: vi.fn(async (path) => ({ path })),
+            _mcpToggleInstantiationControlsDialog: vi.fn(async () => ({ open: true })),
+        };
+        const commands = createExportWorkspaceCommands({ documentRef: document, windowRef });
+        const reportProgress = vi.fn();
+
+        const finished = commands.rav_export_demo_visual(
+            { output_path: '/tmp/demo.html', step_delay_ms: 0 },
+            { reportProgress },
+        );
+        await vi.runAllTimersAsync();
+        await finished;
+        expect(reportProgress.mock.calls.map(([progress, total]) => [progress, total])).toEqual([
+            [0, 4], [1, 4], [2, 4], [3, 4], [4, 4],
+        ]);
+
+        const controller = new AbortController();
+        const cancelled = commands.rav_export_demo_visual(
+            { output_path: '/tmp/cancelled.html', step_delay_ms: 0 },
+            { signal: controller.signal },
+        );
+        controller.abort();
+        const rejection = expect(cancelled).rejects.toThrow('Export cancelled');
+        await vi.runAllTimersAsync();
+        await rejection;
+        expect(windowRef._mcpExportDemoToPath).toHaveBeenCalledTimes(1);
+        expect(windowRef._mcpToggleInstantiationControlsDialog).toHaveBeenLastCalledWith('close');
+    });
+
+    it('routes spec sheet exports through the artifact binding with the requested format', async () => {
+        const windowRef = {
+            _mcpExportArtifactToPath: vi.fn(async (_command, path) => path),
+        };
+        const commands = createExportWorkspaceCommands({ documentRef: document, windowRef });
+
+        await expect(commands.rav_export_spec_sheet({
+            format: 'html',
+            output_path: '/tmp/handoff/spec.html',
+        })).resolves.toEqual({ ok: true, path: '/tmp/handoff/spec.html' });
+        expect(windowRef._mcpExportArtifactToPath).toHaveBeenCalledWith(
+            'make_spec_sheet_to_path',
+            '/tmp/handoff/spec.html',
+            { commandArgs: { format: 'html' }, packageSource: 'cdn' },
+        );
+        await expect(commands.rav_export_spec_sheet({})).rejects.toThrow('output_path is required');
+    });
+
+    it('routes web component exports through the artifact binding with tag and riv url options', async () => {
+        const windowRef = {
+            _mcpExportArtifactToPath: vi.fn(async (_command, path) => path),
+        };
+        const commands = createExportWorkspaceCommands({ documentRef: document, windowRef });
+
+        await expect(commands.rav_export_web_component({
+            output_path: '/tmp/components/hero.js',
+            tag_name: 'x-hero',
+        })).resolves.toEqual({ ok: true, path: '/tmp/components/hero.js' });
+        expect(windowRef._mcpExportArtifactToPath).toHaveBeenCalledWith(
+            'make_web_component_to_path',
+            '/tmp/components/hero.js',
+            { commandArgs: { options: { tag_name: 'x-hero', riv_url: null } } },
+        );
+    });
+
+    it('routes framework starter exports with the framework and package source', async () => {
+        const windowRef = {
+            _mcpExportArtifactToPath: vi.fn(async (_command, path) => path),
+        };
+        const commands = createExportWorkspaceCommands({ documentRef: document, windowRef });
+
+        await expect(commands.rav_export_framework_starter({
+            framework: 'svelte',
+            output_path: '/tmp/starters/hero',
+            package_source: 'local',
+        })).resolves.toEqual({ ok: true, path: '/tmp/starters/hero' });
+        expect(windowRef._mcpExportArtifactToPath).toHaveBeenCalledWith(
+            'make_framework_starter_to_path',
+            '/tmp/starters/hero',
+            { commandArgs: { framework: 'svelte' }, packageSource: 'local' },
+        );
+        await expect(commands.rav_export_framework_starter({ output_path: '/tmp/x' }))
+            .rejects.toThrow('framework is required');
+    });
+
+    it('routes TypeScript binding exports through the artifact binding', async () => {
+        const windowRef = {
+            _mcpExportArtifactToPath: vi.fn(async (_command, path) => path),
+        };
+        const commands = createExportWorkspaceCommands({ documentRef: document, windowRef });
+
+        await expect(commands.rav_export_typescript_bindings({ output_path: '/tmp/types/card.ts' }))
+            .resolves.toEqual({ ok: true, path: '/tmp/types/card.ts' });
+        expect(windowRef._mcpExportArtifactToPath).toHaveBeenCalledWith(
+            'make_typescript_bindings_to_path',
+            '/tmp/types/card.ts',
+        );
+    });
+
+    it('generates native snippets through the artifact command binding', async () => {
+        const snippet = { platform: 'flutter', language: 'dart', code: 'RiveWidget()' };
+        const windowRef = {
+            _mcpInvokeArtifactCommand: vi.fn(async () => snippet),
+        };
+        const commands = createExportWorkspaceCommands({ documentRef: document, windowRef });
+
+        await expect(commands.generate_native_instantiation_code({ platform: 'flutter' }))
+            .resolves.toEqual(snippet);
+        expect(windowRef._mcpInvokeArtifactCommand).toHaveBeenCalledWith(
+            'generate_native_instantiation_code',
+            { commandArgs: { platform: 'flutter' } },
+        );
+        await expect(commands.generate_native_instantiation_code({}))
+            .rejects.toThrow('platform is required');
+    });
+
+    it('passes the PWA export report through', async () => {
+        const report = { path: '/tmp/pwa', verification: { passed: true }, warnings: [] };
+        const windowRef = {
+            _mcpExportArtifactToPath: vi.fn(async () => report),
+        };
+        const commands = createExportWorkspaceCommands({ documentRef: document, windowRef });
+
+        await expect(commands.rav_export_pwa({ output_path: '/tmp/pwa' }))
+            .resolves.toEqual({ ok: true, path: '/tmp/pwa', verification: { passed: true } });
+        expect(windowRef._mcpExportArtifactToPath).toHaveBeenCalledWith('make_pwa_demo_to_path', '/tmp/pwa', {
+            commandArgs: { options: { csp: false, max_size_bytes: null } },
+        });
+    });
+
+    it('passes the size budget and CSP options to the PWA export and reports the policy', async () => {
+        const windowRef = {
+            _mcpExportArtifactToPath: vi.fn(async (_command, path) => ({
+                path,
+                verification: { passed: true },
+                contentSecurityPolicy: "default-src 'none'; worker-src 'self'",
+            })),
+        };
+        const commands = createExportWorkspaceCommands({ documentRef: document, windowRef });
+
+        await expect(commands.rav_export_pwa({ output_path: '/tmp/pwa', csp: true, max_size_bytes: 4096 }))
+            .resolves.toEqual({
+                ok: true,
+                path: '/tmp/pwa',
+                verification: { passed: true },
+                content_security_policy: "default-src 'none'; worker-src 'self'",
+            });
+        expect(windowRef._mcpExportArtifactToPath).toHaveBeenCalledWith('make_pwa_demo_to_path', '/tmp/pwa', {
+            commandArgs: { options: { csp: true, max_size_bytes: 4096 } },
+        });
+    });
+
+    it('reports failed demo verification as not ok and keeps the report', async () => {
+        const verification = {
+            passed: false,
+            checks: [{ name: 'size-budget', passed: false, detail: '900 bytes exceeds the 1-byte budget' }],
+        };
+        const windowRef = {
+            _mcpExportDemoToPath: vi.fn(async (path) => ({ path, verification, warnings: [] })),
+        };
+        const commands = createExportWorkspaceCommands({ documentRef: document, windowRef });
+
+        await expect(commands.rav_export_demo({ output_path: '/tmp/demo.html', max_size_bytes: 1 }))
+            .resolves.toEqual({ ok: false, path: '/tmp/demo.html', verification });
+    });
+
+    it('drives the timeline recorder and forwards recorded timelines to the demo export', async () => {
+        const timeline = { loop: false, autoplay: true, duration_ms: 900, steps: [{ at_ms: 100, kind: 'boolean', path: 'open', value: true }] };
+        const recorder = {
+            start: vi.fn(() => ({ recording: true, elapsedMs: 0, stepCount: 0 })),
+            status: vi.fn(() => ({ recording: false, elapsedMs: 0, stepCount: 0 })),
+            stop: vi.fn(() => timeline),
+        };
+        const windowRef = {
+            _mcpExportDemoToPath: vi.fn(async () => ({ path: '/tmp/demo.html', verification: { passed: true } })),
+            _mcpGetTimelineRecorder: () => recorder,
+        };
+        const commands = createExportWorkspaceCommands({ documentRef: document, windowRef });
+
+        await expect(commands.rav_record_timeline({ action: 'start' }))
+            .resolves.toEqual({ ok: true, recording: true, elapsedMs: 0, stepCount: 0 });
+        await expect(commands.rav_record_timeline({ action: 'stop' }))
+            .resolves.toEqual({ ok: true, timeline });
+        await expect(commands.rav_record_timeline({ action: 'rewind' }))
+            .rejects.toThrow('Unknown action: rewind');
+
+        await commands.rav_export_demo({ output_path: '/tmp/demo.html', timeline });
+        expect(windowRef._mcpExportDemoToPath).toHaveBeenCalledWith('/tmp/demo.html', expect.objectContaining({ timeline }));
+    });
+
+    it('exports with a saved preset and leaves its export options in charge', async () => {
+        const preset = { name: 'handoff', package_source: 'local', control_selection_keys: ['vm:title:string'], output_dir: '/srv/demos' };
+        const windowRef = {
+            _mcpExportDemoToPath: vi.fn(async () => ({ path: '/srv/demos/card-riv-demo.html', verification: { passed: true } })),
+            _mcpExportPresets: vi.fn(async (action) => (action === 'apply' ? preset : [preset])),
+        };
+        const commands = createExportWorkspaceCommands({ documentRef: document, windowRef });
+
+        await expect(commands.rav_export_demo({ preset: 'handoff' })).resolves.toEqual({
+            ok: true,
+            path: '/srv/demos/card-riv-demo.html',
+            verification: { passed: true },
+        });
+        expect(windowRef._mcpExportPresets).toHaveBeenCalledWith('apply', { name: 'handoff' });
+        expect(windowRef._mcpExportDemoToPath).toHaveBeenCalledWith('', expect.objectContaining({
+            exportOptions: null,
+            packageSource: 'local',
+            preset: 'handoff',
+            selectedControlKeys: ['vm:title:string'],
+        }));
+
+        await expect(commands.rav_export_presets({ action: 'save', preset }))
+            .resolves.toEqual({ ok: true, presets: [preset] });
+        await expect(commands.rav_export_presets({ action: 'delete' }))
+            .rejects.toThrow('name is required');
+    });
 });
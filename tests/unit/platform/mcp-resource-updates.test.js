@@ -0,0 +1,42 @@
+import {
+    RESOURCE_URIS,
+    RESOURCES_UPDATED_EVENT,
+    announceResourceUpdates,
+    createResourceUpdateRelay,
+    vmPropertyUri,
+} from '../../../src/app/platform/mcp/resource-updates.js';
+
+describe('platform/mcp/resource-updates', () => {
+    it('coalesces queued URIs into one resources-updated bridge event', () => {
+        const timers = [];
+        const send = vi.fn();
+        const relay = createResourceUpdateRelay({
+            send,
+            setTimeoutFn: (callback) => timers.push(callback),
+        });
+
+        relay.queue([RESOURCE_URIS.vmTree, vmPropertyUri('settings/volume')]);
+        relay.queue([RESOURCE_URIS.vmTree, '', null]);
+        expect(timers).toHaveLength(1);
+
+        timers[0]();
+        expect(send).toHaveBeenCalledWith({
+            bridgeEvent: 'resources-updated',
+            uris: ['rav://current/vm-tree', 'rav://vm/settings/volume'],
+        });
+        relay.flush();
+        expect(send).toHaveBeenCalledTimes(1);
+    });
+
+    it('announces updates as a document event and encodes property path segments', () => {
+        const listener = vi.fn();
+        document.addEventListener(RESOURCES_UPDATED_EVENT, listener);
+
+        announceResourceUpdates([vmPropertyUri('menu items/0/label')]);
+        announceResourceUpdates([]);
+
+        expect(listener).toHaveBeenCalledTimes(1);
+        expect(listener.mock.calls[0][0].detail).toEqual({ uris: ['rav://vm/menu%20items/0/label'] });
+        document.removeEventListener(RESOURCES_UPDATED_EVENT, listener);
+    });
+});
@@ -0,0 +1,30 @@
+import { createTimelineRecorder } from '../../../src/app/rive/view-model/timeline-recorder.js';
+
+describe('rive/view-model/timeline-recorder', () => {
+    it('records control changes relative to the start of the recording', () => {
+        let clock = 1000;
+        const recorder = createTimelineRecorder({ now: () => clock });
+
+        recorder.record({ kind: 'number', path: 'score' }, 1);
+        expect(recorder.start()).toEqual({ recording: true, elapsedMs: 0, stepCount: 0 });
+
+        clock = 1250;
+        recorder.record({ kind: 'number', path: 'score', source: 'vm' }, 42);
+        clock = 1600;
+        recorder.record({ kind: 'trigger', name: 'pulse', source: 'state-machine', stateMachineName: 'main-sm' }, null);
+        expect(recorder.status()).toEqual({ recording: true, elapsedMs: 600, stepCount: 2 });
+
+        clock = 2000;
+        expect(recorder.stop()).toEqual({
+            loop: false,
+            autoplay: true,
+            duration_ms: 1000,
+            steps: [
+                { at_ms: 250, kind: 'number', path: 'score', value: 42 },
+                { at_ms: 600, kind: 'trigger', state_machine: 'main-sm', input: 'pulse' },
+            ],
+        });
+        expect(recorder.isRecording()).toBe(false);
+        expect(recorder.stop()).toBeNull();
+    });
+});
@@ -430,6 +430,13 @@ describe('rive/vm-controls', () => {
             source: 'state-machine',
             stateMachineName: 'Machine',
         });
+        const stateMachineHierarchy = harness.controller.serializeStateMachineHierarchy();
+        expect(stateMachineHierarchy.kind).toBe('state-machines');
+        expect(stateMachineHierarchy.children[0]).toMatchObject({
+            kind: 'state-machine',
+            label: 'Machine',
+        });
+        expect(stateMachineHierarchy.children[0].inputs[0]).toEqual(controlHierarchy.children[1].inputs[0]);
 
         harness.controller.resetVmInputControls('No animation loaded.');
         expect(harness.elements.vmControlsCount.textContent).toBe('0');